@@ -58,6 +58,7 @@ mod tests {
             started_at: Utc::now(),
             last_activity: Utc::now(),
             window_id: None,
+            owner: None,
             activity: Default::default(),
         }
     }
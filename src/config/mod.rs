@@ -23,6 +23,50 @@ pub struct Config {
     pub notifications: NotificationConfig,
     #[serde(default)]
     pub ui: UiConfig,
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+    #[serde(default)]
+    pub team: TeamConfig,
+}
+
+impl Config {
+    /// All Linear accounts to fetch from.
+    ///
+    /// Falls back to a single unnamed account built from `tokens.linear` so
+    /// existing single-workspace configs keep working unchanged.
+    pub fn linear_accounts(&self) -> Vec<LinearAccount> {
+        if self.linear.accounts.is_empty() {
+            vec![LinearAccount {
+                name: String::new(),
+                token: self.tokens.linear.clone(),
+                api_url: self.linear.api_url.clone(),
+                webhook_secret: None,
+            }]
+        } else {
+            self.linear
+                .accounts
+                .iter()
+                .map(|account| LinearAccount {
+                    api_url: self.linear.api_url.clone(),
+                    ..account.clone()
+                })
+                .collect()
+        }
+    }
+
+    /// True when issues come from more than one Linear workspace
+    pub fn has_multiple_linear_workspaces(&self) -> bool {
+        self.linear.accounts.len() > 1
+    }
+
+    /// API key for the workspace an issue belongs to (`None` = default account)
+    pub fn linear_token_for(&self, workspace: Option<&str>) -> &str {
+        workspace
+            .and_then(|name| self.linear.accounts.iter().find(|a| a.name == name))
+            .or_else(|| self.linear.accounts.first())
+            .map(|a| a.token.as_str())
+            .unwrap_or(&self.tokens.linear)
+    }
 }
 
 // =============================================================================
@@ -31,6 +75,8 @@ pub struct Config {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tokens {
+    /// Default Linear API key (optional when `[[linear.accounts]]` are configured)
+    #[serde(default)]
     pub linear: String,
     pub github: String,
     #[serde(default)]
@@ -54,6 +100,19 @@ pub struct LinearConfig {
     /// Enable incremental sync (only fetch updated issues)
     #[serde(default = "default_true")]
     pub incremental_sync: bool,
+
+    /// Named Linear accounts, one per workspace (replaces `tokens.linear`)
+    #[serde(default)]
+    pub accounts: Vec<LinearAccount>,
+
+    /// Regex that finds an issue identifier in a git branch name. The first
+    /// capture group (or the whole match) is used as the identifier.
+    #[serde(default = "default_identifier_pattern")]
+    pub identifier_pattern: String,
+
+    /// GraphQL endpoint (override to point at a mock server)
+    #[serde(default = "default_linear_api_url")]
+    pub api_url: String,
 }
 
 impl Default for LinearConfig {
@@ -62,46 +121,155 @@ impl Default for LinearConfig {
             filter: default_linear_filter(),
             fetch_limit: default_linear_fetch_limit(),
             incremental_sync: true,
+            accounts: Vec::new(),
+            identifier_pattern: default_identifier_pattern(),
+            api_url: default_linear_api_url(),
         }
     }
 }
 
+/// A Linear workspace and the API key used to access it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearAccount {
+    /// Short label shown in the table and filter menu (e.g., "acme")
+    pub name: String,
+    /// Linear API key for this workspace
+    pub token: String,
+    /// GraphQL endpoint, filled in from `linear.api_url`
+    #[serde(skip, default = "default_linear_api_url")]
+    pub api_url: String,
+    /// Signing secret of this workspace's Linear webhook (ties deliveries to it)
+    #[serde(default)]
+    pub webhook_secret: Option<String>,
+}
+
 fn default_linear_filter() -> String {
     "assignee:me".to_string()
 }
 
+/// Team keys start with a letter and may contain digits (e.g. `DRE-380`, `WEB2-14`, `PLATFORM-7`)
+pub const DEFAULT_IDENTIFIER_PATTERN: &str = r"(?i)(?:^|[^A-Z0-9])([A-Z][A-Z0-9]{1,9}-\d+)";
+
+fn default_identifier_pattern() -> String {
+    DEFAULT_IDENTIFIER_PATTERN.to_string()
+}
+
 fn default_linear_fetch_limit() -> usize {
     150
 }
 
+fn default_linear_api_url() -> String {
+    "https://api.linear.app/graphql".to_string()
+}
+
 // =============================================================================
 // GitHub Configuration
 // =============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubConfig {
-    /// GitHub username (for filtering PRs)
+    /// GitHub username; open PRs they authored are discovered by search
     #[serde(default)]
     pub username: Option<String>,
 
-    /// Organizations to include
+    /// Organizations to search for authored PRs (empty = everywhere)
     #[serde(default)]
     pub organizations: Vec<String>,
+
+    /// REST API base URL; GitHub Enterprise uses `https://<host>/api/v3`
+    #[serde(default = "default_github_api_url")]
+    pub api_url: String,
+}
+
+impl Default for GithubConfig {
+    fn default() -> Self {
+        Self {
+            username: None,
+            organizations: Vec::new(),
+            api_url: default_github_api_url(),
+        }
+    }
+}
+
+impl GithubConfig {
+    /// REST API base URL without a trailing slash
+    pub fn rest_url(&self) -> &str {
+        self.api_url.trim_end_matches('/')
+    }
+
+    /// GraphQL endpoint matching `api_url`
+    ///
+    /// GitHub Enterprise serves GraphQL at `/api/graphql` next to the REST
+    /// API at `/api/v3`; everywhere else it lives at `<api_url>/graphql`.
+    pub fn graphql_url(&self) -> String {
+        let rest = self.rest_url();
+        match rest.strip_suffix("/api/v3") {
+            Some(base) => format!("{}/api/graphql", base),
+            None => format!("{}/graphql", rest),
+        }
+    }
+
+    /// Host that PR and repository web URLs use (e.g. `github.com`)
+    pub fn web_host(&self) -> String {
+        let host = url_host(&self.api_url);
+        host.strip_prefix("api.").unwrap_or(host).to_string()
+    }
+
+    /// Whether PR links on `host` belong to a GitHub instance `tokens.github`
+    /// may be sent to: github.com or the host of `api_url`
+    pub fn serves_host(&self, host: &str) -> bool {
+        host.eq_ignore_ascii_case("github.com") || host.eq_ignore_ascii_case(&self.web_host())
+    }
+}
+
+/// Host part of a URL (`https://api.github.com/x` -> `api.github.com`)
+pub fn url_host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split(['/', '?', '#']).next().unwrap_or_default()
+}
+
+fn default_github_api_url() -> String {
+    "https://api.github.com".to_string()
 }
 
 // =============================================================================
 // Vercel Configuration
 // =============================================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VercelConfig {
     /// Team ID or slug
     #[serde(default)]
     pub team_id: Option<String>,
 
-    /// Project IDs to monitor
+    /// Project IDs to monitor (every project the token can see when empty)
     #[serde(default)]
     pub project_ids: Vec<String>,
+
+    /// REST API base URL (override to point at a mock server)
+    #[serde(default = "default_vercel_api_url")]
+    pub api_url: String,
+}
+
+impl VercelConfig {
+    /// Whether a team or projects are set, i.e. the user deploys on Vercel
+    pub fn is_configured(&self) -> bool {
+        self.team_id.is_some() || !self.project_ids.is_empty()
+    }
+}
+
+impl Default for VercelConfig {
+    fn default() -> Self {
+        Self {
+            team_id: None,
+            project_ids: Vec::new(),
+            api_url: default_vercel_api_url(),
+        }
+    }
+}
+
+fn default_vercel_api_url() -> String {
+    "https://api.vercel.com".to_string()
 }
 
 // =============================================================================
@@ -246,6 +414,10 @@ pub struct UiConfig {
     #[serde(default = "default_sort_mode")]
     pub default_sort: String,
 
+    /// Default grouping for issues (none, project, cycle, team, label, assignee, repo)
+    #[serde(default = "default_group_by")]
+    pub default_group_by: String,
+
     /// Show sub-issues by default
     #[serde(default = "default_true")]
     pub show_sub_issues: bool,
@@ -258,13 +430,20 @@ pub struct UiConfig {
     #[serde(default)]
     pub show_canceled: bool,
 
+    /// Issues blocked by open issues: show, demote (sort last) or hide
+    #[serde(default = "default_blocked_issues")]
+    pub blocked_issues: String,
+
     /// Show preview panel by default
     #[serde(default)]
     pub show_preview: bool,
 
-    /// Column widths [status, priority, id, title, pr, agent, vercel, time]
-    #[serde(default = "default_column_widths")]
-    pub column_widths: [usize; 8],
+    /// Column widths [status, priority, id, title, pr, agent, vercel, time, due, owner, ci]
+    #[serde(
+        default = "default_column_widths",
+        deserialize_with = "deserialize_column_widths"
+    )]
+    pub column_widths: [usize; 11],
 }
 
 impl Default for UiConfig {
@@ -272,9 +451,11 @@ impl Default for UiConfig {
         Self {
             theme: String::new(),
             default_sort: default_sort_mode(),
+            default_group_by: default_group_by(),
             show_sub_issues: true,
             show_completed: false,
             show_canceled: false,
+            blocked_issues: default_blocked_issues(),
             show_preview: false,
             column_widths: default_column_widths(),
         }
@@ -285,9 +466,150 @@ fn default_sort_mode() -> String {
     "priority".to_string()
 }
 
-fn default_column_widths() -> [usize; 8] {
-    // Status, Priority, ID, Title, PR, Agent, Vercel, Time
-    [1, 3, 10, 26, 12, 20, 3, 6]
+fn default_group_by() -> String {
+    "none".to_string()
+}
+
+fn default_blocked_issues() -> String {
+    "show".to_string()
+}
+
+fn default_column_widths() -> [usize; 11] {
+    // Status, Priority, ID, Title, PR, Agent, Vercel, Time, Due, Owner, CI
+    [1, 3, 10, 26, 12, 20, 3, 6, 8, 8, 3]
+}
+
+/// Accept shorter lists from configs written before a column was added;
+/// missing trailing columns keep their default width
+fn deserialize_column_widths<'de, D>(deserializer: D) -> Result<[usize; 11], D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Vec::<usize>::deserialize(deserializer)?;
+    let mut widths = default_column_widths();
+    for (width, value) in widths.iter_mut().zip(values) {
+        *width = value;
+    }
+    Ok(widths)
+}
+
+// =============================================================================
+// Webhook Configuration
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Run an embedded HTTP listener for Linear webhook deliveries
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Address the listener binds to (expose it via a tunnel for Linear to reach)
+    #[serde(default = "default_webhook_listen_addr")]
+    pub listen_addr: String,
+
+    /// Signing secret from Linear's webhook settings (verifies `Linear-Signature`).
+    /// The listener refuses to start without one.
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// Resume regular Linear polling after this many seconds without a delivery
+    #[serde(default = "default_webhook_idle_fallback")]
+    pub idle_fallback_secs: u64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen_addr: default_webhook_listen_addr(),
+            secret: None,
+            idle_fallback_secs: default_webhook_idle_fallback(),
+        }
+    }
+}
+
+fn default_webhook_listen_addr() -> String {
+    "127.0.0.1:8787".to_string()
+}
+
+fn default_webhook_idle_fallback() -> u64 {
+    300
+}
+
+// =============================================================================
+// Team View Configuration
+// =============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamConfig {
+    /// Share this dashboard's agent sessions and show teammates' sessions
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Name shown in teammates' Owner column (defaults to `$USER`)
+    #[serde(default)]
+    pub member: Option<String>,
+
+    /// Directory every member writes `<member>.json` to and reads teammates from
+    /// (e.g. a synced or network folder)
+    #[serde(default)]
+    pub shared_dir: Option<String>,
+
+    /// POST snapshots to a lead's dashboard instead (e.g. "http://lead-box:8788/snapshots")
+    #[serde(default)]
+    pub publish_url: Option<String>,
+
+    /// Lead: accept snapshots POSTed by teammates on this address
+    #[serde(default)]
+    pub listen_addr: Option<String>,
+
+    /// Shared secret signing HTTP snapshots (`X-Panopticon-Signature`).
+    /// Required with `publish_url` or `listen_addr`.
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// Seconds between snapshot publishes (and shared directory scans)
+    #[serde(default = "default_team_publish_interval")]
+    pub publish_interval_secs: u64,
+
+    /// Drop a teammate's sessions when their snapshot is older than this
+    #[serde(default = "default_team_stale_after")]
+    pub stale_after_secs: u64,
+}
+
+impl Default for TeamConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            member: None,
+            shared_dir: None,
+            publish_url: None,
+            listen_addr: None,
+            secret: None,
+            publish_interval_secs: default_team_publish_interval(),
+            stale_after_secs: default_team_stale_after(),
+        }
+    }
+}
+
+impl TeamConfig {
+    /// Name this dashboard publishes under
+    pub fn member_name(&self) -> String {
+        self.member
+            .clone()
+            .filter(|m| !m.trim().is_empty())
+            .or_else(|| std::env::var("USER").ok())
+            .or_else(|| std::env::var("USERNAME").ok())
+            .unwrap_or_else(|| "me".to_string())
+    }
+}
+
+fn default_team_publish_interval() -> u64 {
+    15
+}
+
+fn default_team_stale_after() -> u64 {
+    120
 }
 
 // =============================================================================
@@ -321,6 +643,20 @@ pub fn cache_path(config: &Config) -> Result<PathBuf> {
     }
 }
 
+/// Shared snapshot directory for the team view, with `~` expanded
+pub fn team_shared_dir(config: &Config) -> Option<PathBuf> {
+    let dir = config.team.shared_dir.as_deref()?.trim();
+    if dir.is_empty() {
+        return None;
+    }
+    if let Some(suffix) = dir.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return Some(home.join(suffix));
+        }
+    }
+    Some(PathBuf::from(dir))
+}
+
 // =============================================================================
 // Load/Save
 // =============================================================================
@@ -344,6 +680,14 @@ pub fn load(path: Option<&Path>) -> Result<Config> {
     let config: Config = toml::from_str(&content)
         .with_context(|| format!("Failed to parse config from {}", path.display()))?;
 
+    regex::Regex::new(&config.linear.identifier_pattern).with_context(|| {
+        format!(
+            "Invalid linear.identifier_pattern in {}: {}",
+            path.display(),
+            config.linear.identifier_pattern
+        )
+    })?;
+
     Ok(config)
 }
 
@@ -363,16 +707,32 @@ vercel = "xxxxx"  # Optional
 filter = "assignee:me"    # Linear search filter
 fetch_limit = 150         # Max issues per API call
 incremental_sync = true   # Only fetch updated issues
+# Issue identifier in branch names (sessions on Linear's suggested branch match exactly)
+# identifier_pattern = '(?i)(?:^|[^A-Z0-9])([A-Z][A-Z0-9]{1,9}-\d+)'
+# api_url = "https://api.linear.app/graphql"  # Override for a mock server
+
+# Multiple Linear workspaces (optional, replaces tokens.linear)
+# [[linear.accounts]]
+# name = "acme"
+# token = "lin_api_xxxxx"
+# webhook_secret = "lin_wh_xxxxx" # With [webhook], replaces webhook.secret
+#
+# [[linear.accounts]]
+# name = "side-project"
+# token = "lin_api_yyyyy"
 
 # GitHub Settings
 [github]
 username = "your-username"
 organizations = ["org1", "org2"]
+# GitHub Enterprise (GraphQL is derived as https://github.example.com/api/graphql)
+# api_url = "https://github.example.com/api/v3"
 
 # Vercel Settings
 [vercel]
 team_id = "team_xxxxx"
 project_ids = ["prj_xxxxx"]
+# api_url = "https://api.vercel.com"  # Override for a mock server
 
 # Polling Intervals (seconds)
 [polling]
@@ -398,12 +758,34 @@ on_deploy_failure = true
 # UI Preferences
 [ui]
 theme = ""                # Future: light/dark/custom
-default_sort = "priority" # priority, status, updated, agent, pr, vercel
+default_sort = "priority" # priority, status, updated, agent, pr, vercel, due, ci
+default_group_by = "none" # none, project, cycle, team, label, assignee, repo
 show_sub_issues = true
 show_completed = false
 show_canceled = false
+blocked_issues = "show"   # show, demote (sort last), hide
 show_preview = false
-column_widths = [1, 3, 10, 26, 12, 10, 3, 6]
+column_widths = [1, 3, 10, 26, 12, 10, 3, 6, 8, 8, 3]
+
+# Linear Webhooks (optional realtime updates)
+[webhook]
+enabled = false
+listen_addr = "127.0.0.1:8787" # Point a Linear webhook (via tunnel) here
+secret = "lin_wh_xxxxx"        # Signing secret (required), verifies Linear-Signature
+idle_fallback_secs = 300       # Poll Linear normally after this long without events
+
+# Team-wide agent view (optional)
+[team]
+enabled = false
+# member = "alice"        # Owner name teammates see (defaults to $USER)
+# Share through a synced/network folder (everyone writes <member>.json)...
+# shared_dir = "~/Dropbox/panopticon-team"
+# ...or POST snapshots to a lead's dashboard, which listens on listen_addr
+# publish_url = "http://lead-box:8788/snapshots"
+# listen_addr = "0.0.0.0:8788"
+# secret = "change-me"    # Signs HTTP snapshots (required with publish_url/listen_addr)
+publish_interval_secs = 15
+stale_after_secs = 120    # Hide a teammate's sessions after this long without a snapshot
 "#
     .to_string()
 }
@@ -467,6 +849,8 @@ pub async fn init_wizard() -> Result<()> {
         cache: CacheConfig::default(),
         notifications: NotificationConfig::default(),
         ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
     };
 
     // Create config directory
@@ -2,7 +2,8 @@ mod sorting;
 
 pub use sorting::sort_children;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -10,8 +11,13 @@ use std::collections::HashSet;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Workstream {
     pub linear_issue: LinearIssue,
+    /// Primary PR: the linked PR needing the most attention
     pub github_pr: Option<GitHubPR>,
+    /// Preview deployment of the primary PR
     pub vercel_deployment: Option<VercelDeployment>,
+    /// Every PR linked to this workstream with its preview, primary first.
+    #[serde(default)]
+    pub linked_prs: Vec<LinkedPr>,
     /// All agent sessions linked to this workstream (can be multiple).
     #[serde(default)]
     pub agent_sessions: Vec<AgentSession>,
@@ -22,6 +28,86 @@ pub struct Workstream {
     pub stale: bool,
 }
 
+impl Workstream {
+    /// Set the linked PRs, worst status first, and make the first primary
+    pub fn set_linked_prs(&mut self, mut prs: Vec<LinkedPr>) {
+        prs.sort_by_key(|linked| pr_sort_order(linked.pr.status));
+        self.github_pr = prs.first().map(|linked| linked.pr.clone());
+        self.vercel_deployment = prs
+            .first()
+            .and_then(|linked| linked.vercel_deployment.clone());
+        self.linked_prs = prs;
+    }
+
+    /// Add a PR, or replace the one with the same URL (keeping its preview)
+    pub fn upsert_pr(&mut self, pr: GitHubPR) {
+        let mut prs = self.all_linked_prs();
+        match prs.iter_mut().find(|linked| linked.pr.url == pr.url) {
+            Some(existing) => existing.pr = pr,
+            None => prs.push(LinkedPr {
+                pr,
+                vercel_deployment: None,
+            }),
+        }
+        self.set_linked_prs(prs);
+    }
+
+    /// Linked PRs, falling back to the primary PR for workstreams built
+    /// before `linked_prs` existed
+    pub fn all_linked_prs(&self) -> Vec<LinkedPr> {
+        if !self.linked_prs.is_empty() {
+            return self.linked_prs.clone();
+        }
+        self.github_pr
+            .iter()
+            .map(|pr| LinkedPr {
+                pr: pr.clone(),
+                vercel_deployment: self.vercel_deployment.clone(),
+            })
+            .collect()
+    }
+
+    pub fn pr_count(&self) -> usize {
+        self.linked_prs
+            .len()
+            .max(usize::from(self.github_pr.is_some()))
+    }
+
+    /// Worst CI state across the linked PRs
+    pub fn worst_ci_status(&self) -> Option<CiStatus> {
+        let primary = self.github_pr.as_ref().and_then(|pr| pr.ci_status);
+        self.linked_prs
+            .iter()
+            .filter_map(|linked| linked.pr.ci_status)
+            .chain(primary)
+            .min_by_key(|status| ci_sort_order(*status))
+    }
+
+    /// Failing GitHub Actions jobs across the linked PRs, with their PR
+    pub fn failed_actions_jobs(&self) -> Vec<(GitHubPR, CiCheck)> {
+        self.all_linked_prs()
+            .into_iter()
+            .flat_map(|linked| {
+                let jobs: Vec<CiCheck> = linked
+                    .pr
+                    .failing_checks()
+                    .filter(|check| check.actions_job_id().is_some())
+                    .cloned()
+                    .collect();
+                jobs.into_iter()
+                    .map(move |check| (linked.pr.clone(), check))
+            })
+            .collect()
+    }
+}
+
+/// A PR linked to a workstream, with the preview deployment of its branch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedPr {
+    pub pr: GitHubPR,
+    pub vercel_deployment: Option<VercelDeployment>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinearIssue {
     pub id: String,
@@ -40,9 +126,21 @@ pub struct LinearIssue {
     pub assignee_id: Option<String>,
     pub assignee_name: Option<String>,
     pub estimate: Option<f32>,
+    /// Due date set on the issue (a calendar day, no time of day)
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    /// When the team's SLA for this issue is breached (only set with SLAs enabled)
+    #[serde(default)]
+    pub sla_breaches_at: Option<DateTime<Utc>>,
     pub attachments: Vec<LinearAttachment>,
     pub parent: Option<LinearParentRef>,
     pub children: Vec<LinearChildRef>,
+    /// Blocking, blocked-by, related and duplicate links to other issues
+    #[serde(default)]
+    pub relations: Vec<LinearRelationRef>,
+    /// Linear account name the issue was fetched from (set with multiple workspaces)
+    #[serde(default)]
+    pub workspace: Option<String>,
 }
 
 /// Linear label
@@ -90,6 +188,268 @@ pub struct LinearChildRef {
     pub url: String,
     pub status: LinearStatus,
     pub priority: LinearPriority,
+    #[serde(default)]
+    pub estimate: Option<f32>,
+}
+
+/// Kind of relation between two issues, from the viewed issue's side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RelationKind {
+    Blocks,
+    BlockedBy,
+    Related,
+    Duplicate,
+}
+
+impl RelationKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Blocks => "Blocks",
+            Self::BlockedBy => "Blocked by",
+            Self::Related => "Related",
+            Self::Duplicate => "Duplicate",
+        }
+    }
+
+    /// Display order in the issue modal (blockers first)
+    pub fn sort_order(&self) -> u8 {
+        match self {
+            Self::BlockedBy => 0,
+            Self::Blocks => 1,
+            Self::Related => 2,
+            Self::Duplicate => 3,
+        }
+    }
+}
+
+/// Reference to an issue linked through a relation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearRelationRef {
+    pub kind: RelationKind,
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub status: LinearStatus,
+}
+
+impl LinearIssue {
+    /// Open issues this one is waiting on (finished blockers don't count)
+    pub fn open_blockers(&self) -> impl Iterator<Item = &LinearRelationRef> {
+        self.relations
+            .iter()
+            .filter(|r| r.kind == RelationKind::BlockedBy && !r.status.is_closed())
+    }
+
+    /// Blocked by at least one open issue
+    pub fn is_blocked(&self) -> bool {
+        self.open_blockers().next().is_some()
+    }
+
+    /// Earliest of the due date (end of that day, local time) and the SLA breach time
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        let due = self.due_date.and_then(|date| {
+            date.and_hms_opt(23, 59, 59)?
+                .and_local_timezone(Local)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc))
+        });
+        match (due, self.sla_breaches_at) {
+            (Some(due), Some(sla)) => Some(due.min(sla)),
+            (due, sla) => due.or(sla),
+        }
+    }
+
+    /// Overdue / due soon badge for open issues with a deadline
+    pub fn due_state(&self, now: DateTime<Utc>) -> Option<DueState> {
+        if self.status.is_closed() {
+            return None;
+        }
+        let deadline = self.deadline()?;
+        Some(if deadline <= now {
+            DueState::Overdue
+        } else if deadline - now <= Duration::days(DUE_SOON_DAYS) {
+            DueState::DueSoon
+        } else {
+            DueState::Upcoming
+        })
+    }
+
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.due_state(now) == Some(DueState::Overdue)
+    }
+
+    /// Sum of sub-issue estimates (`None` when no sub-issue is estimated)
+    pub fn children_estimate(&self) -> Option<f32> {
+        self.children
+            .iter()
+            .filter_map(|child| child.estimate)
+            .reduce(|a, b| a + b)
+    }
+
+    /// Own estimate plus sub-issue estimates
+    pub fn rolled_up_estimate(&self) -> Option<f32> {
+        match (self.estimate, self.children_estimate()) {
+            (Some(own), Some(children)) => Some(own + children),
+            (own, children) => own.or(children),
+        }
+    }
+}
+
+/// Progress of the active cycle across a set of issues (header summary)
+#[derive(Debug, Clone)]
+pub struct CycleProgress {
+    pub cycle: LinearCycle,
+    /// Whole days until the cycle ends (rounded up)
+    pub days_left: i64,
+    /// Estimate points of completed issues
+    pub points_done: f32,
+    /// Estimate points of all issues in the cycle (canceled excluded)
+    pub points_total: f32,
+    pub issues_done: usize,
+    pub issues_total: usize,
+}
+
+impl CycleProgress {
+    /// Summarize the cycle running at `now`. When issues span several active
+    /// cycles (one per team), the one with the most issues wins. Only each
+    /// issue's own estimate counts, so sub-issues aren't double-counted.
+    pub fn compute<'a>(
+        issues: impl IntoIterator<Item = &'a LinearIssue>,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        use std::collections::HashMap;
+
+        let mut by_cycle: HashMap<&str, (&LinearCycle, Vec<&LinearIssue>)> = HashMap::new();
+        for issue in issues {
+            let Some(cycle) = &issue.cycle else {
+                continue;
+            };
+            if cycle.starts_at > now || cycle.ends_at <= now {
+                continue;
+            }
+            if matches!(
+                issue.status,
+                LinearStatus::Canceled | LinearStatus::Duplicate
+            ) {
+                continue;
+            }
+            by_cycle
+                .entry(cycle.id.as_str())
+                .or_insert_with(|| (cycle, Vec::new()))
+                .1
+                .push(issue);
+        }
+
+        let (cycle, issues) = by_cycle
+            .into_values()
+            .max_by(|(a_cycle, a), (b_cycle, b)| {
+                a.len()
+                    .cmp(&b.len())
+                    .then_with(|| b_cycle.ends_at.cmp(&a_cycle.ends_at))
+            })?;
+
+        let done: Vec<&LinearIssue> = issues
+            .iter()
+            .copied()
+            .filter(|i| i.status == LinearStatus::Done)
+            .collect();
+
+        Some(Self {
+            cycle: cycle.clone(),
+            days_left: ((cycle.ends_at - now).num_hours() + 23) / 24,
+            points_done: done.iter().filter_map(|i| i.estimate).sum(),
+            points_total: issues.iter().filter_map(|i| i.estimate).sum(),
+            issues_done: done.len(),
+            issues_total: issues.len(),
+        })
+    }
+
+    pub fn points_remaining(&self) -> f32 {
+        (self.points_total - self.points_done).max(0.0)
+    }
+}
+
+/// Deadlines closer than this many days count as "due soon"
+pub const DUE_SOON_DAYS: i64 = 2;
+
+/// How close an open issue is to its due date or SLA breach
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueState {
+    Overdue,
+    DueSoon,
+    Upcoming,
+}
+
+impl DueState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Overdue => "overdue",
+            Self::DueSoon => "due soon",
+            Self::Upcoming => "upcoming",
+        }
+    }
+}
+
+/// Kind of Linear inbox notification shown in the inbox view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    Mention,
+    Assigned,
+    Comment,
+    StatusChange,
+}
+
+impl NotificationKind {
+    /// Map Linear's notification `type` ("issueMention", "issueNewComment", ...).
+    /// Other types (reactions, subscriptions, ...) aren't shown.
+    pub fn from_linear_type(kind: &str) -> Option<Self> {
+        if kind.contains("Mention") {
+            Some(Self::Mention)
+        } else if kind == "issueAssignedToYou" {
+            Some(Self::Assigned)
+        } else if kind == "issueNewComment" || kind == "issueCommentThread" {
+            Some(Self::Comment)
+        } else if kind.starts_with("issueStatusChanged") {
+            Some(Self::StatusChange)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Mention => "mentioned you",
+            Self::Assigned => "assigned you",
+            Self::Comment => "commented",
+            Self::StatusChange => "changed status",
+        }
+    }
+}
+
+/// Notification from the Linear inbox
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearNotification {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub issue_id: Option<String>,
+    pub issue_identifier: Option<String>,
+    pub issue_title: Option<String>,
+    pub issue_url: Option<String>,
+    /// Who triggered it (None for automations)
+    pub actor: Option<String>,
+    /// First line of the comment for mentions and comments
+    pub excerpt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+    /// Linear account name (only set with multiple workspaces)
+    pub workspace: Option<String>,
+}
+
+impl LinearNotification {
+    pub fn is_unread(&self) -> bool {
+        self.read_at.is_none()
+    }
 }
 
 /// Linear issue priority (0-4 from API)
@@ -210,6 +570,11 @@ impl LinearStatus {
         }
     }
 
+    /// Done, canceled or duplicate
+    pub fn is_closed(&self) -> bool {
+        matches!(self, Self::Done | Self::Canceled | Self::Duplicate)
+    }
+
     /// Iterator over all status variants in display order
     pub fn all() -> impl Iterator<Item = Self> {
         [
@@ -234,6 +599,121 @@ pub struct GitHubPR {
     pub status: GitHubPRStatus,
     pub branch: String,
     pub repo: String,
+    /// Combined CI state of the head commit (None = no checks reported)
+    #[serde(default)]
+    pub ci_status: Option<CiStatus>,
+    /// Check runs and commit statuses on the head commit
+    #[serde(default)]
+    pub checks: Vec<CiCheck>,
+    /// Each reviewer's latest review
+    #[serde(default)]
+    pub reviews: Vec<PrReview>,
+    /// Inline review threads, resolved or not
+    #[serde(default)]
+    pub review_threads: Vec<ReviewThread>,
+    /// SHA of the head commit
+    #[serde(default)]
+    pub head_sha: Option<String>,
+}
+
+impl GitHubPR {
+    pub fn failing_checks(&self) -> impl Iterator<Item = &CiCheck> {
+        self.checks
+            .iter()
+            .filter(|check| check.status == CiStatus::Failing)
+    }
+
+    pub fn unresolved_threads(&self) -> impl Iterator<Item = &ReviewThread> {
+        self.review_threads.iter().filter(|thread| !thread.resolved)
+    }
+
+    pub fn unresolved_thread_count(&self) -> usize {
+        self.unresolved_threads().count()
+    }
+
+    /// Prompt asking an agent to address the unresolved review threads
+    /// (None when there are none)
+    pub fn unresolved_threads_prompt(&self) -> Option<String> {
+        let threads: Vec<&ReviewThread> = self.unresolved_threads().collect();
+        if threads.is_empty() {
+            return None;
+        }
+        let mut prompt = format!(
+            "Address the unresolved review comments on PR #{} ({}):\n",
+            self.number, self.repo
+        );
+        for (i, thread) in threads.iter().enumerate() {
+            prompt.push_str(&format!("\n{}. {}", i + 1, thread.location()));
+            if let Some(author) = &thread.author {
+                prompt.push_str(&format!(" (@{})", author));
+            }
+            for line in thread.body.trim().lines() {
+                prompt.push_str("\n   ");
+                prompt.push_str(line);
+            }
+            prompt.push('\n');
+        }
+        Some(prompt)
+    }
+}
+
+/// A reviewer's latest review on a PR
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrReview {
+    pub author: Option<String>,
+    pub state: ReviewState,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+}
+
+impl ReviewState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Approved => "Approved",
+            Self::ChangesRequested => "Changes requested",
+            Self::Commented => "Commented",
+        }
+    }
+
+    /// Map a review `state`; pending and dismissed reviews are skipped
+    pub fn from_graphql(state: &str) -> Option<Self> {
+        match state {
+            "APPROVED" => Some(Self::Approved),
+            "CHANGES_REQUESTED" => Some(Self::ChangesRequested),
+            "COMMENTED" => Some(Self::Commented),
+            _ => None,
+        }
+    }
+}
+
+/// An inline review thread, described by its first comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewThread {
+    pub path: String,
+    /// Line in the current diff (None once the thread is outdated)
+    pub line: Option<u32>,
+    pub author: Option<String>,
+    pub body: String,
+    pub url: Option<String>,
+    /// Comments in the thread, including the first
+    pub comment_count: usize,
+    pub resolved: bool,
+}
+
+impl ReviewThread {
+    /// "src/lib.rs:42", or just the path without a line
+    pub fn location(&self) -> String {
+        match self.line {
+            Some(line) => format!("{}:{}", self.path, line),
+            None => self.path.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -286,12 +766,446 @@ impl GitHubPRStatus {
     }
 }
 
+/// How a merged PR's commits land on the base branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeMethod {
+    #[default]
+    Squash,
+    Merge,
+    Rebase,
+}
+
+impl MergeMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Squash => "squash",
+            Self::Merge => "merge commit",
+            Self::Rebase => "rebase",
+        }
+    }
+
+    /// GitHub's `PullRequestMergeMethod` value
+    pub fn as_graphql(&self) -> &'static str {
+        match self {
+            Self::Squash => "SQUASH",
+            Self::Merge => "MERGE",
+            Self::Rebase => "REBASE",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Squash => Self::Merge,
+            Self::Merge => Self::Rebase,
+            Self::Rebase => Self::Squash,
+        }
+    }
+}
+
+/// Change made to a PR from the TUI (always confirmed first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrAction {
+    MarkReady,
+    Merge(MergeMethod),
+    EnableAutoMerge(MergeMethod),
+    /// Ask everyone who already reviewed to review again
+    RerequestReviews,
+    Close,
+}
+
+impl PrAction {
+    pub fn label(&self) -> String {
+        match self {
+            Self::MarkReady => "Mark ready for review".to_string(),
+            Self::Merge(method) => format!("Merge ({})", method.label()),
+            Self::EnableAutoMerge(method) => format!("Enable auto-merge ({})", method.label()),
+            Self::RerequestReviews => "Re-request reviews".to_string(),
+            Self::Close => "Close".to_string(),
+        }
+    }
+
+    /// Whether the action makes sense for a PR in this state
+    pub fn is_available(&self, status: GitHubPRStatus) -> bool {
+        use GitHubPRStatus::*;
+        match self {
+            Self::MarkReady => status == Draft,
+            Self::Merge(_) | Self::EnableAutoMerge(_) | Self::RerequestReviews => {
+                matches!(status, Open | ReviewRequested | ChangesRequested | Approved)
+            }
+            Self::Close => !matches!(status, Merged | Closed),
+        }
+    }
+}
+
+/// Open PR waiting on the user's review, directly or through one of their teams
+#[derive(Debug, Clone)]
+pub struct ReviewRequest {
+    pub pr: GitHubPR,
+    /// Login of the PR's author
+    pub author: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changed_files: u64,
+    /// Linear identifier found in the PR's branch
+    pub linked_identifier: Option<String>,
+    /// The Linear issue behind `linked_identifier`, when it could be loaded
+    pub linked_issue: Option<LinearIssue>,
+}
+
+impl ReviewRequest {
+    /// Lines changed (additions + deletions)
+    pub fn size(&self) -> u64 {
+        self.additions + self.deletions
+    }
+
+    /// T-shirt size of the change, by lines changed
+    pub fn size_label(&self) -> &'static str {
+        match self.size() {
+            0..=9 => "XS",
+            10..=99 => "S",
+            100..=499 => "M",
+            500..=999 => "L",
+            _ => "XL",
+        }
+    }
+}
+
+/// CI state of a PR, from GitHub's `statusCheckRollup`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CiStatus {
+    Passing,
+    Failing,
+    Pending,
+}
+
+impl CiStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Passing => "Passing",
+            Self::Failing => "Failing",
+            Self::Pending => "Pending",
+        }
+    }
+
+    /// Map a rollup or commit status `state` (SUCCESS, FAILURE, PENDING, ...)
+    pub fn from_state(state: &str) -> Option<Self> {
+        match state {
+            "SUCCESS" => Some(Self::Passing),
+            "FAILURE" | "ERROR" => Some(Self::Failing),
+            "PENDING" | "EXPECTED" => Some(Self::Pending),
+            _ => None,
+        }
+    }
+
+    /// Map a check run's `status` and `conclusion`; anything not finished is
+    /// pending, and only successful, neutral or skipped runs count as passing
+    pub fn from_check_run(status: &str, conclusion: Option<&str>) -> Self {
+        if status != "COMPLETED" {
+            return Self::Pending;
+        }
+        match conclusion {
+            Some("SUCCESS" | "NEUTRAL" | "SKIPPED") => Self::Passing,
+            _ => Self::Failing,
+        }
+    }
+}
+
+/// A single check run or commit status on a PR
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CiCheck {
+    pub name: String,
+    pub status: CiStatus,
+    /// Details page (the run's log or the status's target URL)
+    pub url: Option<String>,
+}
+
+impl CiCheck {
+    /// GitHub Actions job ID, from a details URL like
+    /// `https://github.com/o/r/actions/runs/123/job/456` (None for other CI)
+    pub fn actions_job_id(&self) -> Option<u64> {
+        let url = self.url.as_deref()?;
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let (before, job) = path.trim_end_matches('/').rsplit_once("/job/")?;
+        if !before.contains("/actions/runs/") {
+            return None;
+        }
+        job.parse().ok()
+    }
+}
+
+/// The end of a failed GitHub Actions job's log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobLog {
+    /// Job (check) name
+    pub name: String,
+    pub repo: String,
+    pub pr_number: u64,
+    /// Last `JOB_LOG_TAIL_LINES` lines, timestamps and ANSI colors removed
+    pub lines: Vec<String>,
+    /// Lines dropped from the start of the log
+    pub skipped: usize,
+}
+
+/// Lines of a job log kept for display
+pub const JOB_LOG_TAIL_LINES: usize = 500;
+
+/// Lines of a job log handed to an agent
+const JOB_LOG_EXCERPT_LINES: usize = 60;
+
+static ANSI_ESCAPE: Lazy<regex::Regex> =
+    Lazy::new(|| regex::Regex::new(r"\x1b\[[0-9;]*[A-Za-z]").unwrap());
+
+impl JobLog {
+    /// Keep the tail of a raw Actions log (`<timestamp> <text>` per line)
+    pub fn from_raw(pr: &GitHubPR, check: &CiCheck, raw: &str) -> Self {
+        let all: Vec<String> = raw
+            .trim_start_matches('\u{feff}')
+            .lines()
+            .map(|line| {
+                let text = match line.split_once(' ') {
+                    Some((stamp, text)) if is_log_timestamp(stamp) => text,
+                    _ => line,
+                };
+                ANSI_ESCAPE.replace_all(text, "").trim_end().to_string()
+            })
+            .collect();
+        let skipped = all.len().saturating_sub(JOB_LOG_TAIL_LINES);
+        Self {
+            name: check.name.clone(),
+            repo: pr.repo.clone(),
+            pr_number: pr.number,
+            lines: all.into_iter().skip(skipped).collect(),
+            skipped,
+        }
+    }
+
+    /// Whether a line reports an error (highlighted, and kept in excerpts).
+    /// Summaries such as `errors: 0` or `exited with code 0` don't count.
+    pub fn is_error_line(line: &str) -> bool {
+        let trimmed = line.trim_start();
+        let lower = trimmed.to_lowercase();
+        trimmed.starts_with("##[error]")
+            || lower.starts_with("error:")
+            || lower.starts_with("error[")
+            || lower.starts_with("failed")
+            || lower.contains(" error:")
+            || lower.contains("panicked at")
+            || lower.contains("assertion failed")
+            || Self::nonzero_exit_code(&lower)
+    }
+
+    /// Whether a line reports a process exiting with a non-zero code
+    fn nonzero_exit_code(lower: &str) -> bool {
+        let Some((_, rest)) = lower.split_once("exited with code") else {
+            return false;
+        };
+        rest.trim_start()
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .any(|c| c != '0')
+    }
+
+    /// The part of the log explaining the failure: every error line with a
+    /// little context (`…` marks gaps), or the last lines when nothing looks
+    /// like an error. Capped at `JOB_LOG_EXCERPT_LINES`, keeping the end.
+    pub fn failure_excerpt(&self) -> Vec<&str> {
+        let len = self.lines.len();
+        let mut keep = vec![false; len];
+        for (i, line) in self.lines.iter().enumerate() {
+            if Self::is_error_line(line) {
+                keep[i.saturating_sub(3)..(i + 3).min(len)].fill(true);
+            }
+        }
+        if !keep.contains(&true) {
+            keep[len.saturating_sub(JOB_LOG_EXCERPT_LINES)..].fill(true);
+        }
+
+        let mut excerpt = Vec::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            if !keep[i] {
+                continue;
+            }
+            if i > 0 && !keep[i - 1] && !excerpt.is_empty() {
+                excerpt.push("…");
+            }
+            excerpt.push(line.as_str());
+        }
+        excerpt.split_off(excerpt.len().saturating_sub(JOB_LOG_EXCERPT_LINES))
+    }
+
+    /// Follow-up prompt asking an agent to fix the failure
+    pub fn agent_prompt(&self) -> String {
+        format!(
+            "CI job \"{}\" failed on PR #{} ({}). Find the cause and fix it. End of the job log:\n\n```\n{}\n```\n",
+            self.name,
+            self.pr_number,
+            self.repo,
+            self.failure_excerpt().join("\n")
+        )
+    }
+}
+
+/// `2024-05-01T12:34:56.1234567Z`, the prefix Actions puts on every log line
+fn is_log_timestamp(s: &str) -> bool {
+    s.len() >= 20 && s.ends_with('Z') && s.as_bytes()[4] == b'-' && s.as_bytes()[10] == b'T'
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VercelDeployment {
     pub id: String,
     pub url: String,
     pub status: VercelStatus,
     pub created_at: DateTime<Utc>,
+    /// Project name (needed to redeploy)
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// "production", or None for a preview
+    #[serde(default)]
+    pub target: Option<String>,
+    #[serde(default)]
+    pub commit_sha: Option<String>,
+    /// First line of the commit message
+    #[serde(default)]
+    pub commit_message: Option<String>,
+    #[serde(default)]
+    pub source: DeploymentSource,
+}
+
+/// Where a deployment was found
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeploymentSource {
+    /// Vercel's API
+    #[default]
+    Vercel,
+    /// The deployments and commit statuses Vercel reports on the PR's head
+    /// commit in GitHub (no Vercel token, or Vercel rate limited)
+    GitHub,
+}
+
+impl VercelDeployment {
+    pub fn is_production(&self) -> bool {
+        self.target.as_deref() == Some("production")
+    }
+
+    /// Abbreviated commit SHA, as GitHub shows it
+    pub fn short_sha(&self) -> Option<&str> {
+        self.commit_sha
+            .as_deref()
+            .map(|sha| &sha[..sha.len().min(7)])
+    }
+}
+
+/// Change made to a Vercel deployment from the TUI (always confirmed first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeploymentAction {
+    /// Build the deployment's commit again
+    Redeploy,
+    Cancel,
+    /// Point the production domains at the deployment
+    Promote,
+}
+
+impl DeploymentAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Redeploy => "Redeploy",
+            Self::Cancel => "Cancel build",
+            Self::Promote => "Promote to production",
+        }
+    }
+
+    /// Whether the action makes sense for this deployment
+    pub fn is_available(&self, deployment: &VercelDeployment) -> bool {
+        use VercelStatus::*;
+        match self {
+            Self::Redeploy => matches!(deployment.status, Ready | Error | Canceled),
+            Self::Cancel => matches!(deployment.status, Queued | Building),
+            Self::Promote => deployment.status == Ready && !deployment.is_production(),
+        }
+    }
+}
+
+/// Build output of a Vercel deployment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildLog {
+    pub deployment_id: String,
+    /// Deployment status when the log was fetched
+    pub status: VercelStatus,
+    /// Build output, ANSI colors removed
+    pub lines: Vec<String>,
+    /// Index of the first line reporting an error
+    pub first_error: Option<usize>,
+    /// Last build event read; later polls only fetch the events after it
+    pub cursor: Option<BuildLogCursor>,
+}
+
+/// Position of a Vercel build event in a deployment's event stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildLogCursor {
+    /// Event timestamp (ms since epoch), sent as `since=`
+    pub created: i64,
+    /// Event ID, to skip the events `since=` returns again
+    pub id: Option<String>,
+}
+
+impl BuildLog {
+    pub fn new(deployment_id: &str, status: VercelStatus, raw_lines: Vec<String>) -> Self {
+        let lines: Vec<String> = raw_lines
+            .iter()
+            .flat_map(|text| text.lines())
+            .map(|line| ANSI_ESCAPE.replace_all(line, "").trim_end().to_string())
+            .collect();
+        let first_error = lines.iter().position(|line| JobLog::is_error_line(line));
+        Self {
+            deployment_id: deployment_id.to_string(),
+            status,
+            lines,
+            first_error,
+            cursor: None,
+        }
+    }
+
+    /// Add the output of a later poll, which only holds newer events
+    pub fn append(&mut self, newer: BuildLog) {
+        let offset = self.lines.len();
+        self.status = newer.status;
+        self.lines.extend(newer.lines);
+        self.first_error = self
+            .first_error
+            .or(newer.first_error.map(|idx| idx + offset));
+        if newer.cursor.is_some() {
+            self.cursor = newer.cursor;
+        }
+    }
+
+    /// Whether the build is still running, so the log keeps growing
+    pub fn is_live(&self) -> bool {
+        matches!(self.status, VercelStatus::Queued | VercelStatus::Building)
+    }
+
+    /// The output from a few lines before the first error on (or the last
+    /// lines when nothing looks like an error), capped at
+    /// `JOB_LOG_EXCERPT_LINES`
+    pub fn failure_excerpt(&self) -> &[String] {
+        let start = match self.first_error {
+            Some(idx) => idx.saturating_sub(3),
+            None => self.lines.len().saturating_sub(JOB_LOG_EXCERPT_LINES),
+        };
+        let end = (start + JOB_LOG_EXCERPT_LINES).min(self.lines.len());
+        &self.lines[start..end]
+    }
+
+    /// Follow-up prompt asking an agent to fix the failed build
+    pub fn agent_prompt(&self, deployment_url: &str) -> String {
+        format!(
+            "The Vercel deployment {} failed to build. Find the cause and fix it. Build log from the first error:\n\n```\n{}\n```\n",
+            deployment_url,
+            self.failure_excerpt().join("\n")
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -388,6 +1302,18 @@ pub struct AgentSession {
     /// Rich activity data for display
     #[serde(default)]
     pub activity: AgentActivity,
+    /// Teammate running this session (`None` = a session on this machine)
+    #[serde(default)]
+    pub owner: Option<SessionOwner>,
+}
+
+/// Teammate a shared session belongs to (team view)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionOwner {
+    /// Member name from the teammate's `[team]` config
+    pub name: String,
+    /// Teammate's Linear user ID (matches the assignee filter)
+    pub linear_user_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -457,22 +1383,186 @@ pub enum SortMode {
     ByLastUpdated,
     ByPriority,
     ByPRActivity,
+    ByDueDate,
+    ByCIStatus,
 }
 
-/// Section type for the two-section agent-first view
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Grouping dimension for the issues below the Agent Sessions section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    /// Single "Issues" section
+    #[default]
+    None,
+    Project,
+    Cycle,
+    Team,
+    Label,
+    Assignee,
+    Repository,
+}
+
+impl GroupBy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Project => "Project",
+            Self::Cycle => "Cycle",
+            Self::Team => "Team",
+            Self::Label => "Label",
+            Self::Assignee => "Assignee",
+            Self::Repository => "Repository",
+        }
+    }
+
+    /// Menu key used to select this grouping in the sort menu
+    pub fn key(&self) -> char {
+        match self {
+            Self::None => 'n',
+            Self::Project => 'p',
+            Self::Cycle => 'c',
+            Self::Team => 't',
+            Self::Label => 'l',
+            Self::Assignee => 'a',
+            Self::Repository => 'r',
+        }
+    }
+
+    pub fn from_key(key: char) -> Option<Self> {
+        Self::all().find(|g| g.key() == key)
+    }
+
+    pub fn from_config_str(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "" | "none" | "off" => Some(Self::None),
+            "project" => Some(Self::Project),
+            "cycle" | "sprint" => Some(Self::Cycle),
+            "team" => Some(Self::Team),
+            "label" | "labels" => Some(Self::Label),
+            "assignee" | "owner" => Some(Self::Assignee),
+            "repo" | "repository" => Some(Self::Repository),
+            _ => None,
+        }
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        [
+            Self::None,
+            Self::Project,
+            Self::Cycle,
+            Self::Team,
+            Self::Label,
+            Self::Assignee,
+            Self::Repository,
+        ]
+        .into_iter()
+    }
+
+    /// Header label for the group a workstream falls into.
+    ///
+    /// Issues with several labels are grouped under their first label.
+    pub fn group_name(&self, ws: &Workstream) -> String {
+        let issue = &ws.linear_issue;
+        let value = match self {
+            Self::None => return SectionType::Issues.display_name().to_string(),
+            Self::Project => issue.project.clone(),
+            Self::Cycle => issue.cycle.as_ref().map(|c| c.name.clone()),
+            Self::Team => issue.team.clone(),
+            Self::Label => issue.labels.first().map(|l| l.name.clone()),
+            Self::Assignee => issue.assignee_name.clone(),
+            Self::Repository => ws.github_pr.as_ref().map(|pr| pr.repo.clone()),
+        };
+        value.unwrap_or_else(|| self.empty_group_name().to_string())
+    }
+
+    /// Ordering key for a workstream's group: named groups first (cycles by
+    /// number, everything else alphabetically), the "No ..." group last
+    fn group_order(&self, ws: &Workstream) -> (bool, i32, String) {
+        let name = self.group_name(ws);
+        let missing = name == self.empty_group_name();
+        let number = match self {
+            Self::Cycle => ws
+                .linear_issue
+                .cycle
+                .as_ref()
+                .map(|c| c.number)
+                .unwrap_or(0),
+            _ => 0,
+        };
+        (missing, number, name.to_lowercase())
+    }
+
+    fn empty_group_name(&self) -> &'static str {
+        match self {
+            Self::None => "Issues",
+            Self::Project => "No project",
+            Self::Cycle => "No cycle",
+            Self::Team => "No team",
+            Self::Label => "No label",
+            Self::Assignee => "Unassigned",
+            Self::Repository => "No repository",
+        }
+    }
+}
+
+/// How issues blocked by open issues are treated in the list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockedMode {
+    /// Sorted like everything else
+    #[default]
+    Show,
+    /// Sorted below unblocked issues in the same group
+    Demote,
+    /// Filtered out
+    Hide,
+}
+
+impl BlockedMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Show => "Show",
+            Self::Demote => "Demote",
+            Self::Hide => "Hide",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Show => Self::Demote,
+            Self::Demote => Self::Hide,
+            Self::Hide => Self::Show,
+        }
+    }
+
+    pub fn from_config_str(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "show" => Some(Self::Show),
+            "demote" => Some(Self::Demote),
+            "hide" => Some(Self::Hide),
+            _ => None,
+        }
+    }
+}
+
+/// Section type for the agent-first view
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SectionType {
     /// Issues with active agent sessions
     AgentSessions,
     /// Issues without agents
     Issues,
+    /// Issues without agents sharing a group value (see `GroupBy`)
+    Group(String),
+    /// Other people's PRs waiting on the user's review
+    ReviewRequests,
 }
 
 impl SectionType {
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> &str {
         match self {
             Self::AgentSessions => "Agent Sessions",
             Self::Issues => "Issues",
+            Self::Group(name) => name,
+            Self::ReviewRequests => "Review Requests",
         }
     }
 }
@@ -486,6 +1576,8 @@ pub enum VisualItem {
     AgentSession { ws_idx: usize, session_idx: usize },
     /// Workstream row (selectable) - contains index into workstreams vec
     Workstream(usize),
+    /// Review request row (selectable) - index into review_requests vec
+    ReviewRequest(usize),
 }
 
 impl SortMode {
@@ -497,7 +1589,9 @@ impl SortMode {
             Self::ByVercelStatus => Self::ByLastUpdated,
             Self::ByLastUpdated => Self::ByPriority,
             Self::ByPriority => Self::ByPRActivity,
-            Self::ByPRActivity => Self::ByLinearStatus,
+            Self::ByPRActivity => Self::ByDueDate,
+            Self::ByDueDate => Self::ByCIStatus,
+            Self::ByCIStatus => Self::ByLinearStatus,
         }
     }
 
@@ -509,6 +1603,8 @@ impl SortMode {
             Self::ByLastUpdated => "Last Updated",
             Self::ByPriority => "Priority",
             Self::ByPRActivity => "PR Activity",
+            Self::ByDueDate => "Due Date",
+            Self::ByCIStatus => "CI Status",
         }
     }
 
@@ -520,6 +1616,8 @@ impl SortMode {
             4 => Some(Self::ByPriority),
             5 => Some(Self::ByLinearStatus),
             6 => Some(Self::ByPRActivity),
+            7 => Some(Self::ByDueDate),
+            8 => Some(Self::ByCIStatus),
             _ => None,
         }
     }
@@ -532,6 +1630,8 @@ impl SortMode {
             "vercel" | "vercel_status" | "vercelstatus" => Some(Self::ByVercelStatus),
             "updated" | "last_updated" | "lastupdated" => Some(Self::ByLastUpdated),
             "pr" | "pr_activity" | "practivity" => Some(Self::ByPRActivity),
+            "due" | "due_date" | "duedate" | "deadline" => Some(Self::ByDueDate),
+            "ci" | "ci_status" | "cistatus" | "checks" => Some(Self::ByCIStatus),
             _ => None,
         }
     }
@@ -546,12 +1646,62 @@ pub struct AppState {
     pub last_refresh: Option<DateTime<Utc>>,
     pub collapsed_sections: HashSet<SectionType>,
     pub sort_mode: SortMode,
+    pub group_by: GroupBy,
+    pub blocked_mode: BlockedMode,
+    /// Assignee filter as applied to agent rows (`None` = show every session)
+    pub session_owner_filter: Option<SessionOwnerFilter>,
+    /// PRs waiting on the user's review (listed in their own section)
+    pub review_requests: Vec<ReviewRequest>,
+}
+
+/// Assignee filter for agent rows: sessions are kept by who runs them rather
+/// than by the linked issue's assignee
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionOwnerFilter {
+    /// Keep sessions running on this machine ("me")
+    pub local: bool,
+    /// Keep teammates' sessions that have no Linear user ID ("unassigned")
+    pub unknown: bool,
+    /// Keep teammates' sessions owned by these Linear user IDs
+    pub user_ids: HashSet<String>,
+}
+
+impl SessionOwnerFilter {
+    pub fn matches(&self, session: &AgentSession) -> bool {
+        match &session.owner {
+            None => self.local,
+            Some(owner) => match &owner.linear_user_id {
+                Some(id) => self.user_ids.contains(id),
+                None => self.unknown,
+            },
+        }
+    }
 }
 
 impl AppState {
+    /// Whether a session row passes the assignee filter
+    pub fn shows_session(&self, session: &AgentSession) -> bool {
+        self.session_owner_filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches(session))
+    }
+
+    /// Section a workstream's row is rendered in
+    pub fn section_for(&self, ws: &Workstream) -> SectionType {
+        if !ws.agent_sessions.is_empty() || ws.agent_session.is_some() {
+            SectionType::AgentSessions
+        } else if self.group_by == GroupBy::None {
+            SectionType::Issues
+        } else {
+            SectionType::Group(self.group_by.group_name(ws))
+        }
+    }
+
     /// Get the sort key for a workstream based on current sort mode
+    /// Blocked issues sort after unblocked ones when `BlockedMode::Demote` is on
     fn workstream_sort_key(&self, ws: &Workstream) -> impl Ord {
-        match self.sort_mode {
+        let demoted = self.blocked_mode == BlockedMode::Demote && ws.linear_issue.is_blocked();
+        let key = match self.sort_mode {
             SortMode::ByLinearStatus => {
                 // Default order - sort by issue identifier
                 (0u8, ws.linear_issue.identifier.clone(), 0i64, 0u8, 0u8)
@@ -593,7 +1743,21 @@ impl AppState {
                     .unwrap_or(99);
                 (0u8, String::new(), 0i64, pr, 0u8)
             }
-        }
+            SortMode::ByDueDate => {
+                // Soonest deadline first, issues without one last
+                let ts = ws
+                    .linear_issue
+                    .deadline()
+                    .map(|d| d.timestamp())
+                    .unwrap_or(i64::MAX);
+                (0u8, String::new(), ts, 0u8, 0u8)
+            }
+            SortMode::ByCIStatus => {
+                let status = ws.worst_ci_status().map(ci_sort_order).unwrap_or(99);
+                (status, String::new(), 0i64, 0u8, 0u8)
+            }
+        };
+        (demoted, key)
     }
 
     /// Hierarchically sort workstreams maintaining parent-child relationships.
@@ -769,8 +1933,10 @@ impl AppState {
                 ws.agent_session.as_slice()
             };
 
-            for (session_idx, _session) in sessions.iter().enumerate() {
-                session_rows.push((idx, session_idx));
+            for (session_idx, session) in sessions.iter().enumerate() {
+                if self.shows_session(session) {
+                    session_rows.push((idx, session_idx));
+                }
             }
         }
 
@@ -819,6 +1985,19 @@ impl AppState {
             }
         }
 
+        // ── Review Requests section (longest waiting first) ──
+        if !self.review_requests.is_empty() {
+            items.push(VisualItem::SectionHeader(SectionType::ReviewRequests));
+            if !self
+                .collapsed_sections
+                .contains(&SectionType::ReviewRequests)
+            {
+                let mut review_rows: Vec<usize> = (0..self.review_requests.len()).collect();
+                review_rows.sort_by_key(|&idx| self.review_requests[idx].created_at);
+                items.extend(review_rows.into_iter().map(VisualItem::ReviewRequest));
+            }
+        }
+
         // ── Issues section (one row per issue, no sessions) ──
         let mut issue_rows: Vec<usize> = self
             .workstreams
@@ -850,6 +2029,11 @@ impl AppState {
                 })
         });
 
+        if self.group_by != GroupBy::None {
+            self.push_grouped_issue_rows(&issue_rows, &mut items);
+            return items;
+        }
+
         if !issue_rows.is_empty() {
             items.push(VisualItem::SectionHeader(SectionType::Issues));
             if !self.collapsed_sections.contains(&SectionType::Issues) {
@@ -861,6 +2045,43 @@ impl AppState {
 
         items
     }
+
+    /// Emit one collapsible section per group value, with rows inside each
+    /// group ordered by the current sort mode (children kept under parents)
+    fn push_grouped_issue_rows(&self, issue_rows: &[usize], items: &mut Vec<VisualItem>) {
+        use std::collections::HashMap;
+
+        let mut groups: HashMap<(bool, i32, String), (String, Vec<&Workstream>)> = HashMap::new();
+        for &idx in issue_rows {
+            let ws = &self.workstreams[idx];
+            groups
+                .entry(self.group_by.group_order(ws))
+                .or_insert_with(|| (self.group_by.group_name(ws), Vec::new()))
+                .1
+                .push(ws);
+        }
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        groups.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let id_to_idx: HashMap<&str, usize> = issue_rows
+            .iter()
+            .map(|&idx| (self.workstreams[idx].linear_issue.id.as_str(), idx))
+            .collect();
+
+        for (_, (name, members)) in groups {
+            let section = SectionType::Group(name);
+            let collapsed = self.collapsed_sections.contains(&section);
+            items.push(VisualItem::SectionHeader(section));
+            if collapsed {
+                continue;
+            }
+            for ws in self.hierarchical_sort(&members) {
+                if let Some(&idx) = id_to_idx.get(ws.linear_issue.id.as_str()) {
+                    items.push(VisualItem::Workstream(idx));
+                }
+            }
+        }
+    }
 }
 
 fn pr_sort_order(status: GitHubPRStatus) -> u8 {
@@ -876,6 +2097,15 @@ fn pr_sort_order(status: GitHubPRStatus) -> u8 {
     }
 }
 
+fn ci_sort_order(status: CiStatus) -> u8 {
+    match status {
+        // Failing first
+        CiStatus::Failing => 0,
+        CiStatus::Pending => 1,
+        CiStatus::Passing => 2,
+    }
+}
+
 fn agent_sort_order(status: AgentStatus) -> u8 {
     match status {
         // Waiting for input is most urgent
@@ -24,7 +24,9 @@ pub fn sort_children(
         SortMode::ByAgentStatus
         | SortMode::ByVercelStatus
         | SortMode::ByPRActivity
-        | SortMode::ByLastUpdated => {
+        | SortMode::ByLastUpdated
+        | SortMode::ByDueDate
+        | SortMode::ByCIStatus => {
             children.sort_by_key(|c| c.status.sort_order());
         }
     }
@@ -44,6 +46,7 @@ mod tests {
             url: format!("https://linear.app/test/{}", id),
             status,
             priority,
+            estimate: None,
         }
     }
 
@@ -90,6 +93,8 @@ mod tests {
             SortMode::ByVercelStatus,
             SortMode::ByPRActivity,
             SortMode::ByLastUpdated,
+            SortMode::ByDueDate,
+            SortMode::ByCIStatus,
         ] {
             let sorted = sort_children(children.clone(), mode);
             assert_eq!(sorted[0].id, "2"); // InProgress
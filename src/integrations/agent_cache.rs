@@ -1,18 +1,29 @@
 //! Agent session caching for efficient lookups during refresh
 //!
 //! The `AgentSessionCache` pre-loads all Claude and OpenClaw sessions once per refresh
-//! cycle and provides O(1) lookup by git branch. This dramatically reduces I/O:
+//! cycle and provides O(1) lookup by git branch (exact match against Linear's
+//! suggested `branchName` first, then by issue identifier). This dramatically reduces I/O:
 //! - Before: 100 issues = 100 file reads per issue
 //! - After: 100 issues = 1 file read for Claude + 1 directory scan for OpenClaw
 
+use super::session_links::SessionLinks;
+use crate::config::DEFAULT_IDENTIFIER_PATTERN;
 use crate::data::{AgentSession, AgentStatus, AgentType};
 use once_cell::sync::Lazy;
+use regex::Regex;
 use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-static ISSUE_ID_RE: Lazy<regex::Regex> =
-    Lazy::new(|| regex::Regex::new(r"(?i)([A-Z]{2,5}-\d+)").unwrap());
+static ISSUE_ID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(DEFAULT_IDENTIFIER_PATTERN).unwrap());
+
+/// Compile `linear.identifier_pattern`, falling back to the default pattern if invalid.
+pub fn identifier_regex(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap_or_else(|e| {
+        tracing::warn!("Invalid identifier pattern {:?}: {}", pattern, e);
+        ISSUE_ID_RE.clone()
+    })
+}
 
 /// Pre-loaded agent session cache for a single refresh cycle.
 ///
@@ -35,12 +46,13 @@ pub struct AgentSessionCache {
 /// - `feat/DRE-380-unified-orchestration` -> `DRE-380`
 /// - `fix/dre-123-bug-fix` -> `DRE-123`
 /// - `DRE-456-feature` -> `DRE-456`
+/// - `user/web2-31-login` -> `WEB2-31`
 ///
+/// Uses the first capture group of `re` (or the whole match without groups).
 /// Returns uppercase identifier for case-insensitive matching.
-fn extract_issue_id(branch: &str) -> Option<String> {
-    ISSUE_ID_RE
-        .captures(branch)
-        .and_then(|caps| caps.get(1))
+pub fn extract_issue_id(re: &Regex, branch: &str) -> Option<String> {
+    re.captures(branch)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(0)))
         .map(|m| m.as_str().to_uppercase())
 }
 
@@ -139,6 +151,15 @@ impl AgentSessionCache {
     pub fn from_sessions(
         claude_sessions: Vec<AgentSession>,
         openclaw_sessions: Vec<AgentSession>,
+    ) -> Self {
+        Self::from_sessions_with_pattern(claude_sessions, openclaw_sessions, &ISSUE_ID_RE)
+    }
+
+    /// Like `from_sessions`, extracting identifiers from branches with `identifier_re`.
+    pub fn from_sessions_with_pattern(
+        claude_sessions: Vec<AgentSession>,
+        openclaw_sessions: Vec<AgentSession>,
+        identifier_re: &Regex,
     ) -> Self {
         let mut by_branch: HashMap<String, Vec<AgentSession>> = HashMap::new();
         let mut by_identifier: HashMap<String, Vec<AgentSession>> = HashMap::new();
@@ -157,7 +178,7 @@ impl AgentSessionCache {
                     .or_default()
                     .push(session.clone());
                 // Also index by issue identifier extracted from branch name
-                if let Some(id) = extract_issue_id(branch) {
+                if let Some(id) = extract_issue_id(identifier_re, branch) {
                     by_identifier.entry(id).or_default().push(session);
                 }
             }
@@ -175,7 +196,7 @@ impl AgentSessionCache {
                     .or_default()
                     .push(session.clone());
                 // Also index by identifier
-                if let Some(id) = extract_issue_id(branch) {
+                if let Some(id) = extract_issue_id(identifier_re, branch) {
                     by_identifier.entry(id).or_default().push(session);
                 }
             }
@@ -195,12 +216,15 @@ impl AgentSessionCache {
     /// - 1 directory scan for OpenClaw sessions (~/.openclaw/agents/*/sessions/)
     ///
     /// Errors in either source are logged and treated as empty lists.
-    pub async fn load() -> Self {
+    pub async fn load(identifier_pattern: &str) -> Self {
+        let mut complete = true;
+
         // Load Claude sessions (single file read)
         let claude_sessions = match super::claude::find_all_sessions().await {
             Ok(sessions) => sessions,
             Err(e) => {
                 tracing::debug!("Failed to load Claude sessions: {}", e);
+                complete = false;
                 vec![]
             }
         };
@@ -210,11 +234,53 @@ impl AgentSessionCache {
             Ok(sessions) => sessions,
             Err(e) => {
                 tracing::debug!("Failed to load OpenClaw sessions: {}", e);
+                complete = false;
                 vec![]
             }
         };
 
-        Self::from_sessions(claude_sessions, openclaw_sessions)
+        let cache = Self::from_sessions_with_pattern(
+            claude_sessions,
+            openclaw_sessions,
+            &identifier_regex(identifier_pattern),
+        );
+
+        // Only prune links against a complete session list, or a source that
+        // failed to load would lose its links
+        let links = if complete {
+            super::session_links::load_pruned(&cache.all_sessions)
+        } else {
+            super::session_links::load()
+        };
+        let links = match links {
+            Ok(links) => links,
+            Err(e) => {
+                tracing::debug!("Failed to load session links: {}", e);
+                Default::default()
+            }
+        };
+
+        cache.with_links(&links)
+    }
+
+    /// Index sessions explicitly linked to an issue under that issue's identifier.
+    ///
+    /// Used for sessions whose branch doesn't carry the identifier (e.g. an
+    /// issue created from an unlinked session).
+    pub fn with_links(mut self, links: &SessionLinks) -> Self {
+        for session in &self.all_sessions {
+            let Some(identifier) = links.identifier_for(session) else {
+                continue;
+            };
+            let sessions = self
+                .by_identifier
+                .entry(identifier.to_uppercase())
+                .or_default();
+            if !sessions.iter().any(|s| s.id == session.id) {
+                sessions.push(session.clone());
+            }
+        }
+        self
     }
 
     /// Find an agent session for a git branch.
@@ -248,9 +314,9 @@ impl AgentSessionCache {
 
     /// Find all agent sessions by branch and/or identifier.
     ///
-    /// Identifier matches are included first, then branch matches are unioned.
-    /// Results are filtered by repo hint when provided. Without a repo hint,
-    /// ambiguous branch matches are dropped to avoid mis-linking.
+    /// Exact branch matches (Linear's suggested `branchName`) are included first,
+    /// then identifier matches are unioned. Results are filtered by repo hint when
+    /// provided. Without a repo hint, ambiguous matches are dropped to avoid mis-linking.
     pub fn find_all_for_branch_or_identifier(
         &self,
         branch: Option<&str>,
@@ -261,17 +327,15 @@ impl AgentSessionCache {
         let mut seen_ids: HashSet<String> = HashSet::new();
         let key = identifier.to_uppercase();
 
-        if let Some(sessions) = self.by_identifier.get(&key) {
+        let branch_sessions = branch.and_then(|b| self.by_branch.get(b));
+        let identifier_sessions = self.by_identifier.get(&key);
+        for sessions in [branch_sessions, identifier_sessions].into_iter().flatten() {
             if repo_hint.is_some() && !sessions.is_empty() {
                 let repo_name = repo_name_from_hint(repo_hint.unwrap_or_default());
-                let filtered: Vec<AgentSession> = sessions
-                    .iter()
-                    .filter(|s| matches_repo_hint(s, repo_name))
-                    .cloned()
-                    .collect();
-                for session in filtered {
-                    if seen_ids.insert(session.id.clone()) {
-                        results.push(session);
+                for session in sessions {
+                    if matches_repo_hint(session, repo_name) && seen_ids.insert(session.id.clone())
+                    {
+                        results.push(session.clone());
                     }
                 }
             } else if !branch_is_ambiguous_without_repo_hint(sessions) {
@@ -283,33 +347,12 @@ impl AgentSessionCache {
             }
         }
 
-        if let Some(branch) = branch {
-            if let Some(sessions) = self.by_branch.get(branch) {
-                if repo_hint.is_some() && !sessions.is_empty() {
-                    let repo_name = repo_name_from_hint(repo_hint.unwrap_or_default());
-                    for session in sessions {
-                        if matches_repo_hint(session, repo_name)
-                            && seen_ids.insert(session.id.clone())
-                        {
-                            results.push(session.clone());
-                        }
-                    }
-                } else if !branch_is_ambiguous_without_repo_hint(sessions) {
-                    for session in sessions {
-                        if seen_ids.insert(session.id.clone()) {
-                            results.push(session.clone());
-                        }
-                    }
-                }
-            }
-        }
-
         results
     }
 
     /// Find an agent session by branch OR identifier (fallback).
     ///
-    /// Tries identifier match first, then falls back to branch match.
+    /// Prefers sessions on the exact branch, then falls back to identifier matches.
     /// This handles cases where Linear's branchName doesn't match the git branch.
     pub fn find_for_branch_or_identifier(
         &self,
@@ -318,7 +361,16 @@ impl AgentSessionCache {
         repo_hint: Option<&str>,
     ) -> Option<AgentSession> {
         let sessions = self.find_all_for_branch_or_identifier(branch, identifier, repo_hint);
-        select_best_session(&sessions, repo_hint)
+        let exact: Vec<AgentSession> = sessions
+            .iter()
+            .filter(|s| branch.is_some() && s.git_branch.as_deref() == branch)
+            .cloned()
+            .collect();
+        if exact.is_empty() {
+            select_best_session(&sessions, repo_hint)
+        } else {
+            select_best_session(&exact, repo_hint)
+        }
     }
 
     /// Find an agent session for a working directory (legacy).
@@ -373,6 +425,7 @@ mod tests {
             started_at: Utc::now(),
             last_activity: Utc::now(),
             window_id: None,
+            owner: None,
             activity: Default::default(),
         }
     }
@@ -439,21 +492,55 @@ mod tests {
     fn test_extract_issue_id() {
         // Common branch naming patterns
         assert_eq!(
-            extract_issue_id("feat/DRE-380-unified-orchestration"),
+            extract_issue_id(&ISSUE_ID_RE, "feat/DRE-380-unified-orchestration"),
             Some("DRE-380".to_string())
         );
         assert_eq!(
-            extract_issue_id("fix/dre-123-bug-fix"),
+            extract_issue_id(&ISSUE_ID_RE, "fix/dre-123-bug-fix"),
             Some("DRE-123".to_string())
         );
         assert_eq!(
-            extract_issue_id("DRE-456-feature"),
+            extract_issue_id(&ISSUE_ID_RE, "DRE-456-feature"),
             Some("DRE-456".to_string())
         );
-        assert_eq!(extract_issue_id("ABC-789"), Some("ABC-789".to_string()));
+        assert_eq!(
+            extract_issue_id(&ISSUE_ID_RE, "ABC-789"),
+            Some("ABC-789".to_string())
+        );
         // No match
-        assert_eq!(extract_issue_id("main"), None);
-        assert_eq!(extract_issue_id("feature-branch"), None);
+        assert_eq!(extract_issue_id(&ISSUE_ID_RE, "main"), None);
+        assert_eq!(extract_issue_id(&ISSUE_ID_RE, "feature-branch"), None);
+    }
+
+    #[test]
+    fn test_extract_issue_id_long_and_numeric_team_keys() {
+        assert_eq!(
+            extract_issue_id(&ISSUE_ID_RE, "user/platform-42-cache"),
+            Some("PLATFORM-42".to_string())
+        );
+        assert_eq!(
+            extract_issue_id(&ISSUE_ID_RE, "feat/web2-31-login"),
+            Some("WEB2-31".to_string())
+        );
+        // Digits can't start a team key
+        assert_eq!(extract_issue_id(&ISSUE_ID_RE, "release/2024-10"), None);
+    }
+
+    #[test]
+    fn test_extract_issue_id_custom_pattern() {
+        // Branches that carry only the issue number
+        let re = identifier_regex(r"ticket/(\d+)");
+        assert_eq!(
+            extract_issue_id(&re, "ticket/4512-cleanup"),
+            Some("4512".to_string())
+        );
+
+        // Invalid patterns fall back to the default
+        let re = identifier_regex("([A-Z");
+        assert_eq!(
+            extract_issue_id(&re, "fix/dre-1"),
+            Some("DRE-1".to_string())
+        );
     }
 
     #[test]
@@ -527,4 +614,67 @@ mod tests {
             .find_for_branch_or_identifier(Some("other"), "XYZ-999", None)
             .is_none());
     }
+
+    #[test]
+    fn test_exact_branch_match_preferred_over_identifier() {
+        let mut on_branch = make_session(
+            "s1",
+            "/project",
+            Some("me/dre-380-unified-orchestration"),
+            AgentType::ClaudeCode,
+        );
+        on_branch.status = AgentStatus::Idle;
+        let by_identifier = make_session(
+            "s2",
+            "/project",
+            Some("dre-380-follow-up"),
+            AgentType::ClaudeCode,
+        );
+
+        let cache = AgentSessionCache::from_sessions(vec![by_identifier, on_branch], vec![]);
+
+        let ids: Vec<String> = cache
+            .find_all_for_branch_or_identifier(
+                Some("me/dre-380-unified-orchestration"),
+                "DRE-380",
+                None,
+            )
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(ids, vec!["s1", "s2"]);
+
+        // The running identifier match would win on status alone
+        let found = cache
+            .find_for_branch_or_identifier(
+                Some("me/dre-380-unified-orchestration"),
+                "DRE-380",
+                None,
+            )
+            .unwrap();
+        assert_eq!(found.id, "s1");
+    }
+
+    #[test]
+    fn test_linked_session_found_by_identifier() {
+        use crate::integrations::session_links::{SessionLink, SessionLinks};
+
+        let linked = make_session("s1", "/sandbox", Some("main"), AgentType::ClaudeCode);
+        let later = make_session("s2", "/sandbox", Some("main"), AgentType::ClaudeCode);
+        let other_repo = make_session("s3", "/elsewhere", Some("main"), AgentType::ClaudeCode);
+
+        let mut links = SessionLinks::default();
+        links.add(SessionLink::for_session(&linked, "DRE-412"));
+
+        let cache = AgentSessionCache::from_sessions(vec![linked, later, other_repo], vec![])
+            .with_links(&links);
+
+        // A later session on the same branch in the same checkout isn't linked
+        let ids: Vec<String> = cache
+            .find_all_for_branch_or_identifier(None, "dre-412", None)
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(ids, vec!["s1"]);
+    }
 }
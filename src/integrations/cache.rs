@@ -202,14 +202,19 @@ mod tests {
                 assignee_id: None,
                 assignee_name: None,
                 estimate: None,
+                due_date: None,
+                sla_breaches_at: None,
                 created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
                 updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
                 attachments: Vec::new(),
                 parent: None,
                 children: Vec::new(),
+                relations: vec![],
+                workspace: None,
             },
             github_pr: None,
             vercel_deployment: None,
+            linked_prs: vec![],
             agent_sessions: vec![],
             agent_session: None,
             stale: false,
@@ -119,6 +119,34 @@ pub async fn focus_session_window(session: &AgentSession) -> Result<()> {
     Ok(())
 }
 
+/// Hand a prompt to a session: copy it to the clipboard and focus the
+/// session's window so it can be pasted in
+pub async fn hand_prompt_to_session(session: &AgentSession, prompt: &str) -> Result<()> {
+    copy_to_clipboard(prompt).await?;
+    focus_session_window(session).await
+}
+
+async fn copy_to_clipboard(text: &str) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    #[cfg(target_os = "macos")]
+    let program = "pbcopy";
+    #[cfg(not(target_os = "macos"))]
+    let program = "clip.exe";
+
+    let mut child = tokio::process::Command::new(program)
+        .stdin(std::process::Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes()).await?;
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("{} exited with {}", program, status);
+    }
+    Ok(())
+}
+
 /// Initialize Claude integration (call on startup)
 pub fn init() -> Result<()> {
     // Ensure hooks are installed
@@ -425,6 +425,7 @@ pub fn sessions_from_state(state: &ClaudeState) -> Vec<AgentSession> {
                 started_at,
                 last_activity,
                 window_id: None,
+                owner: None,
                 activity: map_activity_to_data(&s.activity),
             }
         })
@@ -4,6 +4,8 @@
 //! - `AsyncTtlCache`: in-memory TTL cache with request coalescing (singleflight)
 //! - A persisted JSON cache for warm-starts
 //! - Simple backoff flags for rate limits (GitHub + Vercel)
+//! - ETag/Last-Modified validators for conditional requests, with counts of
+//!   the requests a `304 Not Modified` saved
 //!
 //! The UI refresh loop can be more frequent than GitHub/Vercel polling;
 //! this cache ensures we don't spam external APIs while keeping status
@@ -17,6 +19,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, Notify, OnceCell, RwLock};
@@ -140,6 +143,39 @@ where
             }
         }
     }
+
+    /// Whether a key holds an unexpired value or is being fetched.
+    pub async fn contains_fresh(&self, key: &K) -> bool {
+        match self.inner.lock().await.get(key) {
+            Some(Entry::Ready { expires_at, .. }) => Instant::now() < *expires_at,
+            Some(Entry::Loading { .. }) => true,
+            None => false,
+        }
+    }
+
+    /// Drop a key so the next lookup fetches it again. An in-flight fetch
+    /// still completes and stores its result.
+    pub async fn remove(&self, key: &K) {
+        let mut guard = self.inner.lock().await;
+        if let Some(Entry::Ready { .. }) = guard.get(key) {
+            guard.remove(key);
+        }
+    }
+
+    /// Replace a key's value (e.g. after a write made the cached copy stale).
+    pub async fn insert(&self, key: K, value: V, ttl: Duration) {
+        let mut guard = self.inner.lock().await;
+        if let Some(Entry::Loading { notify }) = guard.remove(&key) {
+            notify.notify_waiters();
+        }
+        guard.insert(
+            key,
+            Entry::Ready {
+                value: Arc::new(value),
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
 }
 
 // =============================================================================
@@ -159,6 +195,81 @@ struct PersistedEnrichmentCache {
 
     github: HashMap<String, PersistedValue<GitHubPR>>,
     vercel: HashMap<String, PersistedValue<Option<VercelDeployment>>>,
+    /// PR URL found by searching a repository for a head branch (None = no PR)
+    #[serde(default)]
+    branch_prs: HashMap<String, PersistedValue<Option<String>>>,
+
+    /// Validators and the parsed result of GitHub REST PR requests, by request URL
+    #[serde(default)]
+    github_conditional: HashMap<String, Conditional<GitHubPR>>,
+    /// Validators and the parsed result of Vercel deployment requests, by request URL
+    #[serde(default)]
+    vercel_conditional: HashMap<String, Conditional<Option<VercelDeployment>>>,
+}
+
+/// Response validators, sent back as `If-None-Match` / `If-Modified-Since`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    /// Validators of a response (None when it sent neither header)
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let validators = Self {
+            etag: header(reqwest::header::ETAG),
+            last_modified: header(reqwest::header::LAST_MODIFIED),
+        };
+        (validators.etag.is_some() || validators.last_modified.is_some()).then_some(validators)
+    }
+
+    /// Make `request` conditional on the resource having changed
+    pub fn apply(&self, mut request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if let Some(etag) = &self.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &self.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+        request
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Conditional<T> {
+    validators: Validators,
+    value: T,
+    /// Last time the entry was stored or looked up (entries from before this
+    /// field existed count as unused)
+    #[serde(default)]
+    used_at: DateTime<Utc>,
+}
+
+/// Conditional entries unused for this long are dropped (the PR or branch is
+/// most likely gone from the dashboard)
+const CONDITIONAL_MAX_AGE_DAYS: i64 = 7;
+/// Upper bound on conditional entries kept per service
+const CONDITIONAL_MAX_ENTRIES: usize = 500;
+
+/// Drop stale conditional entries, then the least recently used ones over
+/// `CONDITIONAL_MAX_ENTRIES`
+fn prune_conditional<T>(entries: &mut HashMap<String, Conditional<T>>, now: DateTime<Utc>) {
+    let cutoff = now - ChronoDuration::days(CONDITIONAL_MAX_AGE_DAYS);
+    entries.retain(|_, entry| entry.used_at > cutoff);
+
+    if entries.len() > CONDITIONAL_MAX_ENTRIES {
+        let mut used: Vec<DateTime<Utc>> = entries.values().map(|e| e.used_at).collect();
+        used.sort_unstable_by(|a, b| b.cmp(a));
+        let oldest_kept = used[CONDITIONAL_MAX_ENTRIES - 1];
+        entries.retain(|_, entry| entry.used_at >= oldest_kept);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -258,6 +369,34 @@ async fn persist_if_enabled(config: &Config, cache: &PersistedEnrichmentCache) {
     }
 }
 
+/// How long conditional entries are batched before the cache is written
+const PERSIST_DEBOUNCE: Duration = Duration::from_secs(5);
+
+static PERSIST_SCHEDULED: AtomicBool = AtomicBool::new(false);
+
+/// Write the cache once `PERSIST_DEBOUNCE` has passed, folding in every
+/// change made until then. For high-churn entries (validators) that are cheap
+/// to lose on exit.
+fn persist_debounced(config: &Config) {
+    if !config.cache.enabled || PERSIST_SCHEDULED.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    tokio::spawn(async {
+        tokio::time::sleep(PERSIST_DEBOUNCE).await;
+        PERSIST_SCHEDULED.store(false, Ordering::Release);
+        let Some(c) = GLOBAL_PERSISTED.get() else {
+            return;
+        };
+        let snapshot = c.read().await.clone();
+        let Ok(path) = persisted_cache_path() else {
+            return;
+        };
+        if let Err(e) = save_to_path(&path, &snapshot) {
+            tracing::warn!("Failed to persist enrichment cache: {e}");
+        }
+    });
+}
+
 pub fn normalize_github_pr_url(pr_url: &str) -> String {
     pr_url.trim().trim_end_matches('/').to_string()
 }
@@ -266,6 +405,10 @@ pub fn vercel_key(repo: &str, branch: &str) -> String {
     format!("{}:{}", repo, branch)
 }
 
+pub fn branch_pr_key(repo: &str, branch: &str) -> String {
+    format!("{}:{}", repo.to_lowercase(), branch)
+}
+
 fn github_in_backoff(cache: &PersistedEnrichmentCache) -> bool {
     cache
         .github_backoff_until
@@ -346,6 +489,126 @@ pub async fn set_cached_vercel(config: &Config, key: &str, value: Option<VercelD
     persist_if_enabled(config, &snapshot).await;
 }
 
+/// PR URL found for a branch (None: the branch had no PR), fresh for
+/// `ttl_secs`
+pub async fn get_cached_branch_pr(
+    config: &Config,
+    key: &str,
+    ttl_secs: u64,
+) -> Option<(Option<String>, bool /*fresh*/)> {
+    let c = persisted(config).await;
+    let c = c.read().await;
+    let entry = c.branch_prs.get(key)?;
+    Some((entry.value.clone(), entry.is_fresh(ttl_secs)))
+}
+
+pub async fn set_cached_branch_pr(config: &Config, key: &str, pr_url: Option<String>) {
+    let c = persisted(config).await;
+    let mut c = c.write().await;
+
+    c.branch_prs.insert(
+        key.to_string(),
+        PersistedValue {
+            fetched_at: Utc::now(),
+            value: pr_url,
+        },
+    );
+
+    let snapshot = c.clone();
+    drop(c);
+    persist_if_enabled(config, &snapshot).await;
+}
+
+/// Validators and the PR parsed from the last full response to `url`
+pub async fn get_github_conditional(config: &Config, url: &str) -> Option<(Validators, GitHubPR)> {
+    let c = persisted(config).await;
+    let mut c = c.write().await;
+    let entry = c.github_conditional.get_mut(url)?;
+    entry.used_at = Utc::now();
+    Some((entry.validators.clone(), entry.value.clone()))
+}
+
+pub async fn set_github_conditional(
+    config: &Config,
+    url: &str,
+    validators: Validators,
+    pr: GitHubPR,
+) {
+    let c = persisted(config).await;
+    let mut c = c.write().await;
+
+    let now = Utc::now();
+    c.github_conditional.insert(
+        url.to_string(),
+        Conditional {
+            validators,
+            value: pr,
+            used_at: now,
+        },
+    );
+    prune_conditional(&mut c.github_conditional, now);
+
+    drop(c);
+    persist_debounced(config);
+}
+
+/// Validators and the deployment parsed from the last full response to `url`
+pub async fn get_vercel_conditional(
+    config: &Config,
+    url: &str,
+) -> Option<(Validators, Option<VercelDeployment>)> {
+    let c = persisted(config).await;
+    let mut c = c.write().await;
+    let entry = c.vercel_conditional.get_mut(url)?;
+    entry.used_at = Utc::now();
+    Some((entry.validators.clone(), entry.value.clone()))
+}
+
+pub async fn set_vercel_conditional(
+    config: &Config,
+    url: &str,
+    validators: Validators,
+    value: Option<VercelDeployment>,
+) {
+    let c = persisted(config).await;
+    let mut c = c.write().await;
+
+    let now = Utc::now();
+    c.vercel_conditional.insert(
+        url.to_string(),
+        Conditional {
+            validators,
+            value,
+            used_at: now,
+        },
+    );
+    prune_conditional(&mut c.vercel_conditional, now);
+
+    drop(c);
+    persist_debounced(config);
+}
+
+static GITHUB_NOT_MODIFIED: AtomicU64 = AtomicU64::new(0);
+static VERCEL_NOT_MODIFIED: AtomicU64 = AtomicU64::new(0);
+
+/// Count a GitHub request answered with `304 Not Modified`
+pub fn record_github_not_modified() {
+    GITHUB_NOT_MODIFIED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Count a Vercel request answered with `304 Not Modified`
+pub fn record_vercel_not_modified() {
+    VERCEL_NOT_MODIFIED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// GitHub and Vercel requests saved by `304 Not Modified` since startup
+pub fn not_modified_counts() -> (u64, u64) {
+    (
+        GITHUB_NOT_MODIFIED.load(Ordering::Relaxed),
+        VERCEL_NOT_MODIFIED.load(Ordering::Relaxed),
+    )
+}
+
 pub async fn mark_github_rate_limited(
     config: &Config,
     remaining: Option<u64>,
@@ -466,4 +729,34 @@ mod tests {
         assert_eq!(v3, 1, "should refetch after expiry");
         assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
+
+    #[test]
+    fn prunes_stale_and_least_recently_used_conditionals() {
+        let now = Utc::now();
+        let entry = |used_at| Conditional {
+            validators: Validators::default(),
+            value: (),
+            used_at,
+        };
+
+        let mut entries = HashMap::new();
+        entries.insert("stale".to_string(), entry(now - ChronoDuration::days(8)));
+        entries.insert("legacy".to_string(), entry(DateTime::<Utc>::default()));
+        entries.insert("recent".to_string(), entry(now - ChronoDuration::days(1)));
+        prune_conditional(&mut entries, now);
+        assert_eq!(entries.keys().collect::<Vec<_>>(), vec!["recent"]);
+
+        let mut entries: HashMap<_, _> = (0..CONDITIONAL_MAX_ENTRIES + 10)
+            .map(|i| {
+                (
+                    i.to_string(),
+                    entry(now - ChronoDuration::minutes(i as i64)),
+                )
+            })
+            .collect();
+        prune_conditional(&mut entries, now);
+        assert_eq!(entries.len(), CONDITIONAL_MAX_ENTRIES);
+        assert!(entries.contains_key("0"));
+        assert!(!entries.contains_key(&CONDITIONAL_MAX_ENTRIES.to_string()));
+    }
 }
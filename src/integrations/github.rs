@@ -1,15 +1,127 @@
 use crate::config::Config;
-use crate::data::{GitHubPR, GitHubPRStatus};
+use crate::data::{
+    CiCheck, CiStatus, GitHubPR, GitHubPRStatus, PrAction, PrReview, ReviewRequest, ReviewState,
+    ReviewThread,
+};
 use crate::integrations::enrichment_cache::{self, AsyncTtlCache, Cached};
 use crate::integrations::HTTP_CLIENT;
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
 use once_cell::sync::Lazy;
 use std::time::Duration;
 
-const GITHUB_API_URL: &str = "https://api.github.com";
+/// PR fields shared by every GraphQL query (parsed by `pr_from_graphql`).
+/// reviewDecision gives the review state without calling /reviews;
+/// statusCheckRollup gives CI state and its checks for the head commit.
+const PR_FIELDS_FRAGMENT: &str = r#"
+  fragment PrFields on PullRequest {
+    number
+    title
+    url
+    state
+    isDraft
+    merged
+    headRefName
+    headRefOid
+    reviewDecision
+    commits(last:1) {
+      nodes {
+        commit {
+          statusCheckRollup {
+            state
+            contexts(first:50) {
+              nodes {
+                ... on CheckRun { name status conclusion detailsUrl }
+                ... on StatusContext { context state targetUrl }
+              }
+            }
+          }
+        }
+      }
+    }
+    latestReviews(first:20) {
+      nodes { author { login } state body }
+    }
+    reviewThreads(first:50) {
+      nodes {
+        isResolved
+        path
+        line
+        comments(first:1) {
+          totalCount
+          nodes { author { login } body url }
+        }
+      }
+    }
+  }
+"#;
 
 static PR_CACHE: Lazy<AsyncTtlCache<String, Cached<GitHubPR>>> = Lazy::new(AsyncTtlCache::default);
 
+/// REST and GraphQL endpoints of one GitHub instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiEndpoint {
+    pub rest: String,
+    pub graphql: String,
+}
+
+impl ApiEndpoint {
+    /// The instance configured by `github.api_url`
+    pub fn configured(config: &Config) -> Self {
+        Self {
+            rest: config.github.rest_url().to_string(),
+            graphql: config.github.graphql_url(),
+        }
+    }
+
+    /// The instance serving web URLs on `host`: github.com and the configured
+    /// instance's host use `github.api_url`. None for any other host, which
+    /// must never be sent `tokens.github`.
+    pub fn for_host(config: &Config, host: &str) -> Option<Self> {
+        config
+            .github
+            .serves_host(host)
+            .then(|| Self::configured(config))
+    }
+}
+
+/// The parts of a PR web URL (`https://<host>/<owner>/<repo>/pull/<number>`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrUrl<'a> {
+    pub host: &'a str,
+    pub owner: &'a str,
+    pub repo: &'a str,
+    pub number: u64,
+}
+
+/// Parse a PR web URL on github.com or the configured GitHub instance's host.
+/// Links on any other host are not treated as PRs.
+pub fn parse_pr_url<'a>(config: &Config, url: &'a str) -> Option<PrUrl<'a>> {
+    split_pr_url(url).filter(|parsed| config.github.serves_host(parsed.host))
+}
+
+/// The parts of a PR web URL on any host
+fn split_pr_url(url: &str) -> Option<PrUrl<'_>> {
+    let url = url.trim();
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let mut parts = rest.split('/');
+    let host = parts.next().filter(|h| !h.is_empty())?;
+    let owner = parts.next().filter(|o| !o.is_empty())?;
+    let repo = parts.next().filter(|r| !r.is_empty())?;
+    if parts.next() != Some("pull") {
+        return None;
+    }
+    let number = parts.next()?.parse().ok()?;
+    Some(PrUrl {
+        host,
+        owner,
+        repo,
+        number,
+    })
+}
+
 /// Fetch PR details from a GitHub PR URL.
 ///
 /// Uses an in-memory TTL cache + request coalescing to drastically reduce
@@ -55,7 +167,8 @@ pub async fn fetch_pr(config: &Config, owner: &str, repo: &str, number: u64) ->
 
     let cached = PR_CACHE
         .get_or_try_init_with_ttl(key, || async {
-            let outcome = fetch_pr_uncached(config, owner, repo, number).await;
+            let endpoint = ApiEndpoint::configured(config);
+            let outcome = fetch_pr_uncached(config, &endpoint, owner, repo, number).await;
             outcome_to_cached(outcome)
         })
         .await;
@@ -66,6 +179,8 @@ pub async fn fetch_pr(config: &Config, owner: &str, repo: &str, number: u64) ->
 struct FetchError {
     msg: String,
     backoff: Duration,
+    /// GitHub answered 403/429; callers shouldn't retry another way
+    rate_limited: bool,
 }
 
 type FetchOutcome<T> = std::result::Result<T, FetchError>;
@@ -121,70 +236,53 @@ fn backoff_from_rate_limit_headers(response: &reqwest::Response) -> Option<Durat
 }
 
 async fn fetch_pr_from_url_uncached(config: &Config, pr_url: &str) -> FetchOutcome<GitHubPR> {
-    // Parse URL: https://github.com/owner/repo/pull/123
-    let parts: Vec<&str> = pr_url.trim_end_matches('/').split('/').collect();
-    let pr_number: u64 = parts
-        .last()
-        .context("Invalid PR URL")
-        .and_then(|s| s.parse().context("Invalid PR number"))
+    let parsed = parse_pr_url(config, pr_url)
+        .with_context(|| format!("Not a PR on a configured GitHub host: {}", pr_url))
         .map_err(anyhow_to_fetch_error)?;
-
-    let repo_idx = parts.iter().position(|&s| s == "github.com").unwrap_or(0) + 1;
-    let owner = parts.get(repo_idx).context("Missing owner").map_err(anyhow_to_fetch_error)?;
-    let repo = parts
-        .get(repo_idx + 1)
-        .context("Missing repo")
+    let endpoint = ApiEndpoint::for_host(config, parsed.host)
+        .with_context(|| format!("Not a PR on a configured GitHub host: {}", pr_url))
         .map_err(anyhow_to_fetch_error)?;
 
-    fetch_pr_uncached(config, owner, repo, pr_number).await
+    fetch_pr_uncached(config, &endpoint, parsed.owner, parsed.repo, parsed.number).await
 }
 
 async fn fetch_pr_uncached(
     config: &Config,
+    endpoint: &ApiEndpoint,
     owner: &str,
     repo: &str,
     number: u64,
 ) -> FetchOutcome<GitHubPR> {
     // Prefer GraphQL (1 call instead of pulls+reviews).
     // If GraphQL fails, fall back to REST.
-    match fetch_pr_graphql(config, owner, repo, number).await {
+    match fetch_pr_graphql(config, endpoint, owner, repo, number).await {
         Ok(pr) => Ok(pr),
         Err(e) => {
             // If it was a rate-limit, don't hammer the fallback.
-            if e.msg.contains("rate limited") {
+            if e.rate_limited {
                 return Err(e);
             }
             tracing::debug!("GitHub GraphQL failed ({}), falling back to REST", e.msg);
-            fetch_pr_rest(config, owner, repo, number).await
+            fetch_pr_rest(config, endpoint, owner, repo, number).await
         }
     }
 }
 
 async fn fetch_pr_graphql(
     config: &Config,
+    endpoint: &ApiEndpoint,
     owner: &str,
     repo: &str,
     number: u64,
 ) -> FetchOutcome<GitHubPR> {
-    let client = &*HTTP_CLIENT;
-
-    // reviewDecision provides overall review state without calling /reviews.
     let query = r#"
       query($owner:String!, $repo:String!, $number:Int!) {
         repository(owner:$owner, name:$repo) {
-          pullRequest(number:$number) {
-            number
-            title
-            url
-            state
-            isDraft
-            merged
-            headRefName
-            reviewDecision
-          }
+          pullRequest(number:$number) { ...PrFields }
         }
       }
     "#;
+    let query = format!("{}{}", query, PR_FIELDS_FRAGMENT);
 
     let variables = serde_json::json!({
         "owner": owner,
@@ -192,8 +290,40 @@ async fn fetch_pr_graphql(
         "number": number as i64,
     });
 
+    let body = post_graphql(config, endpoint, &query, variables).await?;
+
+    if body
+        .get("errors")
+        .is_some_and(|e| e.is_array() && !e.as_array().unwrap().is_empty())
+    {
+        return Err(FetchError {
+            msg: format!("GitHub GraphQL returned errors: {}", body["errors"]),
+            backoff: Duration::from_secs(60),
+            rate_limited: false,
+        });
+    }
+
+    let pr = &body["data"]["repository"]["pullRequest"];
+
+    let mut parsed = pr_from_graphql(pr, format!("{}/{}", owner, repo));
+    if parsed.number == 0 {
+        parsed.number = number;
+    }
+    Ok(parsed)
+}
+
+/// POST a GraphQL document for PR enrichment. HTTP and rate-limit failures
+/// become `FetchError`s; GraphQL `errors` are left for the caller to judge.
+async fn post_graphql(
+    config: &Config,
+    endpoint: &ApiEndpoint,
+    query: &str,
+    variables: serde_json::Value,
+) -> FetchOutcome<serde_json::Value> {
+    let client = &*HTTP_CLIENT;
+
     let response = client
-        .post(format!("{}/graphql", GITHUB_API_URL))
+        .post(&endpoint.graphql)
         .header("Authorization", format!("Bearer {}", config.tokens.github))
         .header("Accept", "application/vnd.github+json")
         .header("User-Agent", "panopticon")
@@ -208,6 +338,7 @@ async fn fetch_pr_graphql(
         return Err(FetchError {
             msg: format!("GitHub API rate limited: {}", response.status()),
             backoff,
+            rate_limited: true,
         });
     }
 
@@ -215,26 +346,144 @@ async fn fetch_pr_graphql(
         return Err(FetchError {
             msg: format!("GitHub API error: {}", response.status()),
             backoff: Duration::from_secs(60),
+            rate_limited: false,
         });
     }
 
-    let body: serde_json::Value = response
+    response
         .json()
         .await
-        .map_err(|e| anyhow_to_fetch_error(e.into()))?;
+        .map_err(|e| anyhow_to_fetch_error(e.into()))
+}
 
-    if body
-        .get("errors")
-        .is_some_and(|e| e.is_array() && !e.as_array().unwrap().is_empty())
-    {
-        return Err(FetchError {
-            msg: format!("GitHub GraphQL returned errors: {}", body["errors"]),
-            backoff: Duration::from_secs(60),
-        });
+// =============================================================================
+// Batched enrichment
+// =============================================================================
+
+/// PRs fetched per aliased GraphQL request
+pub const PR_BATCH_SIZE: usize = 50;
+
+/// Load every PR in `urls` that isn't cached yet, `PR_BATCH_SIZE` per GraphQL
+/// request, so the per-issue `fetch_pr_from_url` calls that follow are cache
+/// hits. PRs a batch couldn't return fall back to REST one by one.
+pub async fn prefetch_prs(config: &Config, urls: &[String]) {
+    if enrichment_cache::github_should_backoff(config).await {
+        return;
     }
 
-    let pr = &body["data"]["repository"]["pullRequest"];
+    let mut batches: Vec<(ApiEndpoint, Vec<(String, PrUrl<'_>)>)> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for url in urls {
+        let key = enrichment_cache::normalize_github_pr_url(url);
+        if !seen.insert(key.clone()) || PR_CACHE.contains_fresh(&key).await {
+            continue;
+        }
+        let Some(parsed) = parse_pr_url(config, url) else {
+            continue;
+        };
+        if let Some((_, true)) = enrichment_cache::get_cached_github_pr(
+            config,
+            &key,
+            config.polling.github_interval_secs,
+        )
+        .await
+        {
+            continue;
+        }
 
+        let Some(endpoint) = ApiEndpoint::for_host(config, parsed.host) else {
+            continue;
+        };
+        match batches.iter_mut().find(|(e, _)| *e == endpoint) {
+            Some((_, prs)) => prs.push((key, parsed)),
+            None => batches.push((endpoint, vec![(key, parsed)])),
+        }
+    }
+
+    let requests = batches.iter().flat_map(|(endpoint, prs)| {
+        prs.chunks(PR_BATCH_SIZE)
+            .map(move |chunk| fetch_pr_batch(config, endpoint, chunk))
+    });
+    futures::future::join_all(requests).await;
+}
+
+/// Fetch one batch and cache each PR under its URL key
+async fn fetch_pr_batch(config: &Config, endpoint: &ApiEndpoint, prs: &[(String, PrUrl<'_>)]) {
+    let urls: Vec<PrUrl> = prs.iter().map(|(_, parsed)| parsed.clone()).collect();
+    let (query, variables) = pr_batch_query(&urls);
+
+    let results = match post_graphql(config, endpoint, &query, variables).await {
+        Ok(body) => parse_pr_batch(&body, &urls),
+        Err(e) if e.rate_limited => {
+            // Don't hammer the fallback; every PR waits out the backoff
+            for (key, _) in prs {
+                PR_CACHE
+                    .insert(key.clone(), Cached::Err(e.msg.clone()), e.backoff)
+                    .await;
+            }
+            return;
+        }
+        Err(e) => {
+            tracing::debug!("GitHub batch of {} PRs failed: {}", prs.len(), e.msg);
+            vec![None; prs.len()]
+        }
+    };
+
+    futures::stream::iter(prs.iter().zip(results))
+        .for_each_concurrent(5, |((key, parsed), pr)| async move {
+            let outcome = match pr {
+                Some(pr) => Ok(pr),
+                None => {
+                    fetch_pr_rest(config, endpoint, parsed.owner, parsed.repo, parsed.number).await
+                }
+            };
+            let (cached, ttl) = outcome_to_cached(outcome);
+            if let Cached::Ok(pr) = &cached {
+                enrichment_cache::set_cached_github_pr(config, key, pr.clone()).await;
+            }
+            PR_CACHE.insert(key.clone(), cached, ttl).await;
+        })
+        .await;
+}
+
+/// One query fetching every PR in `prs`, aliased `pr0`, `pr1`, ... in order
+pub fn pr_batch_query(prs: &[PrUrl<'_>]) -> (String, serde_json::Value) {
+    let mut params = Vec::new();
+    let mut fields = String::new();
+    let mut variables = serde_json::Map::new();
+    for (i, pr) in prs.iter().enumerate() {
+        params.push(format!("$o{i}:String!, $r{i}:String!, $n{i}:Int!"));
+        fields.push_str(&format!(
+            "  pr{i}: repository(owner:$o{i}, name:$r{i}) {{ pullRequest(number:$n{i}) {{ ...PrFields }} }}\n"
+        ));
+        variables.insert(format!("o{i}"), pr.owner.into());
+        variables.insert(format!("r{i}"), pr.repo.into());
+        variables.insert(format!("n{i}"), (pr.number as i64).into());
+    }
+    let query = format!(
+        "query({}) {{\n{}}}{}",
+        params.join(", "),
+        fields,
+        PR_FIELDS_FRAGMENT
+    );
+    (query, variables.into())
+}
+
+/// The PRs of a `pr_batch_query` response, in request order. Missing,
+/// inaccessible or errored entries are `None`.
+pub fn parse_pr_batch(body: &serde_json::Value, prs: &[PrUrl<'_>]) -> Vec<Option<GitHubPR>> {
+    prs.iter()
+        .enumerate()
+        .map(|(i, pr)| {
+            let node = &body["data"][format!("pr{}", i)]["pullRequest"];
+            node.is_object()
+                .then(|| pr_from_graphql(node, format!("{}/{}", pr.owner, pr.repo)))
+        })
+        .collect()
+}
+
+/// Build a PR from a GraphQL `PullRequest` node selected with `PR_FIELDS_FRAGMENT`
+fn pr_from_graphql(pr: &serde_json::Value, repo: String) -> GitHubPR {
     let state = pr["state"].as_str().unwrap_or("OPEN");
     let merged = pr["merged"].as_bool().unwrap_or(false);
     let is_draft = pr["isDraft"].as_bool().unwrap_or(false);
@@ -254,21 +503,536 @@ async fn fetch_pr_graphql(
         }
     };
 
-    Ok(GitHubPR {
-        number: pr["number"].as_u64().unwrap_or(number),
+    let rollup = &pr["commits"]["nodes"][0]["commit"]["statusCheckRollup"];
+    let checks = rollup["contexts"]["nodes"]
+        .as_array()
+        .map(|nodes| nodes.iter().filter_map(check_from_graphql).collect())
+        .unwrap_or_default();
+    let reviews = pr["latestReviews"]["nodes"]
+        .as_array()
+        .map(|nodes| nodes.iter().filter_map(review_from_graphql).collect())
+        .unwrap_or_default();
+    let review_threads = pr["reviewThreads"]["nodes"]
+        .as_array()
+        .map(|nodes| nodes.iter().filter_map(thread_from_graphql).collect())
+        .unwrap_or_default();
+
+    GitHubPR {
+        number: pr["number"].as_u64().unwrap_or(0),
         title: pr["title"].as_str().unwrap_or("").to_string(),
         url: pr["url"].as_str().unwrap_or("").to_string(),
         status,
         branch: pr["headRefName"].as_str().unwrap_or("").to_string(),
-        repo: format!("{}/{}", owner, repo),
+        repo,
+        ci_status: rollup["state"].as_str().and_then(CiStatus::from_state),
+        checks,
+        reviews,
+        review_threads,
+        head_sha: pr["headRefOid"].as_str().map(String::from),
+    }
+}
+
+fn review_from_graphql(node: &serde_json::Value) -> Option<PrReview> {
+    Some(PrReview {
+        author: node["author"]["login"].as_str().map(String::from),
+        state: ReviewState::from_graphql(node["state"].as_str()?)?,
+        body: node["body"].as_str().unwrap_or("").to_string(),
+    })
+}
+
+/// Parse a review thread from its first comment
+fn thread_from_graphql(node: &serde_json::Value) -> Option<ReviewThread> {
+    let comment = &node["comments"]["nodes"][0];
+    Some(ReviewThread {
+        path: node["path"].as_str()?.to_string(),
+        line: node["line"].as_u64().map(|line| line as u32),
+        author: comment["author"]["login"].as_str().map(String::from),
+        body: comment["body"].as_str().unwrap_or("").to_string(),
+        url: comment["url"].as_str().map(String::from),
+        comment_count: node["comments"]["totalCount"].as_u64().unwrap_or(1) as usize,
+        resolved: node["isResolved"].as_bool().unwrap_or(false),
+    })
+}
+
+/// Parse a `statusCheckRollup` context: a check run (Actions and other apps)
+/// or a legacy commit status
+fn check_from_graphql(node: &serde_json::Value) -> Option<CiCheck> {
+    let url = |key: &str| {
+        node[key]
+            .as_str()
+            .filter(|u| !u.is_empty())
+            .map(String::from)
+    };
+    if let Some(name) = node["name"].as_str() {
+        let status = CiStatus::from_check_run(
+            node["status"].as_str().unwrap_or(""),
+            node["conclusion"].as_str(),
+        );
+        return Some(CiCheck {
+            name: name.to_string(),
+            status,
+            url: url("detailsUrl"),
+        });
+    }
+    let name = node["context"].as_str()?;
+    Some(CiCheck {
+        name: name.to_string(),
+        status: CiStatus::from_state(node["state"].as_str()?)?,
+        url: url("targetUrl"),
     })
 }
 
+/// GitHub search query for open PRs authored by `username` in any of `organizations`
+/// (all repositories when no organization is configured)
+pub fn authored_prs_query(username: &str, organizations: &[String]) -> String {
+    pr_search_query(&format!("author:{}", username), organizations)
+}
+
+/// GitHub search query for open PRs waiting on `username`'s review. GitHub
+/// counts review requests to any team the user belongs to as well.
+pub fn review_requests_query(username: &str, organizations: &[String]) -> String {
+    pr_search_query(&format!("review-requested:{}", username), organizations)
+}
+
+fn pr_search_query(filter: &str, organizations: &[String]) -> String {
+    let mut query = format!("is:pr is:open archived:false {}", filter);
+    for org in organizations {
+        query.push_str(" org:");
+        query.push_str(org);
+    }
+    query
+}
+
+/// PR nodes of a `search` GraphQL response body, with their repository
+fn search_pr_nodes(
+    body: &serde_json::Value,
+) -> impl Iterator<Item = (&serde_json::Value, GitHubPR)> {
+    body["data"]["search"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|node| {
+            let repo = node["repository"]["nameWithOwner"].as_str()?;
+            let pr = pr_from_graphql(node, repo.to_string());
+            (pr.number > 0 && !pr.url.is_empty()).then_some((node, pr))
+        })
+}
+
+/// Parse PRs from a `search` GraphQL response body
+pub fn parse_authored_prs(body: &serde_json::Value) -> Vec<GitHubPR> {
+    search_pr_nodes(body).map(|(_, pr)| pr).collect()
+}
+
+/// Parse review requests from a `search` GraphQL response body
+pub fn parse_review_requests(body: &serde_json::Value) -> Vec<ReviewRequest> {
+    search_pr_nodes(body)
+        .map(|(node, pr)| ReviewRequest {
+            pr,
+            author: node["author"]["login"].as_str().map(String::from),
+            created_at: node["createdAt"]
+                .as_str()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(Utc::now),
+            additions: node["additions"].as_u64().unwrap_or(0),
+            deletions: node["deletions"].as_u64().unwrap_or(0),
+            changed_files: node["changedFiles"].as_u64().unwrap_or(0),
+            linked_identifier: None,
+            linked_issue: None,
+        })
+        .collect()
+}
+
+/// Open PRs authored by `github.username` in `github.organizations`.
+///
+/// Finds PRs no Linear attachment points at. Empty without a configured
+/// username or while GitHub is rate limiting us.
+pub async fn fetch_authored_prs(config: &Config) -> Result<Vec<GitHubPR>> {
+    let Some(username) = search_username(config) else {
+        return Ok(vec![]);
+    };
+    if enrichment_cache::github_should_backoff(config).await {
+        return Ok(vec![]);
+    }
+    let search = authored_prs_query(username, &config.github.organizations);
+    Ok(parse_authored_prs(&search_prs(config, &search).await?))
+}
+
+/// Open PRs in `github.organizations` waiting on `github.username`'s review.
+///
+/// Empty without a configured username or while GitHub is rate limiting us.
+pub async fn fetch_review_requests(config: &Config) -> Result<Vec<ReviewRequest>> {
+    let Some(username) = search_username(config) else {
+        return Ok(vec![]);
+    };
+    if enrichment_cache::github_should_backoff(config).await {
+        return Ok(vec![]);
+    }
+    let search = review_requests_query(username, &config.github.organizations);
+    Ok(parse_review_requests(&search_prs(config, &search).await?))
+}
+
+/// PR opened from `branch` in `repo` ("owner/name"), for issues Linear has no
+/// PR attachment for yet. An open PR wins over the most recently updated
+/// closed or merged one; PRs from forks that happen to share the branch name
+/// are ignored.
+///
+/// The branch → PR link is kept in the enrichment cache, so a branch is only
+/// searched again while it has no open PR: a closed or merged one may have
+/// been replaced by a new PR from the same branch.
+pub async fn fetch_pr_for_branch(
+    config: &Config,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<GitHubPR>> {
+    let key = enrichment_cache::branch_pr_key(repo, branch);
+    let cached =
+        enrichment_cache::get_cached_branch_pr(config, &key, config.polling.github_interval_secs)
+            .await;
+    let stale = match cached {
+        Some((Some(url), fresh)) => {
+            let pr = fetch_pr_from_url(config, &url).await?;
+            if fresh || !matches!(pr.status, GitHubPRStatus::Merged | GitHubPRStatus::Closed) {
+                return Ok(Some(pr));
+            }
+            Some(pr)
+        }
+        Some((None, true)) => return Ok(None),
+        Some((None, false)) | None => None,
+    };
+    // Serve the stale link while we can't search
+    if config.tokens.github.is_empty() || enrichment_cache::github_should_backoff(config).await {
+        return Ok(stale);
+    }
+
+    let pr = search_branch_pr(config, repo, branch).await?;
+    let url = pr.as_ref().map(|pr| pr.url.clone());
+    enrichment_cache::set_cached_branch_pr(config, &key, url).await;
+    if let Some(pr) = &pr {
+        let pr_key = enrichment_cache::normalize_github_pr_url(&pr.url);
+        enrichment_cache::set_cached_github_pr(config, &pr_key, pr.clone()).await;
+    }
+    Ok(pr)
+}
+
+async fn search_branch_pr(config: &Config, repo: &str, branch: &str) -> Result<Option<GitHubPR>> {
+    let (owner, name) = repo
+        .split_once('/')
+        .with_context(|| format!("Invalid repository: {}", repo))?;
+    let query = r#"
+      query($owner:String!, $repo:String!, $branch:String!) {
+        repository(owner:$owner, name:$repo) {
+          pullRequests(headRefName:$branch, first:10, orderBy:{field:UPDATED_AT, direction:DESC}) {
+            nodes {
+              ...PrFields
+              headRepository { nameWithOwner }
+            }
+          }
+        }
+      }
+    "#;
+    let query = format!("{}{}", query, PR_FIELDS_FRAGMENT);
+    let variables = serde_json::json!({"owner": owner, "repo": name, "branch": branch});
+    let endpoint = ApiEndpoint::configured(config);
+    let body = graphql_request(
+        config,
+        &endpoint,
+        &query,
+        variables,
+        "GitHub branch PR lookup",
+    )
+    .await?;
+    Ok(parse_branch_pr(&body, repo))
+}
+
+/// Pick the PR for a branch out of a `pullRequests(headRefName:)` response
+pub fn parse_branch_pr(body: &serde_json::Value, repo: &str) -> Option<GitHubPR> {
+    let nodes = body["data"]["repository"]["pullRequests"]["nodes"].as_array()?;
+    let prs: Vec<GitHubPR> = nodes
+        .iter()
+        .filter(|node| {
+            node["headRepository"]["nameWithOwner"]
+                .as_str()
+                .is_some_and(|head| head.eq_ignore_ascii_case(repo))
+        })
+        .map(|node| pr_from_graphql(node, repo.to_string()))
+        .collect();
+    let open = prs
+        .iter()
+        .position(|pr| !matches!(pr.status, GitHubPRStatus::Merged | GitHubPRStatus::Closed));
+    prs.into_iter().nth(open.unwrap_or(0))
+}
+
+/// User PR searches run for (None without a username or a token)
+fn search_username(config: &Config) -> Option<&str> {
+    if config.tokens.github.is_empty() {
+        return None;
+    }
+    config.github.username.as_deref().filter(|u| !u.is_empty())
+}
+
+/// Run a PR search through GraphQL and return the response body
+async fn search_prs(config: &Config, search: &str) -> Result<serde_json::Value> {
+    let query = r#"
+      query($search:String!) {
+        search(query:$search, type:ISSUE, first:50) {
+          nodes {
+            ... on PullRequest {
+              ...PrFields
+              repository { nameWithOwner }
+              author { login }
+              createdAt
+              additions
+              deletions
+              changedFiles
+            }
+          }
+        }
+      }
+    "#;
+    let query = format!("{}{}", query, PR_FIELDS_FRAGMENT);
+
+    graphql_request(
+        config,
+        &ApiEndpoint::configured(config),
+        &query,
+        serde_json::json!({"search": search}),
+        "GitHub search",
+    )
+    .await
+}
+
+/// POST a GraphQL document and return the response body, failing on HTTP
+/// errors or GraphQL `errors` (`what` names the call in error messages)
+async fn graphql_request(
+    config: &Config,
+    endpoint: &ApiEndpoint,
+    query: &str,
+    variables: serde_json::Value,
+    what: &str,
+) -> Result<serde_json::Value> {
+    let response = HTTP_CLIENT
+        .post(&endpoint.graphql)
+        .header("Authorization", format!("Bearer {}", config.tokens.github))
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "panopticon")
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .json(&serde_json::json!({"query": query, "variables": variables}))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("{} failed: {}", what, response.status());
+    }
+
+    let body: serde_json::Value = response.json().await?;
+    if body
+        .get("errors")
+        .is_some_and(|e| e.as_array().is_some_and(|a| !a.is_empty()))
+    {
+        anyhow::bail!(
+            "{} returned errors: {}",
+            what,
+            graphql_error_messages(&body)
+        );
+    }
+    Ok(body)
+}
+
+/// The `message` of each GraphQL error, or the raw `errors` value
+fn graphql_error_messages(body: &serde_json::Value) -> String {
+    let messages: Vec<&str> = body["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter_map(|e| e["message"].as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+    if messages.is_empty() {
+        body["errors"].to_string()
+    } else {
+        messages.join("; ")
+    }
+}
+
+// =============================================================================
+// PR actions
+// =============================================================================
+
+/// GraphQL mutation for a PR action. The mutation is aliased to `result` so
+/// every action's response is read the same way; `$id` is the PR's node ID.
+pub fn pr_action_mutation(action: PrAction) -> String {
+    let (params, call) = match action {
+        PrAction::MarkReady => (
+            "",
+            "markPullRequestReadyForReview(input:{pullRequestId:$id})",
+        ),
+        PrAction::Merge(_) => (
+            ", $method:PullRequestMergeMethod!",
+            "mergePullRequest(input:{pullRequestId:$id, mergeMethod:$method})",
+        ),
+        PrAction::EnableAutoMerge(_) => (
+            ", $method:PullRequestMergeMethod!",
+            "enablePullRequestAutoMerge(input:{pullRequestId:$id, mergeMethod:$method})",
+        ),
+        PrAction::RerequestReviews => (
+            ", $users:[ID!]!",
+            "requestReviews(input:{pullRequestId:$id, userIds:$users, union:true})",
+        ),
+        PrAction::Close => ("", "closePullRequest(input:{pullRequestId:$id})"),
+    };
+    format!(
+        "mutation($id:ID!{}) {{ result: {} {{ pullRequest {{ ...PrFields }} }} }}{}",
+        params, call, PR_FIELDS_FRAGMENT
+    )
+}
+
+/// Parse the PR out of a `pr_action_mutation` response body
+pub fn parse_pr_action_result(body: &serde_json::Value, repo: &str) -> Option<GitHubPR> {
+    let node = &body["data"]["result"]["pullRequest"];
+    node.is_object()
+        .then(|| pr_from_graphql(node, repo.to_string()))
+}
+
+/// Node ID of a PR and the user IDs of its past reviewers (minus the viewer)
+async fn pr_node_ids(
+    config: &Config,
+    endpoint: &ApiEndpoint,
+    pr: &GitHubPR,
+) -> Result<(String, Vec<String>)> {
+    let (owner, name) = pr
+        .repo
+        .split_once('/')
+        .with_context(|| format!("Invalid repository: {}", pr.repo))?;
+    let query = r#"
+      query($owner:String!, $repo:String!, $number:Int!) {
+        viewer { id }
+        repository(owner:$owner, name:$repo) {
+          pullRequest(number:$number) {
+            id
+            latestReviews(first:20) { nodes { author { ... on User { id } } } }
+          }
+        }
+      }
+    "#;
+    let variables = serde_json::json!({"owner": owner, "repo": name, "number": pr.number as i64});
+    let body = graphql_request(config, endpoint, query, variables, "GitHub PR lookup").await?;
+
+    let node = &body["data"]["repository"]["pullRequest"];
+    let id = node["id"].as_str().context("PR not found")?.to_string();
+    let viewer = body["data"]["viewer"]["id"].as_str();
+    let mut reviewers: Vec<String> = Vec::new();
+    for review in node["latestReviews"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        if let Some(user) = review["author"]["id"].as_str() {
+            if Some(user) != viewer && !reviewers.iter().any(|r| r == user) {
+                reviewers.push(user.to_string());
+            }
+        }
+    }
+    Ok((id, reviewers))
+}
+
+/// Instance serving `pr`; an error for PRs on hosts `tokens.github` must
+/// not be sent to
+fn pr_endpoint(config: &Config, pr: &GitHubPR) -> Result<ApiEndpoint> {
+    parse_pr_url(config, &pr.url)
+        .and_then(|parsed| ApiEndpoint::for_host(config, parsed.host))
+        .with_context(|| format!("{} isn't on a configured GitHub host", pr.url))
+}
+
+/// Run a PR action and return the PR as GitHub reports it afterwards. The
+/// result also replaces the cached copy so the next refresh doesn't undo it.
+pub async fn run_pr_action(config: &Config, pr: &GitHubPR, action: PrAction) -> Result<GitHubPR> {
+    if config.tokens.github.is_empty() {
+        anyhow::bail!("no GitHub token configured");
+    }
+    let endpoint = pr_endpoint(config, pr)?;
+    let (id, reviewers) = pr_node_ids(config, &endpoint, pr).await?;
+
+    let mut variables = serde_json::json!({ "id": id });
+    match action {
+        PrAction::Merge(method) | PrAction::EnableAutoMerge(method) => {
+            variables["method"] = method.as_graphql().into();
+        }
+        PrAction::RerequestReviews => {
+            if reviewers.is_empty() {
+                anyhow::bail!("nobody has reviewed this PR yet");
+            }
+            variables["users"] = reviewers.into();
+        }
+        PrAction::MarkReady | PrAction::Close => {}
+    }
+
+    let body = graphql_request(
+        config,
+        &endpoint,
+        &pr_action_mutation(action),
+        variables,
+        &action.label(),
+    )
+    .await?;
+    let updated =
+        parse_pr_action_result(&body, &pr.repo).context("GitHub returned no pull request")?;
+
+    let key = enrichment_cache::normalize_github_pr_url(&updated.url);
+    PR_CACHE
+        .insert(
+            key.clone(),
+            Cached::Ok(updated.clone()),
+            ttl_for_pr_status(updated.status),
+        )
+        .await;
+    enrichment_cache::set_cached_github_pr(config, &key, updated.clone()).await;
+    Ok(updated)
+}
+
+// =============================================================================
+// Actions job logs
+// =============================================================================
+
+/// Plain-text log of a GitHub Actions job that ran for `pr`
+pub async fn fetch_job_log(config: &Config, pr: &GitHubPR, job_id: u64) -> Result<String> {
+    if config.tokens.github.is_empty() {
+        anyhow::bail!("no GitHub token configured");
+    }
+    let endpoint = pr_endpoint(config, pr)?;
+    let url = format!(
+        "{}/repos/{}/actions/jobs/{}/logs",
+        endpoint.rest, pr.repo, job_id
+    );
+
+    // GitHub answers with a redirect to the log file, which reqwest follows
+    let response = HTTP_CLIENT
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", config.tokens.github))
+        .header("Accept", "application/vnd.github+json")
+        .header("User-Agent", "panopticon")
+        .header("X-GitHub-Api-Version", "2022-11-28")
+        .send()
+        .await?;
+
+    match response.status().as_u16() {
+        200..=299 => Ok(response.text().await?),
+        404 | 410 => anyhow::bail!("log not found (expired, or no access to Actions)"),
+        status => anyhow::bail!("GitHub Actions log request failed: {}", status),
+    }
+}
+
 /// Fetch PR details from GitHub REST API.
 ///
-/// Fallback path; makes 1-2 calls depending on PR state.
+/// Fallback path; makes 1-2 calls depending on PR state. CI checks are only
+/// available through GraphQL, so they're left empty here. The pull request is
+/// fetched conditionally: a review or a push bumps its `updated_at`, so a
+/// `304 Not Modified` means the last result (reviews included) still holds.
 async fn fetch_pr_rest(
     config: &Config,
+    endpoint: &ApiEndpoint,
     owner: &str,
     repo: &str,
     number: u64,
@@ -277,24 +1041,38 @@ async fn fetch_pr_rest(
 
     let url = format!(
         "{}/repos/{}/{}/pulls/{}",
-        GITHUB_API_URL, owner, repo, number
+        endpoint.rest, owner, repo, number
     );
+    let conditional = enrichment_cache::get_github_conditional(config, &url).await;
 
-    let response = client
+    let mut request = client
         .get(&url)
         .header("Authorization", format!("Bearer {}", config.tokens.github))
         .header("Accept", "application/vnd.github+json")
         .header("User-Agent", "panopticon")
-        .header("X-GitHub-Api-Version", "2022-11-28")
+        .header("X-GitHub-Api-Version", "2022-11-28");
+    if let Some((validators, _)) = &conditional {
+        request = validators.apply(request);
+    }
+    let response = request
         .send()
         .await
         .map_err(|e| anyhow_to_fetch_error(e.into()))?;
 
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        if let Some((_, pr)) = conditional {
+            enrichment_cache::record_github_not_modified();
+            tracing::debug!("GitHub PR {}/{}#{} not modified", owner, repo, number);
+            return Ok(pr);
+        }
+    }
+
     if response.status().as_u16() == 403 || response.status().as_u16() == 429 {
         let backoff = backoff_from_rate_limit_headers(&response).unwrap_or(Duration::from_secs(60));
         return Err(FetchError {
             msg: format!("GitHub API rate limited: {}", response.status()),
             backoff,
+            rate_limited: true,
         });
     }
 
@@ -302,37 +1080,62 @@ async fn fetch_pr_rest(
         return Err(FetchError {
             msg: format!("GitHub API error: {}", response.status()),
             backoff: Duration::from_secs(60),
+            rate_limited: false,
         });
     }
 
+    let validators = enrichment_cache::Validators::from_headers(response.headers());
     let pr: serde_json::Value = response
         .json()
         .await
         .map_err(|e| anyhow_to_fetch_error(e.into()))?;
 
     // For merged/closed/draft we don't need a reviews call.
-    if pr["merged"].as_bool().unwrap_or(false)
+    let reviews = if pr["merged"].as_bool().unwrap_or(false)
         || pr["state"].as_str() == Some("closed")
         || pr["draft"].as_bool().unwrap_or(false)
     {
-        let status = determine_pr_status(&pr, &[]);
-        return Ok(GitHubPR {
-            number,
-            title: pr["title"].as_str().unwrap_or("").to_string(),
-            url: pr["html_url"].as_str().unwrap_or("").to_string(),
-            status,
-            branch: pr["head"]["ref"].as_str().unwrap_or("").to_string(),
-            repo: format!("{}/{}", owner, repo),
-        });
+        Some(vec![])
+    } else {
+        fetch_reviews_rest(config, endpoint, owner, repo, number).await?
+    };
+
+    let parsed = GitHubPR {
+        number,
+        title: pr["title"].as_str().unwrap_or("").to_string(),
+        url: pr["html_url"].as_str().unwrap_or("").to_string(),
+        status: determine_pr_status(&pr, reviews.as_deref().unwrap_or_default()),
+        branch: pr["head"]["ref"].as_str().unwrap_or("").to_string(),
+        repo: format!("{}/{}", owner, repo),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: pr["head"]["sha"].as_str().map(String::from),
+    };
+
+    // Only a complete result may be replayed on a later 304
+    if let (Some(validators), Some(_)) = (validators, &reviews) {
+        enrichment_cache::set_github_conditional(config, &url, validators, parsed.clone()).await;
     }
+    Ok(parsed)
+}
 
-    // Fetch reviews to determine approval status (fallback-only path).
+/// Reviews of a PR to determine approval status (fallback-only path). None
+/// when rate limited, so the PR basics are returned with a moderate TTL.
+async fn fetch_reviews_rest(
+    config: &Config,
+    endpoint: &ApiEndpoint,
+    owner: &str,
+    repo: &str,
+    number: u64,
+) -> FetchOutcome<Option<Vec<serde_json::Value>>> {
     let reviews_url = format!(
         "{}/repos/{}/{}/pulls/{}/reviews",
-        GITHUB_API_URL, owner, repo, number
+        endpoint.rest, owner, repo, number
     );
 
-    let reviews_response = client
+    let reviews_response = HTTP_CLIENT
         .get(&reviews_url)
         .header("Authorization", format!("Bearer {}", config.tokens.github))
         .header("Accept", "application/vnd.github+json")
@@ -343,43 +1146,21 @@ async fn fetch_pr_rest(
         .map_err(|e| anyhow_to_fetch_error(e.into()))?;
 
     if reviews_response.status().as_u16() == 403 || reviews_response.status().as_u16() == 429 {
-        let status = determine_pr_status(&pr, &[]);
-        // Rate limit on reviews: return PR basics and cache with a moderate TTL.
-        return Ok(GitHubPR {
-            number,
-            title: pr["title"].as_str().unwrap_or("").to_string(),
-            url: pr["html_url"].as_str().unwrap_or("").to_string(),
-            status,
-            branch: pr["head"]["ref"].as_str().unwrap_or("").to_string(),
-            repo: format!("{}/{}", owner, repo),
-        });
+        return Ok(None);
     }
 
-    let reviews: Vec<serde_json::Value> = if reviews_response.status().is_success() {
-        reviews_response
-            .json()
-            .await
-            .unwrap_or_default()
+    if reviews_response.status().is_success() {
+        Ok(Some(reviews_response.json().await.unwrap_or_default()))
     } else {
-        vec![]
-    };
-
-    let status = determine_pr_status(&pr, &reviews);
-
-    Ok(GitHubPR {
-        number,
-        title: pr["title"].as_str().unwrap_or("").to_string(),
-        url: pr["html_url"].as_str().unwrap_or("").to_string(),
-        status,
-        branch: pr["head"]["ref"].as_str().unwrap_or("").to_string(),
-        repo: format!("{}/{}", owner, repo),
-    })
+        Ok(Some(vec![]))
+    }
 }
 
 fn anyhow_to_fetch_error(e: anyhow::Error) -> FetchError {
     FetchError {
         msg: e.to_string(),
         backoff: Duration::from_secs(60),
+        rate_limited: false,
     }
 }
 
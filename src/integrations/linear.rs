@@ -1,15 +1,16 @@
-use crate::config::Config;
+use crate::config::{Config, LinearAccount};
 use crate::data::{
-    LinearAttachment, LinearChildRef, LinearCycle, LinearIssue, LinearLabel, LinearParentRef,
-    LinearPriority, LinearStatus,
+    LinearAttachment, LinearChildRef, LinearCycle, LinearIssue, LinearLabel, LinearNotification,
+    LinearParentRef, LinearPriority, LinearRelationRef, LinearStatus, NotificationKind,
+    RelationKind,
 };
-use crate::integrations::{LinkedLinearIssue, HTTP_CLIENT};
-use anyhow::Result;
-use chrono::{DateTime, Utc};
+use crate::integrations::{github, LinkedLinearIssue, HTTP_CLIENT};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::future::{join_all, try_join_all};
+use futures::stream::{self, StreamExt};
 use serde::Deserialize;
 
-const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
-
 // =============================================================================
 // GraphQL Response Types
 // =============================================================================
@@ -17,13 +18,11 @@ const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 #[derive(Debug, Deserialize)]
 struct GraphQLResponse<T> {
     data: Option<T>,
-    #[allow(dead_code)]
     errors: Option<Vec<GraphQLError>>,
 }
 
 #[derive(Debug, Deserialize)]
 struct GraphQLError {
-    #[allow(dead_code)]
     message: String,
 }
 
@@ -68,6 +67,8 @@ struct IssueNode {
     created_at: String,
     priority: Option<i64>,
     estimate: Option<f64>,
+    due_date: Option<String>,
+    sla_breaches_at: Option<String>,
     state: Option<StateNode>,
     cycle: Option<CycleNode>,
     labels: Option<LabelConnection>,
@@ -77,6 +78,8 @@ struct IssueNode {
     attachments: Option<AttachmentConnection>,
     parent: Option<ParentNode>,
     children: Option<ChildConnection>,
+    relations: Option<RelationConnection>,
+    inverse_relations: Option<InverseRelationConnection>,
     branch_name: Option<String>,
 }
 
@@ -166,6 +169,43 @@ struct ChildNode {
     title: Option<String>,
     url: Option<String>,
     priority: Option<i64>,
+    estimate: Option<f64>,
+    state: Option<StateNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RelationConnection {
+    nodes: Vec<RelationNode>,
+}
+
+/// Relation owned by this issue ("this issue blocks relatedIssue")
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RelationNode {
+    #[serde(rename = "type")]
+    relation_type: String,
+    related_issue: Option<RelatedIssueNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InverseRelationConnection {
+    nodes: Vec<InverseRelationNode>,
+}
+
+/// Relation owned by another issue ("issue blocks this issue")
+#[derive(Debug, Deserialize)]
+struct InverseRelationNode {
+    #[serde(rename = "type")]
+    relation_type: String,
+    issue: Option<RelatedIssueNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RelatedIssueNode {
+    id: String,
+    identifier: String,
+    title: Option<String>,
+    url: Option<String>,
     state: Option<StateNode>,
 }
 
@@ -187,6 +227,9 @@ struct ProjectConnection {
 pub struct ProjectInfo {
     pub id: String,
     pub name: String,
+    /// Linear account the project belongs to (set with multiple workspaces)
+    #[serde(skip)]
+    pub workspace: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -208,6 +251,55 @@ pub struct TeamMemberInfo {
     pub email: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamsData {
+    teams: TeamConnection,
+    issue_labels: IssueLabelConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamConnection {
+    nodes: Vec<TeamInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueLabelConnection {
+    nodes: Vec<IssueLabelNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueLabelNode {
+    id: String,
+    name: String,
+    team: Option<TeamIdNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamIdNode {
+    id: String,
+}
+
+/// Team an issue can be created in, with the labels usable on it
+#[derive(Debug, Clone, Deserialize)]
+pub struct TeamInfo {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    /// Team labels plus workspace-wide labels
+    #[serde(skip)]
+    pub labels: Vec<LabelInfo>,
+    /// Linear account the team belongs to (set with multiple workspaces)
+    #[serde(skip)]
+    pub workspace: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelInfo {
+    pub id: String,
+    pub name: String,
+}
+
 // =============================================================================
 // GraphQL Query Fragments
 // =============================================================================
@@ -222,6 +314,8 @@ const ISSUE_FIELDS: &str = r#"
     createdAt
     priority
     estimate
+    dueDate
+    slaBreachesAt
     state {
         name
         type
@@ -274,12 +368,43 @@ const ISSUE_FIELDS: &str = r#"
             title
             url
             priority
+            estimate
             state {
                 name
                 type
             }
         }
     }
+    relations {
+        nodes {
+            type
+            relatedIssue {
+                id
+                identifier
+                title
+                url
+                state {
+                    name
+                    type
+                }
+            }
+        }
+    }
+    inverseRelations {
+        nodes {
+            type
+            issue {
+                id
+                identifier
+                title
+                url
+                state {
+                    name
+                    type
+                }
+            }
+        }
+    }
     branchName
 "#;
 
@@ -287,24 +412,51 @@ const ISSUE_FIELDS: &str = r#"
 // Public API: Issue Fetching
 // =============================================================================
 
-/// Fetch all assigned issues with pagination
+/// Fetch all assigned issues with pagination, across every configured account
 pub async fn fetch_assigned_issues(config: &Config) -> Result<Vec<LinkedLinearIssue>> {
-    let fetch_limit = config.linear.fetch_limit;
-    fetch_issues_paginated(config, fetch_limit, None).await
+    fetch_issues_all_accounts(config, None).await
 }
 
 /// Fetch issues updated since a given timestamp (incremental sync)
 pub async fn fetch_issues_since(
     config: &Config,
     since: DateTime<Utc>,
+) -> Result<Vec<LinkedLinearIssue>> {
+    fetch_issues_all_accounts(config, Some(since)).await
+}
+
+/// Fetch each account concurrently and tag issues with their workspace.
+///
+/// Any account failing fails the whole fetch so the refresh keeps the previous
+/// data instead of silently dropping one workspace's issues.
+async fn fetch_issues_all_accounts(
+    config: &Config,
+    updated_since: Option<DateTime<Utc>>,
 ) -> Result<Vec<LinkedLinearIssue>> {
     let fetch_limit = config.linear.fetch_limit;
-    fetch_issues_paginated(config, fetch_limit, Some(since)).await
+    let tag_workspace = config.has_multiple_linear_workspaces();
+    let accounts = config.linear_accounts();
+
+    let per_account = try_join_all(accounts.iter().map(|account| async move {
+        let mut issues = fetch_issues_paginated(config, account, fetch_limit, updated_since)
+            .await
+            .with_context(|| format!("Linear workspace '{}'", account.name))?;
+        if tag_workspace {
+            for linked in &mut issues {
+                linked.issue.workspace = Some(account.name.clone());
+            }
+        }
+        Ok::<_, anyhow::Error>(issues)
+    }))
+    .await?;
+
+    Ok(per_account.into_iter().flatten().collect())
 }
 
 /// Fetch issues with pagination support
 async fn fetch_issues_paginated(
     config: &Config,
+    account: &LinearAccount,
     limit: usize,
     updated_since: Option<DateTime<Utc>>,
 ) -> Result<Vec<LinkedLinearIssue>> {
@@ -317,8 +469,8 @@ async fn fetch_issues_paginated(
         let query = build_issues_query(page_size, cursor.as_deref(), updated_since);
 
         let response = client
-            .post(LINEAR_API_URL)
-            .header("Authorization", &config.tokens.linear)
+            .post(&account.api_url)
+            .header("Authorization", &account.token)
             .header("Content-Type", "application/json")
             .json(&serde_json::json!({ "query": query }))
             .send()
@@ -330,7 +482,7 @@ async fn fetch_issues_paginated(
             let connection = data.viewer.assigned_issues;
 
             for node in connection.nodes {
-                if let Some(issue) = parse_issue_node(node) {
+                if let Some(issue) = parse_issue_node(config, node) {
                     all_issues.push(issue);
                 }
             }
@@ -394,8 +546,24 @@ fn build_issues_query(
 // Public API: Projects and Team Members
 // =============================================================================
 
-/// Fetch all projects accessible to the user
+/// Fetch all projects accessible to the user, merged across accounts
 pub async fn fetch_projects(config: &Config) -> Result<Vec<ProjectInfo>> {
+    let tag_workspace = config.has_multiple_linear_workspaces();
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(|account| async move {
+        let mut projects = fetch_projects_for_account(account).await?;
+        if tag_workspace {
+            for project in &mut projects {
+                project.workspace = Some(account.name.clone());
+            }
+        }
+        Ok(projects)
+    }))
+    .await;
+    merge_account_results(results, |p: &ProjectInfo| p.id.clone())
+}
+
+async fn fetch_projects_for_account(account: &LinearAccount) -> Result<Vec<ProjectInfo>> {
     let client = &*HTTP_CLIENT;
 
     let query = r#"
@@ -410,8 +578,8 @@ pub async fn fetch_projects(config: &Config) -> Result<Vec<ProjectInfo>> {
     "#;
 
     let response = client
-        .post(LINEAR_API_URL)
-        .header("Authorization", &config.tokens.linear)
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
         .header("Content-Type", "application/json")
         .json(&serde_json::json!({ "query": query }))
         .send()
@@ -424,8 +592,14 @@ pub async fn fetch_projects(config: &Config) -> Result<Vec<ProjectInfo>> {
     Ok(projects)
 }
 
-/// Fetch team members (users in the organization)
+/// Fetch team members (users in each organization), merged across accounts
 pub async fn fetch_team_members(config: &Config) -> Result<Vec<TeamMemberInfo>> {
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(fetch_team_members_for_account)).await;
+    merge_account_results(results, |m: &TeamMemberInfo| m.id.clone())
+}
+
+async fn fetch_team_members_for_account(account: &LinearAccount) -> Result<Vec<TeamMemberInfo>> {
     let client = &*HTTP_CLIENT;
 
     let query = r#"
@@ -442,8 +616,8 @@ pub async fn fetch_team_members(config: &Config) -> Result<Vec<TeamMemberInfo>>
     "#;
 
     let response = client
-        .post(LINEAR_API_URL)
-        .header("Authorization", &config.tokens.linear)
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
         .header("Content-Type", "application/json")
         .json(&serde_json::json!({ "query": query }))
         .send()
@@ -456,8 +630,93 @@ pub async fn fetch_team_members(config: &Config) -> Result<Vec<TeamMemberInfo>>
     Ok(members)
 }
 
-/// Get the current user's ID
-pub async fn fetch_current_user_id(config: &Config) -> Result<String> {
+/// Fetch teams and their labels (for issue creation), merged across accounts
+pub async fn fetch_teams(config: &Config) -> Result<Vec<TeamInfo>> {
+    let tag_workspace = config.has_multiple_linear_workspaces();
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(|account| async move {
+        let mut teams = fetch_teams_for_account(account).await?;
+        if tag_workspace {
+            for team in &mut teams {
+                team.workspace = Some(account.name.clone());
+            }
+        }
+        Ok(teams)
+    }))
+    .await;
+    merge_account_results(results, |t: &TeamInfo| t.id.clone())
+}
+
+async fn fetch_teams_for_account(account: &LinearAccount) -> Result<Vec<TeamInfo>> {
+    let client = &*HTTP_CLIENT;
+
+    let query = r#"
+        query Teams {
+            teams(first: 100) {
+                nodes {
+                    id
+                    key
+                    name
+                }
+            }
+            issueLabels(first: 250) {
+                nodes {
+                    id
+                    name
+                    team {
+                        id
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response = client
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({ "query": query }))
+        .send()
+        .await?;
+
+    let body: GraphQLResponse<TeamsData> = response.json().await?;
+
+    let Some(data) = body.data else {
+        return Ok(vec![]);
+    };
+
+    let mut teams = data.teams.nodes;
+    for team in &mut teams {
+        team.labels = data
+            .issue_labels
+            .nodes
+            .iter()
+            .filter(|l| l.team.as_ref().is_none_or(|t| t.id == team.id))
+            .map(|l| LabelInfo {
+                id: l.id.clone(),
+                name: l.name.clone(),
+            })
+            .collect();
+        team.labels.sort_by_key(|l| l.name.to_lowercase());
+    }
+    teams.sort_by_key(|t| t.name.to_lowercase());
+
+    Ok(teams)
+}
+
+/// Get the current user's ID in every account ("me" differs per workspace)
+pub async fn fetch_current_user_ids(config: &Config) -> Result<Vec<String>> {
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(|a| async move {
+        fetch_current_user_id_for_account(a)
+            .await
+            .map(|id| vec![id])
+    }))
+    .await;
+    merge_account_results(results, |id: &String| id.clone())
+}
+
+async fn fetch_current_user_id_for_account(account: &LinearAccount) -> Result<String> {
     let client = &*HTTP_CLIENT;
 
     let query = r#"
@@ -479,8 +738,8 @@ pub async fn fetch_current_user_id(config: &Config) -> Result<String> {
     }
 
     let response = client
-        .post(LINEAR_API_URL)
-        .header("Authorization", &config.tokens.linear)
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
         .header("Content-Type", "application/json")
         .json(&serde_json::json!({ "query": query }))
         .send()
@@ -493,13 +752,363 @@ pub async fn fetch_current_user_id(config: &Config) -> Result<String> {
         .ok_or_else(|| anyhow::anyhow!("Failed to get current user ID"))
 }
 
+// =============================================================================
+// Public API: Issue Creation
+// =============================================================================
+
+/// Fields for Linear's `issueCreate` mutation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueCreate {
+    pub team_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: LinearPriority,
+    pub project_id: Option<String>,
+    pub label_ids: Vec<String>,
+    pub parent_id: Option<String>,
+}
+
+impl IssueCreate {
+    /// GraphQL `IssueCreateInput`, assigned to `assignee_id`
+    fn to_input(&self, assignee_id: &str) -> serde_json::Value {
+        let mut input = serde_json::json!({
+            "teamId": self.team_id,
+            "title": self.title,
+            "priority": self.priority as i64,
+            "assigneeId": assignee_id,
+        });
+        if let Some(description) = &self.description {
+            input["description"] = description.clone().into();
+        }
+        if let Some(project_id) = &self.project_id {
+            input["projectId"] = project_id.clone().into();
+        }
+        if !self.label_ids.is_empty() {
+            input["labelIds"] = self.label_ids.clone().into();
+        }
+        if let Some(parent_id) = &self.parent_id {
+            input["parentId"] = parent_id.clone().into();
+        }
+        input
+    }
+}
+
+/// Create an issue assigned to the current user in the given workspace
+pub async fn create_issue(
+    config: &Config,
+    workspace: Option<&str>,
+    issue: &IssueCreate,
+) -> Result<LinkedLinearIssue> {
+    let account = LinearAccount {
+        name: workspace.unwrap_or_default().to_string(),
+        token: config.linear_token_for(workspace).to_string(),
+        api_url: config.linear.api_url.clone(),
+        webhook_secret: None,
+    };
+    let assignee_id = fetch_current_user_id_for_account(&account).await?;
+
+    let mutation = format!(
+        r#"
+        mutation CreateIssue($input: IssueCreateInput!) {{
+            issueCreate(input: $input) {{
+                success
+                issue {{
+                    {}
+                }}
+            }}
+        }}
+        "#,
+        ISSUE_FIELDS
+    );
+
+    let response = HTTP_CLIENT
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({
+            "query": mutation,
+            "variables": { "input": issue.to_input(&assignee_id) }
+        }))
+        .send()
+        .await?;
+
+    #[derive(Deserialize)]
+    struct CreateData {
+        #[serde(rename = "issueCreate")]
+        issue_create: CreatePayload,
+    }
+
+    #[derive(Deserialize)]
+    struct CreatePayload {
+        success: bool,
+        issue: Option<IssueNode>,
+    }
+
+    let body: GraphQLResponse<CreateData> = response.json().await?;
+
+    if let Some(error) = body.errors.as_ref().and_then(|e| e.first()) {
+        anyhow::bail!("Linear rejected issue: {}", error.message);
+    }
+
+    let payload = body
+        .data
+        .map(|d| d.issue_create)
+        .filter(|p| p.success)
+        .ok_or_else(|| anyhow::anyhow!("Linear did not create the issue"))?;
+
+    let mut linked = payload
+        .issue
+        .and_then(|node| parse_issue_node(config, node))
+        .ok_or_else(|| anyhow::anyhow!("Created issue missing from response"))?;
+    linked.issue.workspace = workspace.map(String::from);
+    Ok(linked)
+}
+
+// =============================================================================
+// Public API: Inbox
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct NotificationsData {
+    notifications: NotificationConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationConnection {
+    nodes: Vec<NotificationNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NotificationNode {
+    id: String,
+    #[serde(rename = "type")]
+    notification_type: String,
+    created_at: String,
+    read_at: Option<String>,
+    archived_at: Option<String>,
+    actor: Option<ActorNode>,
+    issue: Option<NotificationIssueNode>,
+    comment: Option<CommentNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ActorNode {
+    name: String,
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationIssueNode {
+    id: String,
+    identifier: String,
+    title: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommentNode {
+    body: Option<String>,
+}
+
+/// Fetch inbox notifications (newest first), merged across accounts
+pub async fn fetch_notifications(config: &Config) -> Result<Vec<LinearNotification>> {
+    let tag_workspace = config.has_multiple_linear_workspaces();
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(|account| async move {
+        let mut notifications = fetch_notifications_for_account(account).await?;
+        if tag_workspace {
+            for notification in &mut notifications {
+                notification.workspace = Some(account.name.clone());
+            }
+        }
+        Ok(notifications)
+    }))
+    .await;
+    let mut notifications = merge_account_results(results, |n: &LinearNotification| n.id.clone())?;
+    notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+    Ok(notifications)
+}
+
+async fn fetch_notifications_for_account(
+    account: &LinearAccount,
+) -> Result<Vec<LinearNotification>> {
+    let client = &*HTTP_CLIENT;
+
+    let query = r#"
+        query Inbox {
+            notifications(first: 50) {
+                nodes {
+                    id
+                    type
+                    createdAt
+                    readAt
+                    archivedAt
+                    actor {
+                        name
+                        displayName
+                    }
+                    ... on IssueNotification {
+                        issue {
+                            id
+                            identifier
+                            title
+                            url
+                        }
+                        comment {
+                            body
+                        }
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response = client
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({ "query": query }))
+        .send()
+        .await?;
+
+    let body: GraphQLResponse<NotificationsData> = response.json().await?;
+
+    if let Some(error) = body.errors.as_ref().and_then(|e| e.first()) {
+        anyhow::bail!("Linear inbox: {}", error.message);
+    }
+
+    let nodes = body.data.map(|d| d.notifications.nodes).unwrap_or_default();
+    Ok(nodes
+        .into_iter()
+        .filter_map(parse_notification_node)
+        .collect())
+}
+
+/// Convert a notification node, skipping archived and unsupported kinds
+fn parse_notification_node(node: NotificationNode) -> Option<LinearNotification> {
+    if node.archived_at.is_some() {
+        return None;
+    }
+    let kind = NotificationKind::from_linear_type(&node.notification_type)?;
+    let excerpt = node.comment.and_then(|c| c.body).and_then(|body| {
+        body.lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .map(String::from)
+    });
+    let issue = node.issue;
+    Some(LinearNotification {
+        id: node.id,
+        kind,
+        issue_id: issue.as_ref().map(|i| i.id.clone()),
+        issue_identifier: issue.as_ref().map(|i| i.identifier.clone()),
+        issue_title: issue.as_ref().and_then(|i| i.title.clone()),
+        issue_url: issue.and_then(|i| i.url),
+        actor: node.actor.map(|a| a.display_name.unwrap_or(a.name)),
+        excerpt,
+        created_at: parse_datetime(&node.created_at),
+        read_at: node.read_at.as_deref().map(parse_datetime),
+        workspace: None,
+    })
+}
+
+/// Mark notifications as read in one workspace, a few at a time. Returns
+/// the ids Linear didn't mark, with why.
+pub async fn mark_notifications_read(
+    config: &Config,
+    workspace: Option<&str>,
+    ids: &[String],
+    read_at: DateTime<Utc>,
+) -> Vec<(String, anyhow::Error)> {
+    let token = config.linear_token_for(workspace);
+
+    stream::iter(ids.iter().cloned())
+        .map(|id| async move {
+            let result = mark_notification_read(config, token, &id, read_at).await;
+            (id, result)
+        })
+        .buffer_unordered(5)
+        .filter_map(|(id, result)| async move { result.err().map(|e| (id, e)) })
+        .collect()
+        .await
+}
+
+async fn mark_notification_read(
+    config: &Config,
+    token: &str,
+    id: &str,
+    read_at: DateTime<Utc>,
+) -> Result<()> {
+    let mutation = r#"
+        mutation MarkRead($id: String!, $readAt: DateTime!) {
+            notificationUpdate(id: $id, input: { readAt: $readAt }) {
+                success
+            }
+        }
+    "#;
+
+    #[derive(Deserialize)]
+    struct UpdateData {
+        #[serde(rename = "notificationUpdate")]
+        notification_update: UpdatePayload,
+    }
+
+    #[derive(Deserialize)]
+    struct UpdatePayload {
+        success: bool,
+    }
+
+    let response = HTTP_CLIENT
+        .post(&config.linear.api_url)
+        .header("Authorization", token)
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({
+            "query": mutation,
+            "variables": { "id": id, "readAt": read_at.to_rfc3339() }
+        }))
+        .send()
+        .await?;
+
+    let body: GraphQLResponse<UpdateData> = response.json().await?;
+
+    if let Some(error) = body.errors.as_ref().and_then(|e| e.first()) {
+        anyhow::bail!("Linear rejected update: {}", error.message);
+    }
+    if !body.data.is_some_and(|d| d.notification_update.success) {
+        anyhow::bail!("Linear did not mark the notification read");
+    }
+    Ok(())
+}
+
 // =============================================================================
 // Public API: Search
 // =============================================================================
 
-/// Search all Linear issues (for full search mode)
+/// Search all Linear issues (for full search mode), across every account
 #[allow(dead_code)]
 pub async fn search_issues(config: &Config, query: &str) -> Result<Vec<LinearIssue>> {
+    let tag_workspace = config.has_multiple_linear_workspaces();
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(|account| async move {
+        let mut issues = search_issues_for_account(config, account, query).await?;
+        if tag_workspace {
+            for issue in &mut issues {
+                issue.workspace = Some(account.name.clone());
+            }
+        }
+        Ok(issues)
+    }))
+    .await;
+    merge_account_results(results, |issue: &LinearIssue| issue.id.clone())
+}
+
+async fn search_issues_for_account(
+    config: &Config,
+    account: &LinearAccount,
+    query: &str,
+) -> Result<Vec<LinearIssue>> {
     let client = &*HTTP_CLIENT;
 
     let graphql_query = format!(
@@ -516,8 +1125,8 @@ pub async fn search_issues(config: &Config, query: &str) -> Result<Vec<LinearIss
     );
 
     let response = client
-        .post(LINEAR_API_URL)
-        .header("Authorization", &config.tokens.linear)
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
         .header("Content-Type", "application/json")
         .json(&serde_json::json!({
             "query": graphql_query,
@@ -540,7 +1149,7 @@ pub async fn search_issues(config: &Config, query: &str) -> Result<Vec<LinearIss
             d.issue_search
                 .nodes
                 .into_iter()
-                .filter_map(|node| parse_issue_node(node).map(|li| li.issue))
+                .filter_map(|node| parse_issue_node(config, node).map(|li| li.issue))
                 .collect()
         })
         .unwrap_or_default();
@@ -548,12 +1157,137 @@ pub async fn search_issues(config: &Config, query: &str) -> Result<Vec<LinearIss
     Ok(issues)
 }
 
+/// Load issues by identifier ("ENG-123"), across every account. Identifiers
+/// no account knows are skipped.
+pub async fn fetch_issues_by_identifier(
+    config: &Config,
+    identifiers: &[String],
+) -> Result<Vec<LinearIssue>> {
+    let filters: Vec<serde_json::Value> = identifiers
+        .iter()
+        .filter_map(|identifier| identifier_filter(identifier))
+        .collect();
+    if filters.is_empty() {
+        return Ok(vec![]);
+    }
+    let filter = serde_json::json!({ "or": filters });
+
+    let tag_workspace = config.has_multiple_linear_workspaces();
+    let accounts = config.linear_accounts();
+    let results = join_all(accounts.iter().map(|account| {
+        let filter = &filter;
+        async move {
+            let mut issues = fetch_issues_by_filter(config, account, filter).await?;
+            if tag_workspace {
+                for issue in &mut issues {
+                    issue.workspace = Some(account.name.clone());
+                }
+            }
+            Ok(issues)
+        }
+    }))
+    .await;
+    merge_account_results(results, |issue: &LinearIssue| issue.id.clone())
+}
+
+/// Issue filter matching one identifier (team key + number)
+fn identifier_filter(identifier: &str) -> Option<serde_json::Value> {
+    let (team, number) = identifier.rsplit_once('-')?;
+    let number: u64 = number.parse().ok()?;
+    Some(serde_json::json!({
+        "team": { "key": { "eqIgnoreCase": team } },
+        "number": { "eq": number },
+    }))
+}
+
+async fn fetch_issues_by_filter(
+    config: &Config,
+    account: &LinearAccount,
+    filter: &serde_json::Value,
+) -> Result<Vec<LinearIssue>> {
+    let graphql_query = format!(
+        r#"
+        query IssuesByIdentifier($filter: IssueFilter!) {{
+            issues(filter: $filter, first: 50) {{
+                nodes {{
+                    {}
+                }}
+            }}
+        }}
+        "#,
+        ISSUE_FIELDS
+    );
+
+    let response = HTTP_CLIENT
+        .post(&account.api_url)
+        .header("Authorization", &account.token)
+        .header("Content-Type", "application/json")
+        .json(&serde_json::json!({
+            "query": graphql_query,
+            "variables": { "filter": filter }
+        }))
+        .send()
+        .await?;
+
+    #[derive(Deserialize)]
+    struct IssuesData {
+        issues: IssueConnection,
+    }
+
+    let body: GraphQLResponse<IssuesData> = response.json().await?;
+    if let Some(error) = body.errors.as_ref().and_then(|e| e.first()) {
+        anyhow::bail!("Linear issues by identifier: {}", error.message);
+    }
+
+    Ok(body
+        .data
+        .map(|d| {
+            d.issues
+                .nodes
+                .into_iter()
+                .filter_map(|node| parse_issue_node(config, node).map(|li| li.issue))
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Merge per-account results, de-duplicating by key.
+///
+/// Succeeds if at least one account answered; only fails when all of them did.
+fn merge_account_results<T>(
+    results: Vec<Result<Vec<T>>>,
+    key: impl Fn(&T) -> String,
+) -> Result<Vec<T>> {
+    let mut seen = std::collections::HashSet::new();
+    let mut merged = Vec::new();
+    let mut last_err = None;
+    let mut any_ok = false;
+
+    for result in results {
+        match result {
+            Ok(items) => {
+                any_ok = true;
+                merged.extend(items.into_iter().filter(|item| seen.insert(key(item))));
+            }
+            Err(e) => {
+                tracing::debug!("Linear account request failed: {}", e);
+                last_err = Some(e);
+            }
+        }
+    }
+
+    match last_err {
+        Some(e) if !any_ok => Err(e),
+        _ => Ok(merged),
+    }
+}
+
 // =============================================================================
 // Parsing Helpers
 // =============================================================================
 
 /// Parse a typed IssueNode into LinkedLinearIssue
-fn parse_issue_node(node: IssueNode) -> Option<LinkedLinearIssue> {
+fn parse_issue_node(config: &Config, node: IssueNode) -> Option<LinkedLinearIssue> {
     let state = node.state.as_ref()?;
     let status = parse_status(&state.state_type, &state.name);
 
@@ -577,22 +1311,26 @@ fn parse_issue_node(node: IssueNode) -> Option<LinkedLinearIssue> {
             .as_ref()
             .map(|a| a.display_name.clone().unwrap_or_else(|| a.name.clone())),
         estimate: node.estimate.map(|e| e as f32),
-        attachments: parse_attachments(&node.attachments),
+        due_date: parse_due_date(node.due_date.as_deref()),
+        sla_breaches_at: node.sla_breaches_at.as_deref().and_then(|s| s.parse().ok()),
+        attachments: parse_attachments(config, &node.attachments),
         parent: parse_parent(node.parent),
         children: parse_children(node.children),
+        relations: parse_relations(node.relations, node.inverse_relations),
+        workspace: None,
     };
 
-    let pr_url = find_github_pr_url(&node.attachments);
+    let pr_urls = find_github_pr_urls(config, &node.attachments);
 
     Some(LinkedLinearIssue {
         issue,
-        linked_pr_url: pr_url,
-        working_directory: node.branch_name,
+        linked_pr_urls: pr_urls,
+        branch_name: node.branch_name,
     })
 }
 
 /// Parse Linear state type and name into LinearStatus
-fn parse_status(state_type: &str, state_name: &str) -> LinearStatus {
+pub(crate) fn parse_status(state_type: &str, state_name: &str) -> LinearStatus {
     let name_lower = state_name.to_lowercase();
 
     match state_type {
@@ -631,14 +1369,19 @@ fn infer_status_from_name(name: &str) -> LinearStatus {
     }
 }
 
-fn parse_priority(priority: Option<i64>) -> LinearPriority {
+pub(crate) fn parse_priority(priority: Option<i64>) -> LinearPriority {
     priority.map(LinearPriority::from_int).unwrap_or_default()
 }
 
-fn parse_datetime(s: &str) -> DateTime<Utc> {
+pub(crate) fn parse_datetime(s: &str) -> DateTime<Utc> {
     s.parse().unwrap_or_else(|_| Utc::now())
 }
 
+/// Parse Linear's `TimelessDate` (`YYYY-MM-DD`)
+pub(crate) fn parse_due_date(s: Option<&str>) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s?, "%Y-%m-%d").ok()
+}
+
 fn parse_cycle(cycle: Option<CycleNode>) -> Option<LinearCycle> {
     cycle.map(|c| LinearCycle {
         id: c.id,
@@ -663,13 +1406,16 @@ fn parse_labels(labels: Option<LabelConnection>) -> Vec<LinearLabel> {
         .unwrap_or_default()
 }
 
-fn parse_attachments(attachments: &Option<AttachmentConnection>) -> Vec<LinearAttachment> {
+fn parse_attachments(
+    config: &Config,
+    attachments: &Option<AttachmentConnection>,
+) -> Vec<LinearAttachment> {
     attachments
         .as_ref()
         .map(|a| {
             a.nodes
                 .iter()
-                .filter(|att| !is_github_pr_url(&att.url))
+                .filter(|att| !is_github_pr_url(config, &att.url))
                 .map(|att| LinearAttachment {
                     id: att.id.clone(),
                     url: att.url.clone(),
@@ -707,6 +1453,7 @@ fn parse_children(children: Option<ChildConnection>) -> Vec<LinearChildRef> {
                         url: child.url.unwrap_or_default(),
                         status,
                         priority: parse_priority(child.priority),
+                        estimate: child.estimate.map(|e| e as f32),
                     })
                 })
                 .collect()
@@ -714,15 +1461,67 @@ fn parse_children(children: Option<ChildConnection>) -> Vec<LinearChildRef> {
         .unwrap_or_default()
 }
 
-fn find_github_pr_url(attachments: &Option<AttachmentConnection>) -> Option<String> {
-    attachments.as_ref().and_then(|a| {
-        a.nodes
-            .iter()
-            .find(|att| is_github_pr_url(&att.url))
-            .map(|att| att.url.clone())
+/// Merge both sides of Linear's relations into refs seen from this issue
+fn parse_relations(
+    relations: Option<RelationConnection>,
+    inverse: Option<InverseRelationConnection>,
+) -> Vec<LinearRelationRef> {
+    let outgoing = relations
+        .map(|r| r.nodes)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|r| {
+            let kind = relation_kind(&r.relation_type, false)?;
+            parse_related_issue(kind, r.related_issue?)
+        });
+    let incoming = inverse
+        .map(|r| r.nodes)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|r| {
+            let kind = relation_kind(&r.relation_type, true)?;
+            parse_related_issue(kind, r.issue?)
+        });
+
+    let mut refs: Vec<LinearRelationRef> = outgoing.chain(incoming).collect();
+    refs.sort_by_key(|r| r.kind.sort_order());
+    refs
+}
+
+/// Map a Linear relation type; `inverse` is true when the other issue owns it
+fn relation_kind(relation_type: &str, inverse: bool) -> Option<RelationKind> {
+    match (relation_type, inverse) {
+        ("blocks", false) => Some(RelationKind::Blocks),
+        ("blocks", true) => Some(RelationKind::BlockedBy),
+        ("related" | "similar", _) => Some(RelationKind::Related),
+        ("duplicate", _) => Some(RelationKind::Duplicate),
+        _ => None,
+    }
+}
+
+fn parse_related_issue(kind: RelationKind, node: RelatedIssueNode) -> Option<LinearRelationRef> {
+    let state = node.state.as_ref()?;
+    Some(LinearRelationRef {
+        kind,
+        status: parse_status(&state.state_type, &state.name),
+        id: node.id,
+        identifier: node.identifier,
+        title: node.title.unwrap_or_default(),
+        url: node.url.unwrap_or_default(),
     })
 }
 
-fn is_github_pr_url(url: &str) -> bool {
-    url.contains("github.com") && url.contains("/pull/")
+fn find_github_pr_urls(config: &Config, attachments: &Option<AttachmentConnection>) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for att in attachments.iter().flat_map(|a| &a.nodes) {
+        if is_github_pr_url(config, &att.url) && !urls.contains(&att.url) {
+            urls.push(att.url.clone());
+        }
+    }
+    urls
+}
+
+/// PR links on github.com or the configured GitHub instance's host
+pub(crate) fn is_github_pr_url(config: &Config, url: &str) -> bool {
+    github::parse_pr_url(config, url).is_some()
 }
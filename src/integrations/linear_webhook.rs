@@ -0,0 +1,603 @@
+//! Embedded receiver for Linear webhook deliveries.
+//!
+//! Linear POSTs a JSON payload for every Issue/Comment/Attachment change. The
+//! listener verifies the `Linear-Signature` HMAC, converts the payload into a
+//! [`WebhookUpdate`] and pushes it to the app as [`RefreshResult::Live`], so
+//! rows change within a second instead of waiting for the next poll.
+//!
+//! The HTTP handling is deliberately minimal (one request per connection,
+//! `Content-Length` bodies only) - Linear is the only expected client.
+
+use crate::config::Config;
+use crate::data::{GitHubPR, LinearAttachment, LinearCycle, LinearIssue, LinearLabel, Workstream};
+use crate::integrations::{github, linear};
+use crate::tui::RefreshResult;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+
+/// Header carrying the hex-encoded HMAC-SHA256 of the raw body
+const SIGNATURE_HEADER: &str = "linear-signature";
+
+/// Upper bound for request headers (Linear sends a handful)
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+
+/// Upper bound for request bodies (issue payloads are a few KB)
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Time a client gets to send its whole request, so stalled clients don't
+/// pile up open connections
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+// =============================================================================
+// Updates
+// =============================================================================
+
+/// A change to apply to the in-memory workstreams, derived from one delivery
+#[derive(Debug, Clone)]
+pub enum WebhookUpdate {
+    /// Issue created or updated (fields not carried by webhooks are kept)
+    Issue(Box<LinearIssue>),
+    /// Issue deleted or archived
+    IssueRemoved(String),
+    /// Comment added or edited - bumps the issue's activity time
+    Comment { issue_id: String, at: DateTime<Utc> },
+    /// Non-PR document attached to an issue
+    Attachment {
+        issue_id: String,
+        attachment: LinearAttachment,
+    },
+    /// GitHub PR attached to an issue (already fetched from GitHub)
+    PullRequest { issue_id: String, pr: GitHubPR },
+}
+
+impl WebhookUpdate {
+    /// ID of the issue this update targets
+    pub fn issue_id(&self) -> &str {
+        match self {
+            WebhookUpdate::Issue(issue) => &issue.id,
+            WebhookUpdate::IssueRemoved(id) => id,
+            WebhookUpdate::Comment { issue_id, .. }
+            | WebhookUpdate::Attachment { issue_id, .. }
+            | WebhookUpdate::PullRequest { issue_id, .. } => issue_id,
+        }
+    }
+
+    /// Apply this update to `workstreams`. Returns true if anything changed.
+    ///
+    /// New issues are only inserted when assigned to one of `current_user_ids`
+    /// (the viewer in each workspace), and issues reassigned to someone else
+    /// are dropped, mirroring the `assignee:me` scope of the regular refresh.
+    pub fn apply(self, workstreams: &mut Vec<Workstream>, current_user_ids: &[String]) -> bool {
+        match self {
+            WebhookUpdate::Issue(issue) => {
+                let assigned_elsewhere = !current_user_ids.is_empty()
+                    && !issue
+                        .assignee_id
+                        .as_ref()
+                        .is_some_and(|id| current_user_ids.contains(id));
+                let existing = workstreams
+                    .iter()
+                    .position(|ws| ws.linear_issue.id == issue.id);
+
+                match existing {
+                    // Older than what we already have (replayed over a fresher fetch)
+                    Some(idx) if workstreams[idx].linear_issue.updated_at > issue.updated_at => {
+                        return false;
+                    }
+                    Some(idx) if assigned_elsewhere => {
+                        workstreams.remove(idx);
+                    }
+                    Some(idx) => {
+                        let ws = &mut workstreams[idx];
+                        let mut issue = *issue;
+                        // Webhook payloads don't carry these - keep what the last refresh saw
+                        issue.attachments = std::mem::take(&mut ws.linear_issue.attachments);
+                        issue.children = std::mem::take(&mut ws.linear_issue.children);
+                        issue.relations = std::mem::take(&mut ws.linear_issue.relations);
+                        issue.workspace = ws.linear_issue.workspace.take().or(issue.workspace);
+                        issue.parent = match (issue.parent, ws.linear_issue.parent.take()) {
+                            (Some(new), Some(old)) if old.id == new.id => Some(old),
+                            (new, _) => new,
+                        };
+                        ws.linear_issue = issue;
+                        ws.stale = false;
+                    }
+                    None if assigned_elsewhere || current_user_ids.is_empty() => return false,
+                    None => workstreams.push(Workstream {
+                        linear_issue: *issue,
+                        github_pr: None,
+                        vercel_deployment: None,
+                        linked_prs: vec![],
+                        agent_sessions: vec![],
+                        agent_session: None,
+                        stale: false,
+                    }),
+                }
+                true
+            }
+            WebhookUpdate::IssueRemoved(id) => {
+                let before = workstreams.len();
+                workstreams.retain(|ws| ws.linear_issue.id != id);
+                workstreams.len() != before
+            }
+            WebhookUpdate::Comment { issue_id, at } => {
+                let Some(ws) = find_mut(workstreams, &issue_id) else {
+                    return false;
+                };
+                if at > ws.linear_issue.updated_at {
+                    ws.linear_issue.updated_at = at;
+                }
+                true
+            }
+            WebhookUpdate::Attachment {
+                issue_id,
+                attachment,
+            } => {
+                let Some(ws) = find_mut(workstreams, &issue_id) else {
+                    return false;
+                };
+                let attachments = &mut ws.linear_issue.attachments;
+                match attachments.iter_mut().find(|a| a.id == attachment.id) {
+                    Some(existing) => *existing = attachment,
+                    None => attachments.push(attachment),
+                }
+                true
+            }
+            WebhookUpdate::PullRequest { issue_id, pr } => {
+                let Some(ws) = find_mut(workstreams, &issue_id) else {
+                    return false;
+                };
+                ws.upsert_pr(pr);
+                true
+            }
+        }
+    }
+}
+
+fn find_mut<'a>(workstreams: &'a mut [Workstream], issue_id: &str) -> Option<&'a mut Workstream> {
+    workstreams
+        .iter_mut()
+        .find(|ws| ws.linear_issue.id == issue_id)
+}
+
+// =============================================================================
+// Payload Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct WebhookPayload {
+    action: String,
+    #[serde(rename = "type")]
+    kind: String,
+    data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueData {
+    id: String,
+    identifier: String,
+    title: String,
+    description: Option<String>,
+    url: String,
+    created_at: String,
+    updated_at: String,
+    priority: Option<i64>,
+    estimate: Option<f64>,
+    due_date: Option<String>,
+    sla_breaches_at: Option<String>,
+    state: StateData,
+    cycle: Option<CycleData>,
+    #[serde(default)]
+    labels: Vec<LabelData>,
+    project: Option<NamedData>,
+    team: Option<NamedData>,
+    assignee: Option<NamedData>,
+    parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StateData {
+    name: String,
+    #[serde(rename = "type")]
+    state_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CycleData {
+    id: String,
+    name: Option<String>,
+    number: i64,
+    starts_at: String,
+    ends_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LabelData {
+    name: String,
+    color: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedData {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentData {
+    issue_id: String,
+    updated_at: Option<String>,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AttachmentData {
+    id: String,
+    issue_id: String,
+    url: String,
+    title: Option<String>,
+    subtitle: Option<String>,
+    source_type: Option<String>,
+}
+
+// =============================================================================
+// Parsing
+// =============================================================================
+
+/// Parsed delivery before any network enrichment
+#[derive(Debug)]
+enum ParsedEvent {
+    Update(WebhookUpdate),
+    /// PR attachment - needs a GitHub fetch before it becomes an update
+    PullRequestLink {
+        issue_id: String,
+        url: String,
+    },
+}
+
+/// Verify a `Linear-Signature` header against the raw request body
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let Ok(expected) = hex::decode(signature.trim()) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Convert a webhook body into an update. `Ok(None)` means the event isn't
+/// something the dashboard tracks (PR attachments are resolved by the
+/// listener, which fetches the PR from GitHub first).
+pub fn parse_payload(config: &Config, body: &[u8]) -> Result<Option<WebhookUpdate>> {
+    Ok(parse_event(config, body)?.and_then(|event| match event {
+        ParsedEvent::Update(update) => Some(update),
+        ParsedEvent::PullRequestLink { .. } => None,
+    }))
+}
+
+fn parse_event(config: &Config, body: &[u8]) -> Result<Option<ParsedEvent>> {
+    let payload: WebhookPayload =
+        serde_json::from_slice(body).context("Invalid webhook payload")?;
+    let removed = payload.action == "remove";
+
+    let event = match payload.kind.as_str() {
+        "Issue" if removed => {
+            let id = payload.data["id"]
+                .as_str()
+                .context("Issue payload missing id")?;
+            ParsedEvent::Update(WebhookUpdate::IssueRemoved(id.to_string()))
+        }
+        "Issue" => {
+            let data: IssueData = serde_json::from_value(payload.data)?;
+            ParsedEvent::Update(WebhookUpdate::Issue(Box::new(issue_from_data(data))))
+        }
+        "Comment" if !removed => {
+            let data: CommentData = serde_json::from_value(payload.data)?;
+            let at = data.updated_at.as_deref().unwrap_or(&data.created_at);
+            ParsedEvent::Update(WebhookUpdate::Comment {
+                issue_id: data.issue_id,
+                at: linear::parse_datetime(at),
+            })
+        }
+        "Attachment" if !removed => {
+            let data: AttachmentData = serde_json::from_value(payload.data)?;
+            if linear::is_github_pr_url(config, &data.url) {
+                ParsedEvent::PullRequestLink {
+                    issue_id: data.issue_id,
+                    url: data.url,
+                }
+            } else {
+                ParsedEvent::Update(WebhookUpdate::Attachment {
+                    issue_id: data.issue_id,
+                    attachment: LinearAttachment {
+                        id: data.id,
+                        url: data.url,
+                        title: data.title.unwrap_or_else(|| "Untitled".to_string()),
+                        subtitle: data.subtitle,
+                        source_type: data.source_type,
+                    },
+                })
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(event))
+}
+
+fn issue_from_data(data: IssueData) -> LinearIssue {
+    let status = linear::parse_status(&data.state.state_type, &data.state.name);
+
+    LinearIssue {
+        id: data.id,
+        identifier: data.identifier,
+        title: data.title,
+        description: data.description,
+        status,
+        priority: linear::parse_priority(data.priority),
+        url: data.url,
+        created_at: linear::parse_datetime(&data.created_at),
+        updated_at: linear::parse_datetime(&data.updated_at),
+        cycle: data.cycle.map(|c| LinearCycle {
+            name: c.name.unwrap_or_else(|| format!("Cycle {}", c.number)),
+            id: c.id,
+            number: c.number as i32,
+            starts_at: linear::parse_datetime(&c.starts_at),
+            ends_at: linear::parse_datetime(&c.ends_at),
+        }),
+        labels: data
+            .labels
+            .into_iter()
+            .map(|l| LinearLabel {
+                name: l.name,
+                color: l.color.unwrap_or_else(|| "#888888".to_string()),
+            })
+            .collect(),
+        project: data.project.map(|p| p.name),
+        team: data.team.map(|t| t.name),
+        assignee_id: data.assignee.as_ref().map(|a| a.id.clone()),
+        assignee_name: data.assignee.map(|a| a.name),
+        estimate: data.estimate.map(|e| e as f32),
+        due_date: linear::parse_due_date(data.due_date.as_deref()),
+        sla_breaches_at: data.sla_breaches_at.as_deref().and_then(|s| s.parse().ok()),
+        attachments: vec![],
+        // Only the id is delivered; `apply` keeps the full ref from the last refresh
+        parent: data.parent_id.map(|id| crate::data::LinearParentRef {
+            id,
+            identifier: String::new(),
+            title: String::new(),
+            url: String::new(),
+        }),
+        children: vec![],
+        relations: vec![],
+        // Tagged by the listener from the secret that signed the delivery
+        workspace: None,
+    }
+}
+
+// =============================================================================
+// HTTP Listener
+// =============================================================================
+
+/// Bind the configured address and serve deliveries in the background.
+///
+/// Refuses to start without a signing secret - unsigned deliveries would let
+/// anyone who can reach the port rewrite issues.
+pub async fn spawn(config: Arc<Config>, tx: mpsc::Sender<RefreshResult>) -> Result<()> {
+    let multiple = config.has_multiple_linear_workspaces();
+    anyhow::ensure!(
+        !multiple || config.webhook.secret.is_none(),
+        "webhook.secret can't be tied to one of several Linear workspaces; \
+         set webhook_secret on each [[linear.accounts]] instead"
+    );
+    anyhow::ensure!(
+        !signing_secrets(&config).is_empty(),
+        "{} is required to verify Linear deliveries",
+        if multiple {
+            "webhook_secret on [[linear.accounts]]"
+        } else {
+            "webhook.secret"
+        }
+    );
+    let listener = TcpListener::bind(&config.webhook.listen_addr)
+        .await
+        .with_context(|| format!("Failed to bind {}", config.webhook.listen_addr))?;
+    tokio::spawn(serve(listener, config, tx));
+    Ok(())
+}
+
+/// Accept connections until the app drops its receiver
+pub async fn serve(listener: TcpListener, config: Arc<Config>, tx: mpsc::Sender<RefreshResult>) {
+    while !tx.is_closed() {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                tracing::debug!("Webhook accept failed: {}", e);
+                continue;
+            }
+        };
+        let config = Arc::clone(&config);
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &config, &tx).await {
+                tracing::debug!("Webhook request failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    config: &Config,
+    tx: &mpsc::Sender<RefreshResult>,
+) -> Result<()> {
+    let request = match read_request(&mut stream, SIGNATURE_HEADER).await {
+        Ok(request) => request,
+        Err(e) => {
+            write_status(&mut stream, "400 Bad Request").await?;
+            return Err(e);
+        }
+    };
+
+    if request.method != "POST" {
+        return write_status(&mut stream, "405 Method Not Allowed").await;
+    }
+
+    // The secret that verifies the delivery tells us its workspace. Without a
+    // secret nothing can be verified, so every delivery is rejected.
+    let signature = request.signature.as_deref().unwrap_or_default();
+    let Some((workspace, _)) = signing_secrets(config)
+        .into_iter()
+        .find(|(_, secret)| verify_signature(secret, &request.body, signature))
+    else {
+        return write_status(&mut stream, "401 Unauthorized").await;
+    };
+
+    let mut event = match parse_event(config, &request.body) {
+        Ok(event) => event,
+        Err(e) => {
+            write_status(&mut stream, "400 Bad Request").await?;
+            return Err(e);
+        }
+    };
+    if let Some(ParsedEvent::Update(WebhookUpdate::Issue(issue))) = &mut event {
+        issue.workspace = workspace.map(String::from);
+    }
+
+    // Acknowledge before any GitHub round-trip so Linear doesn't retry
+    write_status(&mut stream, "200 OK").await?;
+
+    let update = match event {
+        Some(ParsedEvent::Update(update)) => update,
+        Some(ParsedEvent::PullRequestLink { issue_id, url }) => {
+            let pr = github::fetch_pr_from_url(config, &url).await?;
+            WebhookUpdate::PullRequest { issue_id, pr }
+        }
+        None => return Ok(()),
+    };
+
+    crate::util::send_or_log(tx, RefreshResult::Live(update), "webhook update").await;
+    Ok(())
+}
+
+/// Signing secrets paired with the workspace issues they sign belong to.
+///
+/// With several workspaces each account's `webhook_secret` tags deliveries
+/// with that account; otherwise issues aren't tagged (matching the refresh).
+fn signing_secrets(config: &Config) -> Vec<(Option<&str>, &str)> {
+    let secrets: Vec<(Option<&str>, Option<&str>)> = if config.has_multiple_linear_workspaces() {
+        config
+            .linear
+            .accounts
+            .iter()
+            .map(|a| (Some(a.name.as_str()), a.webhook_secret.as_deref()))
+            .collect()
+    } else {
+        let account_secret = config
+            .linear
+            .accounts
+            .first()
+            .and_then(|a| a.webhook_secret.as_deref());
+        vec![(None, config.webhook.secret.as_deref().or(account_secret))]
+    };
+
+    secrets
+        .into_iter()
+        .filter_map(|(workspace, secret)| Some((workspace, secret.filter(|s| !s.is_empty())?)))
+        .collect()
+}
+
+/// A parsed request: method, signature header value and raw body
+pub(crate) struct Request {
+    pub(crate) method: String,
+    pub(crate) signature: Option<String>,
+    pub(crate) body: Vec<u8>,
+}
+
+/// Read one request, capturing `signature_header` (lowercase) if present.
+/// Fails if the client takes longer than `READ_TIMEOUT`.
+pub(crate) async fn read_request(
+    stream: &mut TcpStream,
+    signature_header: &str,
+) -> Result<Request> {
+    tokio::time::timeout(READ_TIMEOUT, read_request_untimed(stream, signature_header))
+        .await
+        .context("Timed out reading request")?
+}
+
+async fn read_request_untimed(stream: &mut TcpStream, signature_header: &str) -> Result<Request> {
+    let mut buf = Vec::with_capacity(4096);
+    let header_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        anyhow::ensure!(buf.len() < MAX_HEADER_BYTES, "Request headers too large");
+        let mut chunk = [0u8; 4096];
+        let n = stream.read(&mut chunk).await?;
+        anyhow::ensure!(n > 0, "Connection closed mid-request");
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = std::str::from_utf8(&buf[..header_end]).context("Non-UTF8 request headers")?;
+    let mut lines = head.split("\r\n");
+    let method = lines
+        .next()
+        .and_then(|line| line.split_whitespace().next())
+        .context("Missing request line")?
+        .to_string();
+
+    let mut content_length = 0usize;
+    let mut signature = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim().to_ascii_lowercase();
+        if name == "content-length" {
+            content_length = value.trim().parse().context("Invalid Content-Length")?;
+        } else if name == signature_header {
+            signature = Some(value.trim().to_string());
+        }
+    }
+    anyhow::ensure!(content_length <= MAX_BODY_BYTES, "Request body too large");
+
+    let mut body = buf.split_off(header_end + 4);
+    while body.len() < content_length {
+        let mut chunk = vec![0u8; content_length - body.len()];
+        let n = stream.read(&mut chunk).await?;
+        anyhow::ensure!(n > 0, "Connection closed mid-body");
+        body.extend_from_slice(&chunk[..n]);
+    }
+    body.truncate(content_length);
+
+    Ok(Request {
+        method,
+        signature,
+        body,
+    })
+}
+
+pub(crate) async fn write_status(stream: &mut TcpStream, status: &str) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
@@ -4,16 +4,25 @@ pub mod claude;
 pub mod enrichment_cache;
 pub mod github;
 pub mod linear;
+pub mod linear_webhook;
 pub mod openclaw;
+pub mod pr_discovery;
+pub mod session_links;
+pub mod team_share;
 pub mod vercel;
 
 use crate::config::Config;
-use crate::data::{AgentSession, LinearIssue, LinearPriority, LinearStatus, Workstream};
+use crate::data::{
+    AgentSession, GitHubPR, LinearIssue, LinearPriority, LinearStatus, LinkedPr, ReviewRequest,
+    VercelDeployment, Workstream,
+};
 use crate::tui::{RefreshMetadata, RefreshProgress, RefreshResult};
 use anyhow::Result;
 use chrono::Utc;
 use futures::stream::{self, StreamExt};
 use once_cell::sync::Lazy;
+use pr_discovery::DiscoveredPrs;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -28,67 +37,76 @@ pub static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
         .expect("Failed to create HTTP client")
 });
 
-/// Fetches all workstreams by querying Linear, then enriching with GitHub/Vercel data
-pub async fn fetch_workstreams(config: &Config) -> Result<Vec<Workstream>> {
-    // 1. Get Linear issues assigned to user
-    let issues = linear::fetch_assigned_issues(config).await?;
+/// Attached PR URLs and Linear's branch name for one issue
+pub type IssueLinks = (Vec<String>, Option<String>);
 
-    // 2. Pre-load agent session cache ONCE (1 file read + 1 HTTP call total)
-    let agent_cache = agent_cache::AgentSessionCache::load().await;
+/// Fetches all workstreams by querying Linear, then enriching with GitHub/Vercel data.
+/// Also returns each issue's links (see [`issue_links`]).
+pub async fn fetch_workstreams(
+    config: &Config,
+) -> Result<(Vec<Workstream>, HashMap<String, IssueLinks>)> {
+    // 1. Get Linear issues assigned to user, and open PRs authored on GitHub
+    let (issues, authored_prs) = tokio::join!(
+        linear::fetch_assigned_issues(config),
+        github::fetch_authored_prs(config)
+    );
+    let issues = issues?;
+    let links = issue_links(&issues);
+    let mut discovered = discover_prs(config, authored_prs, &issues);
+
+    // 2. Pre-load agent session cache ONCE (1 file read + 1 HTTP call total),
+    // and every attached PR in a few batched GraphQL requests
+    let pr_urls = attached_pr_urls(&issues);
+    let (agent_cache, ()) = tokio::join!(
+        agent_cache::AgentSessionCache::load(&config.linear.identifier_pattern),
+        github::prefetch_prs(config, &pr_urls)
+    );
 
-    // 3. For each issue, find linked PR and deployment
+    // 3. For each issue, find linked PRs and their deployments
     let mut workstreams = Vec::new();
     for issue in issues {
-        let pr = if let Some(pr_url) = &issue.linked_pr_url {
-            match github::fetch_pr_from_url(config, pr_url).await {
-                Ok(pr) => Some(pr),
-                Err(e) => {
-                    tracing::debug!("Failed to fetch PR from {}: {}", pr_url, e);
-                    None
-                }
+        let prs = if issue.linked_pr_urls.is_empty() {
+            let claimed =
+                discovered.claim_for_issue(&issue.issue.identifier, issue.branch_name.as_deref());
+            let pr = match claimed {
+                Some(pr) => Some(pr),
+                None => find_issue_pr_by_branch(config, &agent_cache, &issue).await,
+            };
+            if let Some(pr) = &pr {
+                discovered.claim_url(&pr.url);
             }
+            pr.into_iter().collect()
         } else {
-            None
+            fetch_attached_prs(config, &issue.linked_pr_urls).await
         };
+        let linked_prs = fetch_linked_prs(config, prs).await;
 
-        let deployment = if let Some(ref pr) = pr {
-            match vercel::fetch_deployment_for_branch(config, &pr.repo, &pr.branch).await {
-                Ok(deploy) => deploy,
-                Err(e) => {
-                    tracing::debug!(
-                        "Failed to fetch Vercel deployment for {}/{}: {}",
-                        pr.repo,
-                        pr.branch,
-                        e
-                    );
-                    None
-                }
-            }
-        } else {
-            None
+        let mut ws = Workstream {
+            linear_issue: issue.issue,
+            github_pr: None,
+            vercel_deployment: None,
+            linked_prs: vec![],
+            agent_sessions: vec![],
+            agent_session: None,
+            stale: false,
         };
+        ws.set_linked_prs(linked_prs);
 
         // Find agent session via O(1) cache lookup by git branch
-        // Linear's working_directory is the branch name, match against session's git_branch
-        let agent_sessions = agent_cache.find_all_for_branch_or_identifier(
-            issue.working_directory.as_deref(),
-            &issue.issue.identifier,
-            pr.as_ref().map(|p| p.repo.as_str()),
+        // Linear's suggested branch name is matched exactly against the session's git_branch
+        let repo = ws.github_pr.as_ref().map(|p| p.repo.as_str());
+        ws.agent_sessions = agent_cache.find_all_for_branch_or_identifier(
+            issue.branch_name.as_deref(),
+            &ws.linear_issue.identifier,
+            repo,
         );
-        let agent = agent_cache.find_for_branch_or_identifier(
-            issue.working_directory.as_deref(),
-            &issue.issue.identifier,
-            pr.as_ref().map(|p| p.repo.as_str()),
+        ws.agent_session = agent_cache.find_for_branch_or_identifier(
+            issue.branch_name.as_deref(),
+            &ws.linear_issue.identifier,
+            repo,
         );
 
-        workstreams.push(Workstream {
-            linear_issue: issue.issue,
-            github_pr: pr,
-            vercel_deployment: deployment,
-            agent_sessions,
-            agent_session: agent,
-            stale: false,
-        });
+        workstreams.push(ws);
     }
 
     // Add unlinked sessions (sessions not matched to any issue)
@@ -107,37 +125,63 @@ pub async fn fetch_workstreams(config: &Config) -> Result<Vec<Workstream>> {
 
     for session in agent_cache.all_sessions() {
         if !matched_session_ids.contains(&session.id) {
-            workstreams.push(Workstream {
+            let claimed = session
+                .git_branch
+                .as_deref()
+                .and_then(|branch| discovered.claim_for_branch(branch));
+            let pr = match claimed {
+                Some(pr) => Some(pr),
+                None => find_pr_by_branch(config, None, std::slice::from_ref(session)).await,
+            };
+            if let Some(pr) = &pr {
+                discovered.claim_url(&pr.url);
+            }
+            let mut ws = Workstream {
                 linear_issue: create_placeholder_issue(session),
                 github_pr: None,
                 vercel_deployment: None,
+                linked_prs: vec![],
                 agent_sessions: vec![session.clone()],
                 agent_session: Some(session.clone()),
                 stale: false,
-            });
+            };
+            ws.set_linked_prs(fetch_linked_prs(config, pr.into_iter().collect()).await);
+            workstreams.push(ws);
         }
     }
 
-    Ok(workstreams)
+    // Add PRs no issue or session claimed as standalone rows
+    for pr in discovered.take_unclaimed() {
+        let mut ws = pr_discovery::standalone_workstream(pr.clone());
+        ws.set_linked_prs(fetch_linked_prs(config, vec![pr]).await);
+        workstreams.push(ws);
+    }
+
+    log_not_modified_counts();
+    Ok((workstreams, links))
 }
 
 /// Fetch workstreams incrementally with progress updates (non-blocking)
 /// Sends results via channel as they become available
+///
+/// With `known_issues` (webhooks are pushing Linear changes) Linear isn't
+/// queried at all: the given issues are re-enriched with GitHub/Vercel data.
 pub async fn fetch_workstreams_incremental(
     config: &Config,
     tx: mpsc::Sender<RefreshResult>,
+    known_issues: Option<Vec<LinkedLinearIssue>>,
 ) -> Result<()> {
-    // Step 0: Fetch metadata (projects, team members, current user)
-    let (projects_res, members_res, current_user_res) = tokio::join!(
-        linear::fetch_projects(config),
-        linear::fetch_team_members(config),
-        linear::fetch_current_user_id(config)
-    );
-
-    let metadata = RefreshMetadata {
-        projects: projects_res.ok(),
-        team_members: members_res.ok(),
-        current_user_id: current_user_res.ok(),
+    // Step 0: Fetch metadata (projects, team members, current user, teams, inbox,
+    // review requests)
+    let metadata = match known_issues {
+        Some(_) => RefreshMetadata {
+            review_requests: fetch_review_requests(config)
+                .await
+                .inspect_err(|e| tracing::debug!("Failed to fetch review requests: {}", e))
+                .ok(),
+            ..RefreshMetadata::default()
+        },
+        None => fetch_metadata(config).await,
     };
 
     crate::util::send_or_log(&tx, RefreshResult::Metadata(metadata), "metadata update").await;
@@ -154,14 +198,36 @@ pub async fn fetch_workstreams_incremental(
         tracing::warn!("Failed to send progress update: {}", e);
     }
 
-    let issues = linear::fetch_assigned_issues(config).await?;
+    let (issues, authored_prs) = match known_issues {
+        Some(issues) => (issues, github::fetch_authored_prs(config).await),
+        None => {
+            let (issues, authored_prs) = tokio::join!(
+                linear::fetch_assigned_issues(config),
+                github::fetch_authored_prs(config)
+            );
+            let issues = issues?;
+            crate::util::send_or_log(
+                &tx,
+                RefreshResult::IssueLinks(issue_links(&issues)),
+                "issue links",
+            )
+            .await;
+            (issues, authored_prs)
+        }
+    };
     let total = issues.len();
+    let mut discovered = discover_prs(config, authored_prs, &issues);
+
+    // Claim discovered PRs before fanning out, so when several issues match
+    // the same PR the first one always gets it
+    let claims = discovered.claim_for_issues(&issues);
+    let discovered = Arc::new(tokio::sync::Mutex::new(discovered));
 
     if let Err(e) = tx
         .send(RefreshResult::Progress(RefreshProgress {
             total_issues: total,
             completed: 0,
-            current_stage: format!("Found {} issues, loading agent sessions...", total),
+            current_stage: format!("Found {} issues, loading agent sessions and PRs...", total),
         }))
         .await
     {
@@ -169,8 +235,15 @@ pub async fn fetch_workstreams_incremental(
     }
 
     // Step 2: Pre-load agent session cache ONCE (1 file read + 1 HTTP call total)
-    // This replaces 100+ individual file reads and HTTP calls
-    let agent_cache = Arc::new(agent_cache::AgentSessionCache::load().await);
+    // This replaces 100+ individual file reads and HTTP calls. Attached PRs are
+    // fetched alongside, 50 per GraphQL request, so the per-issue step below
+    // reads them from cache.
+    let pr_urls = attached_pr_urls(&issues);
+    let (agent_cache, ()) = tokio::join!(
+        agent_cache::AgentSessionCache::load(&config.linear.identifier_pattern),
+        github::prefetch_prs(config, &pr_urls)
+    );
+    let agent_cache = Arc::new(agent_cache);
 
     if let Err(e) = tx
         .send(RefreshResult::Progress(RefreshProgress {
@@ -188,12 +261,13 @@ pub async fn fetch_workstreams_incremental(
     let matched_session_ids = Arc::new(tokio::sync::Mutex::new(std::collections::HashSet::new()));
 
     let config = config.clone();
-    stream::iter(issues.into_iter().enumerate())
-        .map(|(i, issue)| {
+    stream::iter(issues.into_iter().zip(claims).enumerate())
+        .map(|(i, (issue, claimed))| {
             let config = config.clone();
             let tx = tx.clone();
             let agent_cache = Arc::clone(&agent_cache);
             let matched_ids = Arc::clone(&matched_session_ids);
+            let discovered = Arc::clone(&discovered);
             async move {
                 // Send progress
                 if let Err(e) = tx
@@ -212,47 +286,44 @@ pub async fn fetch_workstreams_incremental(
                     tracing::debug!("Progress channel closed: {}", e);
                 }
 
-                // Fetch GitHub PR if linked
-                let pr = if let Some(pr_url) = &issue.linked_pr_url {
-                    match github::fetch_pr_from_url(&config, pr_url).await {
-                        Ok(pr) => Some(pr),
-                        Err(e) => {
-                            tracing::debug!("Failed to fetch PR from {}: {}", pr_url, e);
-                            None
-                        }
+                // Fetch the linked GitHub PRs, else use the one claimed from search
+                let prs = if issue.linked_pr_urls.is_empty() {
+                    let pr = match claimed {
+                        Some(pr) => Some(pr),
+                        None => find_issue_pr_by_branch(&config, &agent_cache, &issue).await,
+                    };
+                    if let Some(pr) = &pr {
+                        discovered.lock().await.claim_url(&pr.url);
                     }
+                    pr.into_iter().collect()
                 } else {
-                    None
+                    fetch_attached_prs(&config, &issue.linked_pr_urls).await
                 };
 
-                // Fetch Vercel deployment if PR exists
-                let deployment = if let Some(ref pr) = pr {
-                    match vercel::fetch_deployment_for_branch(&config, &pr.repo, &pr.branch).await {
-                        Ok(deploy) => deploy,
-                        Err(e) => {
-                            tracing::debug!(
-                                "Failed to fetch Vercel deployment for {}/{}: {}",
-                                pr.repo,
-                                pr.branch,
-                                e
-                            );
-                            None
-                        }
-                    }
-                } else {
-                    None
+                // Fetch each PR's Vercel deployment
+                let linked_prs = fetch_linked_prs(&config, prs).await;
+                let mut ws = Workstream {
+                    linear_issue: issue.issue,
+                    github_pr: None,
+                    vercel_deployment: None,
+                    linked_prs: vec![],
+                    agent_sessions: vec![],
+                    agent_session: None,
+                    stale: false,
                 };
+                ws.set_linked_prs(linked_prs);
 
                 // Find agent session via O(1) cache lookup by git branch
+                let repo = ws.github_pr.as_ref().map(|p| p.repo.as_str());
                 let agent_sessions = agent_cache.find_all_for_branch_or_identifier(
-                    issue.working_directory.as_deref(),
-                    &issue.issue.identifier,
-                    pr.as_ref().map(|p| p.repo.as_str()),
+                    issue.branch_name.as_deref(),
+                    &ws.linear_issue.identifier,
+                    repo,
                 );
                 let agent = agent_cache.find_for_branch_or_identifier(
-                    issue.working_directory.as_deref(),
-                    &issue.issue.identifier,
-                    pr.as_ref().map(|p| p.repo.as_str()),
+                    issue.branch_name.as_deref(),
+                    &ws.linear_issue.identifier,
+                    repo,
                 );
 
                 // Track matched session IDs
@@ -265,14 +336,8 @@ pub async fn fetch_workstreams_incremental(
                     matched_ids.lock().await.insert(session.id.clone());
                 }
 
-                let ws = Workstream {
-                    linear_issue: issue.issue,
-                    github_pr: pr,
-                    vercel_deployment: deployment,
-                    agent_sessions,
-                    agent_session: agent,
-                    stale: false,
-                };
+                ws.agent_sessions = agent_sessions;
+                ws.agent_session = agent;
 
                 crate::util::send_or_log(
                     &tx,
@@ -290,14 +355,27 @@ pub async fn fetch_workstreams_incremental(
     let matched_ids = matched_session_ids.lock().await;
     for session in agent_cache.all_sessions() {
         if !matched_ids.contains(&session.id) {
-            let ws = Workstream {
+            let claimed = match session.git_branch.as_deref() {
+                Some(branch) => discovered.lock().await.claim_for_branch(branch),
+                None => None,
+            };
+            let pr = match claimed {
+                Some(pr) => Some(pr),
+                None => find_pr_by_branch(&config, None, std::slice::from_ref(session)).await,
+            };
+            if let Some(pr) = &pr {
+                discovered.lock().await.claim_url(&pr.url);
+            }
+            let mut ws = Workstream {
                 linear_issue: create_placeholder_issue(session),
                 github_pr: None,
                 vercel_deployment: None,
+                linked_prs: vec![],
                 agent_sessions: vec![session.clone()],
                 agent_session: Some(session.clone()),
                 stale: false,
             };
+            ws.set_linked_prs(fetch_linked_prs(&config, pr.into_iter().collect()).await);
             crate::util::send_or_log(
                 &tx,
                 RefreshResult::Workstream(Box::new(ws)),
@@ -307,15 +385,265 @@ pub async fn fetch_workstreams_incremental(
         }
     }
 
+    // Step 5: Add PRs no issue or session claimed as standalone rows
+    let unclaimed = discovered.lock().await.take_unclaimed();
+    for pr in unclaimed {
+        let mut ws = pr_discovery::standalone_workstream(pr.clone());
+        ws.set_linked_prs(fetch_linked_prs(&config, vec![pr]).await);
+        crate::util::send_or_log(
+            &tx,
+            RefreshResult::Workstream(Box::new(ws)),
+            "standalone PR",
+        )
+        .await;
+    }
+
+    log_not_modified_counts();
     crate::util::send_or_log(&tx, RefreshResult::Complete, "complete signal").await;
     Ok(())
 }
 
+/// Linear metadata for a full refresh (projects, team members, current user,
+/// teams, inbox) plus GitHub review requests
+async fn fetch_metadata(config: &Config) -> RefreshMetadata {
+    let (projects_res, members_res, current_user_res, teams_res, notifications_res, reviews_res) = tokio::join!(
+        linear::fetch_projects(config),
+        linear::fetch_team_members(config),
+        linear::fetch_current_user_ids(config),
+        linear::fetch_teams(config),
+        linear::fetch_notifications(config),
+        fetch_review_requests(config)
+    );
+
+    RefreshMetadata {
+        projects: projects_res.ok(),
+        team_members: members_res.ok(),
+        current_user_ids: current_user_res.ok(),
+        teams: teams_res.ok(),
+        notifications: notifications_res
+            .inspect_err(|e| tracing::debug!("Failed to fetch Linear inbox: {}", e))
+            .ok(),
+        review_requests: reviews_res
+            .inspect_err(|e| tracing::debug!("Failed to fetch review requests: {}", e))
+            .ok(),
+    }
+}
+
+/// Attached PR URLs and branch name of each issue by ID, kept from a Linear
+/// fetch so refreshes that skip Linear can rebuild issues from the rows on
+/// screen
+pub fn issue_links(issues: &[LinkedLinearIssue]) -> HashMap<String, IssueLinks> {
+    issues
+        .iter()
+        .map(|i| {
+            (
+                i.issue.id.clone(),
+                (i.linked_pr_urls.clone(), i.branch_name.clone()),
+            )
+        })
+        .collect()
+}
+
+/// Linear issues currently shown, for a refresh that skips Linear.
+///
+/// Placeholder rows (unlinked sessions, standalone PRs) are left out - the
+/// refresh rebuilds those. PRs already on a row are kept alongside the
+/// attachments and branch name from the last Linear fetch (`links`), so PRs
+/// attached by webhook aren't lost.
+pub fn known_linear_issues(
+    workstreams: &[Workstream],
+    links: &HashMap<String, IssueLinks>,
+) -> Vec<LinkedLinearIssue> {
+    workstreams
+        .iter()
+        .filter(|ws| !ws.linear_issue.identifier.is_empty())
+        .map(|ws| {
+            let (mut linked_pr_urls, branch_name) =
+                links.get(&ws.linear_issue.id).cloned().unwrap_or_default();
+            for linked in &ws.linked_prs {
+                if !linked_pr_urls.contains(&linked.pr.url) {
+                    linked_pr_urls.push(linked.pr.url.clone());
+                }
+            }
+            LinkedLinearIssue {
+                issue: ws.linear_issue.clone(),
+                linked_pr_urls,
+                branch_name,
+            }
+        })
+        .collect()
+}
+
+/// PRs waiting on the user's review, linked to the Linear issue named in their
+/// branch when it can be loaded
+pub async fn fetch_review_requests(config: &Config) -> Result<Vec<ReviewRequest>> {
+    let mut requests = github::fetch_review_requests(config).await?;
+    let identifiers =
+        pr_discovery::link_review_requests(&mut requests, &config.linear.identifier_pattern);
+    if !identifiers.is_empty() {
+        match linear::fetch_issues_by_identifier(config, &identifiers).await {
+            Ok(issues) => pr_discovery::attach_linked_issues(&mut requests, &issues),
+            Err(e) => tracing::debug!("Failed to load issues for review requests: {}", e),
+        }
+    }
+    Ok(requests)
+}
+
+/// Match PRs from the GitHub search against this refresh's issues
+fn discover_prs(
+    config: &Config,
+    authored_prs: Result<Vec<GitHubPR>>,
+    issues: &[LinkedLinearIssue],
+) -> DiscoveredPrs {
+    let authored_prs = authored_prs.unwrap_or_else(|e| {
+        tracing::debug!("Failed to search GitHub for authored PRs: {}", e);
+        Vec::new()
+    });
+    DiscoveredPrs::new(
+        authored_prs,
+        issues
+            .iter()
+            .flat_map(|i| i.linked_pr_urls.iter().map(String::as_str)),
+        &config.linear.identifier_pattern,
+    )
+}
+
+/// PR for an issue with no attachment and no search match, looked up by branch
+/// in the repositories of the issue's agent sessions
+async fn find_issue_pr_by_branch(
+    config: &Config,
+    agent_cache: &agent_cache::AgentSessionCache,
+    issue: &LinkedLinearIssue,
+) -> Option<GitHubPR> {
+    let sessions = agent_cache.find_all_for_branch_or_identifier(
+        issue.branch_name.as_deref(),
+        &issue.issue.identifier,
+        None,
+    );
+    find_pr_by_branch(config, issue.branch_name.as_deref(), &sessions).await
+}
+
+/// First PR whose head branch is Linear's branch name or a session's branch,
+/// searched in each session's repository
+async fn find_pr_by_branch(
+    config: &Config,
+    branch_name: Option<&str>,
+    sessions: &[AgentSession],
+) -> Option<GitHubPR> {
+    for session in sessions {
+        let Some(repo) = pr_discovery::session_repo(config, session).await else {
+            continue;
+        };
+        let mut branches: Vec<&str> = branch_name.into_iter().collect();
+        if let Some(branch) = session.git_branch.as_deref() {
+            if !branches.contains(&branch) {
+                branches.push(branch);
+            }
+        }
+        for branch in branches {
+            match github::fetch_pr_for_branch(config, &repo, branch).await {
+                Ok(Some(pr)) => return Some(pr),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::debug!("Failed to find PR for {}:{}: {}", repo, branch, e);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Debug-log how many GitHub/Vercel requests conditional requests have saved
+fn log_not_modified_counts() {
+    let (github_saved, vercel_saved) = enrichment_cache::not_modified_counts();
+    tracing::debug!(
+        "Conditional requests saved {} GitHub and {} Vercel calls so far (304 Not Modified)",
+        github_saved,
+        vercel_saved
+    );
+}
+
+/// PR URLs attached to any of the issues
+fn attached_pr_urls(issues: &[LinkedLinearIssue]) -> Vec<String> {
+    issues
+        .iter()
+        .flat_map(|issue| issue.linked_pr_urls.iter().cloned())
+        .collect()
+}
+
+/// Fetch every PR an issue's attachments point at, skipping failures
+async fn fetch_attached_prs(config: &Config, urls: &[String]) -> Vec<GitHubPR> {
+    futures::future::join_all(urls.iter().map(|url| async move {
+        github::fetch_pr_from_url(config, url)
+            .await
+            .inspect_err(|e| tracing::debug!("Failed to fetch PR from {}: {}", url, e))
+            .ok()
+    }))
+    .await
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Pair each PR with its Vercel deployment
+async fn fetch_linked_prs(config: &Config, prs: Vec<GitHubPR>) -> Vec<LinkedPr> {
+    futures::future::join_all(prs.into_iter().map(|pr| async move {
+        let vercel_deployment = fetch_deployment_for_pr(config, Some(&pr)).await;
+        LinkedPr {
+            pr,
+            vercel_deployment,
+        }
+    }))
+    .await
+}
+
+/// Vercel deployment for a PR's branch (None without a PR or on failure).
+///
+/// When Vercel is configured but there's no Vercel token, or while Vercel is
+/// rate limited or failing, the deployment is derived from what Vercel
+/// reports on the PR's head commit in GitHub instead.
+async fn fetch_deployment_for_pr(
+    config: &Config,
+    pr: Option<&GitHubPR>,
+) -> Option<VercelDeployment> {
+    let pr = pr?;
+    let vercel_backoff = enrichment_cache::vercel_should_backoff(config).await;
+    if config.tokens.vercel.is_some() && !vercel_backoff {
+        match vercel::fetch_deployment_for_branch(config, &pr.repo, &pr.branch).await {
+            Ok(deploy) => return deploy,
+            Err(e) => tracing::debug!(
+                "Failed to fetch Vercel deployment for {}/{}: {}",
+                pr.repo,
+                pr.branch,
+                e
+            ),
+        }
+    }
+    // Don't spend GitHub requests on users who don't deploy on Vercel
+    if !config.vercel.is_configured() && !vercel_backoff {
+        return None;
+    }
+    match vercel::fetch_deployment_from_github_status(config, pr).await {
+        Ok(deploy) => deploy,
+        Err(e) => {
+            tracing::debug!(
+                "Failed to derive deployment for {}#{} from GitHub: {}",
+                pr.repo,
+                pr.number,
+                e
+            );
+            None
+        }
+    }
+}
+
 /// Intermediate struct for Linear issues with extra linking info
 pub struct LinkedLinearIssue {
     pub issue: crate::data::LinearIssue,
-    pub linked_pr_url: Option<String>,
-    pub working_directory: Option<String>,
+    /// GitHub PRs attached to the issue
+    pub linked_pr_urls: Vec<String>,
+    /// Linear's suggested git branch name for the issue
+    pub branch_name: Option<String>,
 }
 
 /// Create a placeholder issue for unlinked agent sessions
@@ -379,8 +707,12 @@ fn create_placeholder_issue(session: &AgentSession) -> LinearIssue {
         assignee_id: None,
         assignee_name: None,
         estimate: None,
+        due_date: None,
+        sla_breaches_at: None,
         attachments: vec![],
         parent: None,
         children: vec![],
+        relations: vec![],
+        workspace: None,
     }
 }
@@ -219,6 +219,7 @@ fn load_agent_sessions(sessions_dir: &Path, profile: Option<&str>) -> Result<Vec
             started_at: updated_at,
             last_activity: updated_at,
             window_id: None,
+            owner: None,
             activity,
         });
     }
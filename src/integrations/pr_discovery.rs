@@ -0,0 +1,263 @@
+//! Open PRs found by searching GitHub rather than through Linear attachments.
+//!
+//! Each discovered PR is claimed by at most one row: an issue whose Linear
+//! branch name or identifier matches, else an unlinked agent session on the
+//! same branch. Whatever is left over becomes a standalone PR workstream.
+//!
+//! PRs waiting on the user's review are found the same way; they're linked to
+//! the Linear issue named in their branch but listed in their own section.
+//!
+//! Issues and sessions that still have no PR fall back to a lookup by head
+//! branch in the repository each session's checkout points at.
+
+use crate::config::Config;
+use crate::data::{
+    AgentSession, GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority, LinearStatus, LinkedPr,
+    ReviewRequest, Workstream,
+};
+use crate::integrations::enrichment_cache::normalize_github_pr_url;
+use crate::integrations::{agent_cache, LinkedLinearIssue};
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Repository of each session working directory (remotes rarely change, so
+/// `git` runs once per directory)
+static REPO_BY_DIR: Lazy<Mutex<HashMap<String, Option<String>>>> = Lazy::new(Default::default);
+
+/// Prefix of placeholder issue IDs for standalone PR rows
+pub const PR_ROW_PREFIX: &str = "pr-";
+
+pub struct DiscoveredPrs {
+    prs: Vec<GitHubPR>,
+    claimed: Vec<bool>,
+    identifier_re: Regex,
+}
+
+impl DiscoveredPrs {
+    /// `linked_urls` are PRs Linear attachments already point at; those rows
+    /// fetch them directly, so they're never offered again
+    pub fn new<'a>(
+        prs: Vec<GitHubPR>,
+        linked_urls: impl IntoIterator<Item = &'a str>,
+        identifier_pattern: &str,
+    ) -> Self {
+        let linked: std::collections::HashSet<String> = linked_urls
+            .into_iter()
+            .map(normalize_github_pr_url)
+            .collect();
+        let prs: Vec<GitHubPR> = prs
+            .into_iter()
+            .filter(|pr| !linked.contains(&normalize_github_pr_url(&pr.url)))
+            .collect();
+        Self {
+            claimed: vec![false; prs.len()],
+            prs,
+            identifier_re: agent_cache::identifier_regex(identifier_pattern),
+        }
+    }
+
+    /// Claim the PR for an issue: Linear's branch name first, then the
+    /// identifier in the PR's branch, then in its title
+    pub fn claim_for_issue(
+        &mut self,
+        identifier: &str,
+        branch_name: Option<&str>,
+    ) -> Option<GitHubPR> {
+        if let Some(branch) = branch_name {
+            if let Some(pr) = self.claim_where(|pr| pr.branch.eq_ignore_ascii_case(branch)) {
+                return Some(pr);
+            }
+        }
+        if identifier.is_empty() {
+            return None;
+        }
+        let identifier = identifier.to_uppercase();
+        let re = self.identifier_re.clone();
+        self.claim_where(|pr| {
+            agent_cache::extract_issue_id(&re, &pr.branch).as_deref() == Some(identifier.as_str())
+        })
+        .or_else(|| {
+            self.claim_where(|pr| {
+                agent_cache::extract_issue_id(&re, &pr.title).as_deref()
+                    == Some(identifier.as_str())
+            })
+        })
+    }
+
+    /// Claim a PR for each issue without attachments, in issue order, so the
+    /// outcome doesn't depend on which issue's enrichment finishes first
+    pub fn claim_for_issues(&mut self, issues: &[LinkedLinearIssue]) -> Vec<Option<GitHubPR>> {
+        issues
+            .iter()
+            .map(|issue| {
+                if issue.linked_pr_urls.is_empty() {
+                    self.claim_for_issue(&issue.issue.identifier, issue.branch_name.as_deref())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Claim the PR for an unlinked session's branch
+    pub fn claim_for_branch(&mut self, branch: &str) -> Option<GitHubPR> {
+        self.claim_where(|pr| pr.branch == branch)
+    }
+
+    /// Claim a PR a row found some other way, so it isn't listed twice
+    pub fn claim_url(&mut self, url: &str) {
+        let url = normalize_github_pr_url(url);
+        let _ = self.claim_where(|pr| normalize_github_pr_url(&pr.url) == url);
+    }
+
+    /// PRs no row claimed (become standalone PR workstreams)
+    pub fn take_unclaimed(&mut self) -> Vec<GitHubPR> {
+        let mut unclaimed = Vec::new();
+        for (pr, claimed) in self.prs.iter().zip(self.claimed.iter_mut()) {
+            if !*claimed {
+                *claimed = true;
+                unclaimed.push(pr.clone());
+            }
+        }
+        unclaimed
+    }
+
+    fn claim_where(&mut self, matches: impl Fn(&GitHubPR) -> bool) -> Option<GitHubPR> {
+        let idx = self
+            .prs
+            .iter()
+            .zip(&self.claimed)
+            .position(|(pr, claimed)| !claimed && matches(pr))?;
+        self.claimed[idx] = true;
+        Some(self.prs[idx].clone())
+    }
+}
+
+/// "owner/name" of a remote URL (SSH or HTTPS) on the GitHub `host`
+pub fn parse_github_remote(url: &str, host: &str) -> Option<String> {
+    let (_, path) = url.trim().split_once(host)?;
+    let path = path
+        .strip_prefix(':')
+        .or_else(|| path.strip_prefix('/'))?
+        .trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, name) = path.split_once('/')?;
+    if owner.is_empty() || name.is_empty() || name.contains('/') {
+        return None;
+    }
+    Some(format!("{}/{}", owner, name))
+}
+
+/// GitHub repository a session works in, from its checkout's `origin` remote
+/// (only remotes on the configured GitHub host count)
+pub async fn session_repo(config: &Config, session: &AgentSession) -> Option<String> {
+    let dir = session.working_directory.as_deref()?;
+    if let Some(repo) = REPO_BY_DIR.lock().ok()?.get(dir) {
+        return repo.clone();
+    }
+
+    let repo = tokio::process::Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .current_dir(dir)
+        .output()
+        .await
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| {
+            parse_github_remote(
+                &String::from_utf8_lossy(&o.stdout),
+                &config.github.web_host(),
+            )
+        });
+    if let Ok(mut cache) = REPO_BY_DIR.lock() {
+        cache.insert(dir.to_string(), repo.clone());
+    }
+    repo
+}
+
+/// Placeholder issue for a PR no Linear issue points at
+pub fn create_pr_placeholder_issue(pr: &GitHubPR) -> LinearIssue {
+    let status = match pr.status {
+        GitHubPRStatus::Draft | GitHubPRStatus::Open => LinearStatus::InProgress,
+        GitHubPRStatus::Merged => LinearStatus::Done,
+        GitHubPRStatus::Closed => LinearStatus::Canceled,
+        _ => LinearStatus::InReview,
+    };
+    LinearIssue {
+        id: format!("{}{}#{}", PR_ROW_PREFIX, pr.repo, pr.number),
+        identifier: String::new(), // Empty = not in Linear
+        title: pr.title.clone(),
+        description: Some(format!("{} ({})", pr.repo, pr.branch)),
+        status,
+        priority: LinearPriority::NoPriority,
+        url: String::new(),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        cycle: None,
+        labels: vec![],
+        project: None,
+        team: None,
+        assignee_id: None,
+        assignee_name: None,
+        estimate: None,
+        due_date: None,
+        sla_breaches_at: None,
+        attachments: vec![],
+        parent: None,
+        children: vec![],
+        relations: vec![],
+        workspace: None,
+    }
+}
+
+/// Standalone workstream for an unclaimed PR
+pub fn standalone_workstream(pr: GitHubPR) -> Workstream {
+    let mut ws = Workstream {
+        linear_issue: create_pr_placeholder_issue(&pr),
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    };
+    ws.set_linked_prs(vec![LinkedPr {
+        pr,
+        vercel_deployment: None,
+    }]);
+    ws
+}
+
+/// Set each review request's Linear identifier from its branch; returns the
+/// distinct identifiers to load
+pub fn link_review_requests(
+    requests: &mut [ReviewRequest],
+    identifier_pattern: &str,
+) -> Vec<String> {
+    let re = agent_cache::identifier_regex(identifier_pattern);
+    let mut identifiers: Vec<String> = Vec::new();
+    for request in requests {
+        request.linked_identifier = agent_cache::extract_issue_id(&re, &request.pr.branch);
+        if let Some(identifier) = &request.linked_identifier {
+            if !identifiers.contains(identifier) {
+                identifiers.push(identifier.clone());
+            }
+        }
+    }
+    identifiers
+}
+
+/// Attach loaded Linear issues to the review requests naming them
+pub fn attach_linked_issues(requests: &mut [ReviewRequest], issues: &[LinearIssue]) {
+    for request in requests {
+        request.linked_issue = request.linked_identifier.as_ref().and_then(|identifier| {
+            issues
+                .iter()
+                .find(|issue| issue.identifier.eq_ignore_ascii_case(identifier))
+                .cloned()
+        });
+    }
+}
@@ -0,0 +1,127 @@
+//! Manual links between agent sessions and Linear issues.
+//!
+//! Sessions are normally matched to issues by branch name. When an issue is
+//! created for an unlinked session (quick-capture), the link is recorded here
+//! so that session keeps showing under the new issue across refreshes:
+//! ~/.local/share/panopticon/session_links.json
+//!
+//! Links are by session ID only. Unlinked sessions usually run on shared
+//! branches like `main`, so later sessions on the same branch stay unlinked.
+//! Links to sessions that no longer exist are dropped when the file is loaded.
+
+use crate::data::AgentSession;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A session explicitly linked to an issue identifier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionLink {
+    pub session_id: String,
+    /// Linear issue identifier (e.g., "DRE-412")
+    pub identifier: String,
+}
+
+impl SessionLink {
+    pub fn for_session(session: &AgentSession, identifier: &str) -> Self {
+        Self {
+            session_id: session.id.clone(),
+            identifier: identifier.to_string(),
+        }
+    }
+}
+
+/// All recorded session links
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionLinks {
+    pub links: Vec<SessionLink>,
+}
+
+impl SessionLinks {
+    /// Issue identifier a session was linked to, if any (latest link wins)
+    pub fn identifier_for(&self, session: &AgentSession) -> Option<&str> {
+        self.links
+            .iter()
+            .rev()
+            .find(|link| link.session_id == session.id)
+            .map(|link| link.identifier.as_str())
+    }
+
+    /// Record a link, replacing any previous link for the same session
+    pub fn add(&mut self, link: SessionLink) {
+        self.links.retain(|l| l.session_id != link.session_id);
+        self.links.push(link);
+    }
+
+    /// Drop links to sessions not in `sessions`. Returns whether any were dropped.
+    pub fn retain_sessions(&mut self, sessions: &[AgentSession]) -> bool {
+        let before = self.links.len();
+        self.links
+            .retain(|link| sessions.iter().any(|s| s.id == link.session_id));
+        self.links.len() != before
+    }
+}
+
+/// Path to the links file
+pub fn links_file_path() -> Result<PathBuf> {
+    let data_dir = directories::ProjectDirs::from("com", "panopticon", "panopticon")
+        .ok_or_else(|| anyhow::anyhow!("Could not determine data directory"))?
+        .data_dir()
+        .to_path_buf();
+
+    Ok(data_dir.join("session_links.json"))
+}
+
+/// Load links from disk (missing file = no links)
+pub fn load() -> Result<SessionLinks> {
+    load_from_path(&links_file_path()?)
+}
+
+/// Load links from a specific path (for testing)
+pub fn load_from_path(path: &Path) -> Result<SessionLinks> {
+    if !path.exists() {
+        return Ok(SessionLinks::default());
+    }
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read session links from {}", path.display()))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse session links from {}", path.display()))
+}
+
+/// Load links, dropping (and saving without) links to sessions that no
+/// longer exist
+pub fn load_pruned(sessions: &[AgentSession]) -> Result<SessionLinks> {
+    load_pruned_from_path(&links_file_path()?, sessions)
+}
+
+/// Load and prune links at a specific path (for testing)
+pub fn load_pruned_from_path(path: &Path, sessions: &[AgentSession]) -> Result<SessionLinks> {
+    let mut links = load_from_path(path)?;
+    if links.retain_sessions(sessions) {
+        save_to_path(path, &links)?;
+    }
+    Ok(links)
+}
+
+/// Record a link for a session and persist it
+pub fn link_session(session: &AgentSession, identifier: &str) -> Result<()> {
+    let path = links_file_path()?;
+    let mut links = load_from_path(&path)?;
+    links.add(SessionLink::for_session(session, identifier));
+    save_to_path(&path, &links)
+}
+
+/// Save links to a specific path (for testing)
+pub fn save_to_path(path: &Path, links: &SessionLinks) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let content =
+        serde_json::to_string_pretty(links).context("Failed to serialize session links")?;
+
+    std::fs::write(path, content)
+        .with_context(|| format!("Failed to write session links to {}", path.display()))
+}
@@ -0,0 +1,427 @@
+//! Team view: sharing agent session snapshots between dashboards.
+//!
+//! Every member's Panopticon periodically publishes a sanitized snapshot of
+//! its local agent sessions (agent, status, issue, branch, current tool - no
+//! paths, prompts or output) either as `<member>.json` in a shared directory
+//! or by POSTing it to a lead's dashboard. Dashboards merge the snapshots they
+//! receive into their workstreams as sessions tagged with an owner.
+//!
+//! HTTP snapshots are signed with the shared `[team] secret` in the
+//! `X-Panopticon-Signature` header (hex HMAC-SHA256 of the body, the same
+//! scheme Linear uses for webhooks). The secret is required to publish or
+//! listen over HTTP, and unsigned snapshots are rejected.
+
+use crate::data::{
+    AgentActivity, AgentSession, AgentStatus, AgentType, LinearIssue, LinearPriority, LinearStatus,
+    SessionOwner, Workstream,
+};
+use crate::integrations::linear_webhook::{read_request, verify_signature, write_status};
+use crate::integrations::HTTP_CLIENT;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashSet;
+use std::path::Path;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+
+/// Header carrying the hex-encoded HMAC-SHA256 of a snapshot body
+pub const SIGNATURE_HEADER: &str = "x-panopticon-signature";
+
+/// Issue ID prefix of placeholder rows for teammate sessions on issues that
+/// aren't in this dashboard's list
+pub const TEAM_ROW_PREFIX: &str = "team:";
+
+// =============================================================================
+// Snapshots
+// =============================================================================
+
+/// One agent session as shared with teammates (sanitized)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedSession {
+    pub id: String,
+    pub agent_type: AgentType,
+    pub status: AgentStatus,
+    /// Linked Linear issue (e.g., "DRE-412"), if any
+    pub issue_identifier: Option<String>,
+    pub issue_title: Option<String>,
+    pub issue_url: Option<String>,
+    pub branch: Option<String>,
+    /// Tool the agent is running right now (None = thinking)
+    pub current_tool: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub last_activity: DateTime<Utc>,
+}
+
+impl SharedSession {
+    /// Sanitize a local session linked to `ws`
+    pub fn from_session(session: &AgentSession, ws: &Workstream) -> Self {
+        let issue = &ws.linear_issue;
+        let linked = !issue.identifier.is_empty();
+        Self {
+            id: session.id.clone(),
+            agent_type: session.agent_type,
+            status: session.status,
+            issue_identifier: linked.then(|| issue.identifier.clone()),
+            issue_title: linked.then(|| issue.title.clone()),
+            issue_url: (linked && !issue.url.is_empty()).then(|| issue.url.clone()),
+            branch: session.git_branch.clone(),
+            current_tool: session.activity.current_tool.clone(),
+            started_at: session.started_at,
+            last_activity: session.last_activity,
+        }
+    }
+
+    /// Session row for a teammate's dashboard (ID namespaced by owner)
+    pub fn to_session(&self, owner: &SessionOwner) -> AgentSession {
+        AgentSession {
+            id: format!("{}:{}", owner.name, self.id),
+            agent_type: self.agent_type,
+            status: self.status,
+            working_directory: None,
+            git_branch: self.branch.clone(),
+            last_output: None,
+            started_at: self.started_at,
+            last_activity: self.last_activity,
+            window_id: None,
+            activity: AgentActivity {
+                current_tool: self.current_tool.clone(),
+                ..Default::default()
+            },
+            owner: Some(owner.clone()),
+        }
+    }
+}
+
+/// Everything one member's dashboard publishes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamSnapshot {
+    pub member: String,
+    /// Member's Linear user ID (matches the assignee filter)
+    pub linear_user_id: Option<String>,
+    pub published_at: DateTime<Utc>,
+    pub sessions: Vec<SharedSession>,
+}
+
+impl TeamSnapshot {
+    /// Snapshot of the sessions running on this machine (teammates' sessions
+    /// merged into `workstreams` are never re-published)
+    pub fn from_workstreams(
+        member: &str,
+        linear_user_id: Option<String>,
+        workstreams: &[Workstream],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut seen = HashSet::new();
+        let mut sessions = Vec::new();
+        for ws in workstreams {
+            let ws_sessions: &[AgentSession] = if !ws.agent_sessions.is_empty() {
+                &ws.agent_sessions
+            } else {
+                ws.agent_session.as_slice()
+            };
+            for session in ws_sessions {
+                if session.owner.is_none() && seen.insert(session.id.as_str()) {
+                    sessions.push(SharedSession::from_session(session, ws));
+                }
+            }
+        }
+
+        Self {
+            member: member.to_string(),
+            linear_user_id,
+            published_at: now,
+            sessions,
+        }
+    }
+
+    /// No snapshot for `stale_after_secs` - the member's dashboard is gone
+    pub fn is_stale(&self, now: DateTime<Utc>, stale_after_secs: u64) -> bool {
+        now.signed_duration_since(self.published_at).num_seconds() > stale_after_secs as i64
+    }
+
+    fn owner(&self) -> SessionOwner {
+        SessionOwner {
+            name: self.member.clone(),
+            linear_user_id: self.linear_user_id.clone(),
+        }
+    }
+}
+
+// =============================================================================
+// Merging
+// =============================================================================
+
+/// Remove teammate sessions and placeholder rows added by [`merge`]
+pub fn strip(workstreams: &mut Vec<Workstream>) {
+    workstreams.retain(|ws| !ws.linear_issue.id.starts_with(TEAM_ROW_PREFIX));
+    for ws in workstreams.iter_mut() {
+        if ws.agent_sessions.iter().any(|s| s.owner.is_some()) {
+            ws.agent_sessions.retain(|s| s.owner.is_none());
+        }
+        if ws.agent_session.as_ref().is_some_and(|s| s.owner.is_some()) {
+            ws.agent_session = ws.agent_sessions.first().cloned();
+        }
+    }
+}
+
+/// Attach teammates' sessions to the matching issue rows (or to placeholder
+/// rows for issues this dashboard doesn't list). Idempotent: sessions from a
+/// previous merge are replaced.
+pub fn merge<'a>(
+    workstreams: &mut Vec<Workstream>,
+    snapshots: impl IntoIterator<Item = &'a TeamSnapshot>,
+) {
+    strip(workstreams);
+
+    for snapshot in snapshots {
+        let owner = snapshot.owner();
+        for shared in &snapshot.sessions {
+            let session = shared.to_session(&owner);
+
+            let existing = shared.issue_identifier.as_deref().and_then(|identifier| {
+                workstreams
+                    .iter()
+                    .position(|ws| ws.linear_issue.identifier.eq_ignore_ascii_case(identifier))
+            });
+            let idx = match existing {
+                Some(idx) => idx,
+                None => {
+                    workstreams.push(placeholder_workstream(&owner, shared));
+                    workstreams.len() - 1
+                }
+            };
+
+            let ws = &mut workstreams[idx];
+            if ws.agent_sessions.is_empty() {
+                ws.agent_sessions.extend(ws.agent_session.clone());
+            }
+            ws.agent_sessions.push(session);
+            if ws.agent_session.is_none() {
+                ws.agent_session = ws.agent_sessions.first().cloned();
+            }
+        }
+    }
+}
+
+/// Row for a teammate session on an issue outside this dashboard's list
+fn placeholder_workstream(owner: &SessionOwner, shared: &SharedSession) -> Workstream {
+    let key = shared.issue_identifier.as_deref().unwrap_or(&shared.id);
+    let title = shared
+        .issue_title
+        .clone()
+        .or_else(|| shared.branch.clone())
+        .unwrap_or_else(|| "Unknown session".to_string());
+
+    Workstream {
+        linear_issue: LinearIssue {
+            id: format!("{}{}:{}", TEAM_ROW_PREFIX, owner.name, key),
+            identifier: shared.issue_identifier.clone().unwrap_or_default(),
+            title,
+            description: None,
+            status: LinearStatus::InProgress,
+            priority: LinearPriority::NoPriority,
+            url: shared.issue_url.clone().unwrap_or_default(),
+            created_at: shared.started_at,
+            updated_at: shared.last_activity,
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: owner.linear_user_id.clone(),
+            assignee_name: Some(owner.name.clone()),
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    }
+}
+
+// =============================================================================
+// Shared Directory
+// =============================================================================
+
+/// File a member's snapshot is stored in (`<member>.json`)
+pub fn snapshot_file_name(member: &str) -> String {
+    let safe: String = member
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}.json", safe.trim_start_matches('.'))
+}
+
+/// Write this member's snapshot atomically (temp file + rename) so readers
+/// never see a half-written file
+pub fn write_snapshot(dir: &Path, snapshot: &TeamSnapshot) -> Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let path = dir.join(snapshot_file_name(&snapshot.member));
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(snapshot)?)
+        .with_context(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, &path)
+        .with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(())
+}
+
+/// Read every teammate's snapshot from the directory, skipping `own_member`
+/// and files that don't parse
+pub fn read_snapshots(dir: &Path, own_member: &str) -> Result<Vec<TeamSnapshot>> {
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+
+    let mut snapshots = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let parsed = std::fs::read(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|bytes| Ok(serde_json::from_slice::<TeamSnapshot>(&bytes)?));
+        match parsed {
+            Ok(snapshot) if snapshot.member != own_member => snapshots.push(snapshot),
+            Ok(_) => {}
+            Err(e) => tracing::debug!("Skipping team snapshot {}: {}", path.display(), e),
+        }
+    }
+    Ok(snapshots)
+}
+
+// =============================================================================
+// HTTP
+// =============================================================================
+
+/// Hex-encoded HMAC-SHA256 of `body`
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// POST a snapshot to a lead's dashboard, signed with the shared secret
+pub async fn publish_http(url: &str, snapshot: &TeamSnapshot, secret: Option<&str>) -> Result<()> {
+    let secret = secret
+        .filter(|s| !s.is_empty())
+        .context("team.secret is required to publish snapshots over HTTP")?;
+    let body = serde_json::to_vec(snapshot)?;
+
+    let response = HTTP_CLIENT
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header(SIGNATURE_HEADER, sign(secret, &body))
+        .body(body)
+        .send()
+        .await
+        .with_context(|| format!("Failed to publish team snapshot to {}", url))?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "Team snapshot rejected by {}: {}",
+        url,
+        response.status()
+    );
+    Ok(())
+}
+
+/// Something for the app to apply from the team share tasks
+#[derive(Debug, Clone)]
+pub enum TeamUpdate {
+    Snapshot(TeamSnapshot),
+    Error(String),
+}
+
+/// Bind `addr` and accept teammates' snapshots in the background. Refuses to
+/// start without a secret, since anyone reaching the port could otherwise
+/// inject sessions.
+pub async fn spawn_listener(
+    addr: &str,
+    secret: Option<String>,
+    tx: mpsc::Sender<TeamUpdate>,
+) -> Result<()> {
+    let Some(secret) = secret.filter(|s| !s.is_empty()) else {
+        anyhow::bail!("team.secret is required to accept snapshots on {}", addr);
+    };
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind {}", addr))?;
+    tokio::spawn(serve(listener, secret, tx));
+    Ok(())
+}
+
+/// Accept connections until the app drops its receiver
+pub async fn serve(listener: TcpListener, secret: String, tx: mpsc::Sender<TeamUpdate>) {
+    while !tx.is_closed() {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                tracing::debug!("Team listener accept failed: {}", e);
+                continue;
+            }
+        };
+        let secret = secret.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &secret, &tx).await {
+                tracing::debug!("Team snapshot request failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    secret: &str,
+    tx: &mpsc::Sender<TeamUpdate>,
+) -> Result<()> {
+    let request = match read_request(&mut stream, SIGNATURE_HEADER).await {
+        Ok(request) => request,
+        Err(e) => {
+            write_status(&mut stream, "400 Bad Request").await?;
+            return Err(e);
+        }
+    };
+
+    if request.method != "POST" {
+        return write_status(&mut stream, "405 Method Not Allowed").await;
+    }
+
+    let signed = request
+        .signature
+        .as_deref()
+        .is_some_and(|sig| verify_signature(secret, &request.body, sig));
+    if !signed {
+        return write_status(&mut stream, "401 Unauthorized").await;
+    }
+
+    let snapshot: TeamSnapshot = match serde_json::from_slice(&request.body) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            write_status(&mut stream, "400 Bad Request").await?;
+            return Err(e.into());
+        }
+    };
+
+    write_status(&mut stream, "200 OK").await?;
+    crate::util::send_or_log(tx, TeamUpdate::Snapshot(snapshot), "team snapshot").await;
+    Ok(())
+}
@@ -1,14 +1,16 @@
 use crate::config::Config;
-use crate::data::{VercelDeployment, VercelStatus};
-use crate::integrations::enrichment_cache::{AsyncTtlCache, Cached};
+use crate::data::{
+    BuildLog, BuildLogCursor, DeploymentAction, DeploymentSource, GitHubPR, VercelDeployment,
+    VercelStatus,
+};
+use crate::integrations::enrichment_cache::{self, AsyncTtlCache, Cached};
+use crate::integrations::github;
 use crate::integrations::HTTP_CLIENT;
 use anyhow::Result;
 use chrono::Utc;
 use once_cell::sync::Lazy;
 use std::time::Duration;
 
-const VERCEL_API_URL: &str = "https://api.vercel.com";
-
 static DEPLOYMENT_CACHE: Lazy<AsyncTtlCache<String, Cached<Option<VercelDeployment>>>> =
     Lazy::new(AsyncTtlCache::default);
 
@@ -17,7 +19,7 @@ static DEPLOYMENT_CACHE: Lazy<AsyncTtlCache<String, Cached<Option<VercelDeployme
 /// Uses an in-memory TTL cache + request coalescing to reduce Vercel API usage.
 pub async fn fetch_deployment_for_branch(
     config: &Config,
-    _repo: &str,
+    repo: &str,
     branch: &str,
 ) -> Result<Option<VercelDeployment>> {
     let token = match &config.tokens.vercel {
@@ -25,11 +27,12 @@ pub async fn fetch_deployment_for_branch(
         None => return Ok(None),
     };
 
-    let key = branch.trim().to_string();
+    // Same-named branches in different repositories are different deployments
+    let key = enrichment_cache::vercel_key(repo, branch.trim());
 
     let cached = DEPLOYMENT_CACHE
         .get_or_try_init_with_ttl(key, || async {
-            let outcome = fetch_deployment_for_branch_uncached(token, branch).await;
+            let outcome = fetch_deployment_for_branch_uncached(config, token, repo, branch).await;
             outcome_to_cached(outcome)
         })
         .await;
@@ -76,29 +79,107 @@ fn backoff_from_retry_after(response: &reqwest::Response) -> Option<Duration> {
         .map(Duration::from_secs)
 }
 
+/// Latest deployment of `branch` in the GitHub repository `repo` ("owner/name"),
+/// scoped to the configured team and projects.
+pub fn deployments_url(config: &Config, repo: &str, branch: &str) -> String {
+    branch_deployments_url(config, repo, branch, 1)
+}
+
+/// The last `limit` deployments of `branch` in `repo`, newest first.
+///
+/// Vercel's git metadata stores the repository name and its owner apart, so
+/// `meta-githubOrg` and `meta-githubRepo` carry either side of the slash.
+pub fn branch_deployments_url(config: &Config, repo: &str, branch: &str, limit: usize) -> String {
+    // URL encode the branch name to handle special characters like "/" in "fix/issue-123"
+    let mut url = format!(
+        "{}/v6/deployments?limit={}&meta-githubCommitRef={}",
+        config.vercel.api_url.trim_end_matches('/'),
+        limit,
+        urlencoding::encode(branch)
+    );
+    let (owner, repo_name) = repo.split_once('/').unwrap_or(("", repo));
+    if !owner.is_empty() {
+        url.push_str(&format!("&meta-githubOrg={}", urlencoding::encode(owner)));
+    }
+    if !repo_name.is_empty() {
+        url.push_str(&format!(
+            "&meta-githubRepo={}",
+            urlencoding::encode(repo_name)
+        ));
+    }
+    if let Some(team) = team_param(config) {
+        url.push_str(&format!("&{}", team));
+    }
+    match config.vercel.project_ids.as_slice() {
+        [] => {}
+        [project] => url.push_str(&format!("&projectId={}", urlencoding::encode(project))),
+        projects => {
+            for project in projects {
+                url.push_str(&format!("&projectIds={}", urlencoding::encode(project)));
+            }
+        }
+    }
+    url
+}
+
+/// Query parameter selecting the configured team. Team IDs look like
+/// "team_…"; anything else is the team's slug.
+fn team_param(config: &Config) -> Option<String> {
+    let team = config.vercel.team_id.as_deref().filter(|t| !t.is_empty())?;
+    let param = if team.starts_with("team_") {
+        "teamId"
+    } else {
+        "slug"
+    };
+    Some(format!("{}={}", param, urlencoding::encode(team)))
+}
+
+fn parse_ready_state(state: &str) -> VercelStatus {
+    match state {
+        "QUEUED" | "INITIALIZING" => VercelStatus::Queued,
+        "BUILDING" => VercelStatus::Building,
+        "READY" => VercelStatus::Ready,
+        "ERROR" => VercelStatus::Error,
+        "CANCELED" => VercelStatus::Canceled,
+        _ => VercelStatus::Queued,
+    }
+}
+
 async fn fetch_deployment_for_branch_uncached(
+    config: &Config,
     token: &str,
+    repo: &str,
     branch: &str,
 ) -> FetchOutcome<Option<VercelDeployment>> {
     let client = &*HTTP_CLIENT;
 
-    // Query deployments filtered by branch (meta.githubCommitRef)
-    // URL encode the branch name to handle special characters like "/" in "fix/issue-123"
-    let url = format!(
-        "{}/v6/deployments?limit=1&meta-githubCommitRef={}",
-        VERCEL_API_URL,
-        urlencoding::encode(branch)
-    );
+    let url = deployments_url(config, repo, branch);
 
-    let response = client
+    let conditional = enrichment_cache::get_vercel_conditional(config, &url).await;
+
+    let mut request = client
         .get(&url)
-        .header("Authorization", format!("Bearer {}", token))
+        .header("Authorization", format!("Bearer {}", token));
+    if let Some((validators, _)) = &conditional {
+        request = validators.apply(request);
+    }
+    let response = request
         .send()
         .await
         .map_err(|e| anyhow_to_fetch_error(e.into()))?;
 
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        if let Some((_, deployment)) = conditional {
+            enrichment_cache::record_vercel_not_modified();
+            tracing::debug!("Vercel deployments for {} not modified", branch);
+            return Ok(deployment);
+        }
+    }
+
     if response.status().as_u16() == 429 {
-        let backoff = backoff_from_retry_after(&response).unwrap_or(Duration::from_secs(60));
+        let retry_after = backoff_from_retry_after(&response);
+        enrichment_cache::mark_vercel_rate_limited(config, retry_after.map(|d| d.as_secs())).await;
+        let backoff = retry_after.unwrap_or(Duration::from_secs(60));
         return Err(FetchError {
             msg: format!("Vercel API rate limited: {}", response.status()),
             backoff,
@@ -110,40 +191,272 @@ async fn fetch_deployment_for_branch_uncached(
         return Ok(None);
     }
 
+    let validators = enrichment_cache::Validators::from_headers(response.headers());
     let body: serde_json::Value = response
         .json()
         .await
         .map_err(|e| anyhow_to_fetch_error(e.into()))?;
 
-    let deployment = body["deployments"]
+    let deployment = parse_deployments(&body).into_iter().next();
+
+    if let Some(validators) = validators {
+        enrichment_cache::set_vercel_conditional(config, &url, validators, deployment.clone())
+            .await;
+    }
+    Ok(deployment)
+}
+
+/// Deployments in a list response (`GET /v6/deployments`), newest first
+pub fn parse_deployments(body: &serde_json::Value) -> Vec<VercelDeployment> {
+    body["deployments"]
         .as_array()
-        .and_then(|deps| deps.first())
-        .map(|d| {
-            let state = d["readyState"].as_str().unwrap_or("QUEUED");
-            let status = match state {
-                "QUEUED" => VercelStatus::Queued,
-                "BUILDING" => VercelStatus::Building,
-                "READY" => VercelStatus::Ready,
-                "ERROR" => VercelStatus::Error,
-                "CANCELED" => VercelStatus::Canceled,
-                _ => VercelStatus::Queued,
-            };
+        .map(|deps| deps.iter().map(parse_deployment).collect())
+        .unwrap_or_default()
+}
+
+/// A deployment as returned by the list (`uid`, `state`) or single
+/// deployment (`id`, `readyState`) endpoints
+pub fn parse_deployment(d: &serde_json::Value) -> VercelDeployment {
+    let state = d["readyState"]
+        .as_str()
+        .or_else(|| d["state"].as_str())
+        .unwrap_or("QUEUED");
+    let text = |value: &serde_json::Value| value.as_str().map(str::to_string);
+    VercelDeployment {
+        id: d["uid"]
+            .as_str()
+            .or_else(|| d["id"].as_str())
+            .unwrap_or("")
+            .to_string(),
+        url: d["url"]
+            .as_str()
+            .map(|u| format!("https://{}", u))
+            .unwrap_or_default(),
+        status: parse_ready_state(state),
+        created_at: d["createdAt"]
+            .as_i64()
+            .or_else(|| d["created"].as_i64())
+            .map(|ts| chrono::DateTime::from_timestamp_millis(ts).unwrap_or_else(Utc::now))
+            .unwrap_or_else(Utc::now),
+        name: text(&d["name"]),
+        project_id: text(&d["projectId"]),
+        target: text(&d["target"]),
+        commit_sha: text(&d["meta"]["githubCommitSha"]),
+        commit_message: d["meta"]["githubCommitMessage"]
+            .as_str()
+            .and_then(|message| message.lines().next())
+            .map(str::to_string),
+        source: DeploymentSource::Vercel,
+    }
+}
+
+/// Deployments listed in the issue details
+pub const DEPLOYMENT_HISTORY_LIMIT: usize = 10;
+
+/// Recent deployments of a branch, newest first. Not cached: the history is
+/// only loaded when asked for.
+pub async fn fetch_deployment_history(
+    config: &Config,
+    repo: &str,
+    branch: &str,
+) -> Result<Vec<VercelDeployment>> {
+    let Some(token) = &config.tokens.vercel else {
+        anyhow::bail!("no Vercel token configured");
+    };
+    let url = branch_deployments_url(config, repo, branch, DEPLOYMENT_HISTORY_LIMIT);
+    let body = send_json(HTTP_CLIENT.get(&url), token).await?;
+    Ok(parse_deployments(&body))
+}
+
+/// Run an action on a deployment of `repo`'s `branch`, returning the
+/// deployment it affected (the new one for a redeploy)
+pub async fn run_deployment_action(
+    config: &Config,
+    repo: &str,
+    branch: &str,
+    deployment: &VercelDeployment,
+    action: DeploymentAction,
+) -> Result<VercelDeployment> {
+    let Some(token) = &config.tokens.vercel else {
+        anyhow::bail!("no Vercel token configured");
+    };
+    let base = config.vercel.api_url.trim_end_matches('/');
+    let team = team_param(config);
+    let with_team = |url: String| match &team {
+        Some(team) if url.contains('?') => format!("{}&{}", url, team),
+        Some(team) => format!("{}?{}", url, team),
+        None => url,
+    };
 
+    let updated = match action {
+        DeploymentAction::Redeploy => {
+            let Some(name) = &deployment.name else {
+                anyhow::bail!("the deployment's project is unknown");
+            };
+            let mut body = serde_json::json!({
+                "name": name,
+                "deploymentId": deployment.id,
+            });
+            if deployment.is_production() {
+                body["target"] = "production".into();
+            }
+            let url = with_team(format!("{}/v13/deployments?forceNew=1", base));
+            parse_deployment(&send_json(HTTP_CLIENT.post(&url).json(&body), token).await?)
+        }
+        DeploymentAction::Cancel => {
+            let url = with_team(format!("{}/v12/deployments/{}/cancel", base, deployment.id));
+            parse_deployment(&send_json(HTTP_CLIENT.patch(&url), token).await?)
+        }
+        DeploymentAction::Promote => {
+            let Some(project_id) = &deployment.project_id else {
+                anyhow::bail!("the deployment's project is unknown");
+            };
+            let url = with_team(format!(
+                "{}/v10/projects/{}/promote/{}",
+                base, project_id, deployment.id
+            ));
+            // Answers 201/202 without the deployment
+            send(HTTP_CLIENT.post(&url), token).await?;
             VercelDeployment {
-                id: d["uid"].as_str().unwrap_or("").to_string(),
-                url: d["url"]
-                    .as_str()
-                    .map(|u| format!("https://{}", u))
-                    .unwrap_or_default(),
-                status,
-                created_at: d["createdAt"]
-                    .as_i64()
-                    .map(|ts| chrono::DateTime::from_timestamp_millis(ts).unwrap_or_else(Utc::now))
-                    .unwrap_or_else(Utc::now),
+                target: Some("production".to_string()),
+                ..deployment.clone()
             }
-        });
+        }
+    };
 
-    Ok(deployment)
+    // The branch's latest deployment changed; look it up again next refresh
+    DEPLOYMENT_CACHE
+        .remove(&enrichment_cache::vercel_key(repo, branch.trim()))
+        .await;
+    Ok(updated)
+}
+
+/// Fetch a deployment's current status and build output, only the events
+/// after `after` when given (append the result to the log read so far).
+///
+/// Not cached: the log modal polls this while the build runs.
+pub async fn fetch_build_log(
+    config: &Config,
+    deployment_id: &str,
+    after: Option<&BuildLogCursor>,
+) -> Result<BuildLog> {
+    let Some(token) = &config.tokens.vercel else {
+        anyhow::bail!("no Vercel token configured");
+    };
+    let base = config.vercel.api_url.trim_end_matches('/');
+    let team = team_param(config);
+    let with_team = |url: String, sep: char| match &team {
+        Some(team) => format!("{}{}{}", url, sep, team),
+        None => url,
+    };
+
+    let deployment_url = with_team(format!("{}/v13/deployments/{}", base, deployment_id), '?');
+    let mut events_url = format!(
+        "{}/v3/deployments/{}/events?builds=1&direction=forward&limit=-1",
+        base, deployment_id
+    );
+    if let Some(after) = after {
+        events_url.push_str(&format!("&since={}", after.created));
+    }
+    let events_url = with_team(events_url, '&');
+    let (deployment, events) = tokio::try_join!(
+        get_json(token, &deployment_url),
+        get_json(token, &events_url)
+    )?;
+
+    let status = parse_ready_state(deployment["readyState"].as_str().unwrap_or("QUEUED"));
+    let (lines, cursor) = parse_build_events_after(&events, after);
+    let mut log = BuildLog::new(deployment_id, status, lines);
+    log.cursor = cursor;
+    Ok(log)
+}
+
+/// Text of each build event, in order. Vercel puts it at the top level or
+/// under `payload` depending on the event type.
+pub fn parse_build_events(body: &serde_json::Value) -> Vec<String> {
+    parse_build_events_after(body, None).0
+}
+
+/// Text of the build events after `after`, and the cursor of the last event.
+/// `since=` includes events at the cursor's timestamp, so those up to the
+/// cursor's own event are skipped.
+pub fn parse_build_events_after(
+    body: &serde_json::Value,
+    after: Option<&BuildLogCursor>,
+) -> (Vec<String>, Option<BuildLogCursor>) {
+    let events = body.as_array().map(Vec::as_slice).unwrap_or_default();
+    let id = |event: &serde_json::Value| {
+        event["id"]
+            .as_str()
+            .or_else(|| event["payload"]["id"].as_str())
+            .map(str::to_string)
+    };
+
+    let start = match after {
+        Some(after) => {
+            let seen = after.id.as_ref().and_then(|after_id| {
+                events
+                    .iter()
+                    .position(|event| id(event).as_ref() == Some(after_id))
+            });
+            match seen {
+                Some(idx) => idx + 1,
+                None => events
+                    .iter()
+                    .position(|event| event["created"].as_i64() > Some(after.created))
+                    .unwrap_or(events.len()),
+            }
+        }
+        None => 0,
+    };
+    let events = &events[start..];
+
+    let cursor = events
+        .last()
+        .and_then(|event| {
+            Some(BuildLogCursor {
+                created: event["created"].as_i64()?,
+                id: id(event),
+            })
+        })
+        .or_else(|| after.cloned());
+    let lines = events
+        .iter()
+        .filter(|event| event["type"].as_str() != Some("delimiter"))
+        .filter_map(|event| {
+            event["text"]
+                .as_str()
+                .or_else(|| event["payload"]["text"].as_str())
+        })
+        .map(str::to_string)
+        .collect();
+    (lines, cursor)
+}
+
+async fn get_json(token: &str, url: &str) -> Result<serde_json::Value> {
+    send_json(HTTP_CLIENT.get(url), token).await
+}
+
+async fn send_json(request: reqwest::RequestBuilder, token: &str) -> Result<serde_json::Value> {
+    Ok(send(request, token).await?.json().await?)
+}
+
+/// Send an authenticated request, turning error statuses into errors (with
+/// Vercel's message when it gives one)
+async fn send(request: reqwest::RequestBuilder, token: &str) -> Result<reqwest::Response> {
+    let response = request
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+    let status = response.status();
+    if !status.is_success() {
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        match body["error"]["message"].as_str() {
+            Some(message) => anyhow::bail!("Vercel API error: {} ({})", status, message),
+            None => anyhow::bail!("Vercel API error: {}", status),
+        }
+    }
+    Ok(response)
 }
 
 fn anyhow_to_fetch_error(e: anyhow::Error) -> FetchError {
@@ -153,64 +466,203 @@ fn anyhow_to_fetch_error(e: anyhow::Error) -> FetchError {
     }
 }
 
-/// Alternative: Get deployment status from GitHub commit statuses
-/// This works even without Vercel token if Vercel GitHub integration is set up
-#[allow(dead_code)]
+static STATUS_DEPLOYMENT_CACHE: Lazy<AsyncTtlCache<String, Cached<Option<VercelDeployment>>>> =
+    Lazy::new(AsyncTtlCache::default);
+
+/// Derive a PR's deployment from what Vercel reports to GitHub on its head
+/// commit, for when there's no Vercel token or Vercel is rate limited.
+/// None without a GitHub token.
+///
+/// Prefers the GitHub deployment Vercel creates (its status carries the
+/// preview URL) and falls back to Vercel's commit status (which links to the
+/// deployment's page on vercel.com).
 pub async fn fetch_deployment_from_github_status(
     config: &Config,
-    owner: &str,
+    pr: &GitHubPR,
+) -> Result<Option<VercelDeployment>> {
+    if config.tokens.github.is_empty() {
+        return Ok(None);
+    }
+    let Some(sha) = pr.head_sha.clone() else {
+        return Ok(None);
+    };
+    let Some(endpoint) = github::parse_pr_url(config, &pr.url)
+        .and_then(|url| github::ApiEndpoint::for_host(config, url.host))
+    else {
+        return Ok(None);
+    };
+
+    // A commit's deployments only change while they build
+    let key = format!("{}@{}", pr.repo, sha);
+    let cached = STATUS_DEPLOYMENT_CACHE
+        .get_or_try_init_with_ttl(key, || async {
+            let outcome = fetch_github_deployment(config, &endpoint.rest, &pr.repo, &sha)
+                .await
+                .map_err(anyhow_to_fetch_error);
+            outcome_to_cached(outcome)
+        })
+        .await;
+
+    cached.into_result()
+}
+
+async fn fetch_github_deployment(
+    config: &Config,
+    rest: &str,
     repo: &str,
-    commit_sha: &str,
+    sha: &str,
 ) -> Result<Option<VercelDeployment>> {
-    let client = &*HTTP_CLIENT;
+    let deployments = github_get(
+        config,
+        &format!(
+            "{}/repos/{}/deployments?sha={}&per_page=20",
+            rest, repo, sha
+        ),
+    )
+    .await?;
+    if let Some(deployment) = pick_vercel_deployment(&deployments) {
+        let id = deployment["id"].as_u64().unwrap_or_default();
+        let statuses = github_get(
+            config,
+            &format!(
+                "{}/repos/{}/deployments/{}/statuses?per_page=1",
+                rest, repo, id
+            ),
+        )
+        .await?;
+        return Ok(Some(deployment_from_github(deployment, &statuses[0], sha)));
+    }
 
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/commits/{}/statuses",
-        owner, repo, commit_sha
-    );
+    let statuses = github_get(
+        config,
+        &format!("{}/repos/{}/commits/{}/statuses", rest, repo, sha),
+    )
+    .await?;
+    Ok(deployment_from_commit_statuses(&statuses, sha))
+}
 
-    let response = client
-        .get(&url)
+/// GET a GitHub REST resource, sharing GitHub's rate-limit backoff with the
+/// PR enrichment
+async fn github_get(config: &Config, url: &str) -> Result<serde_json::Value> {
+    if enrichment_cache::github_should_backoff(config).await {
+        anyhow::bail!("GitHub API rate limited (backing off)");
+    }
+    let response = HTTP_CLIENT
+        .get(url)
         .header("Authorization", format!("Bearer {}", config.tokens.github))
         .header("Accept", "application/vnd.github+json")
         .header("User-Agent", "panopticon")
         .header("X-GitHub-Api-Version", "2022-11-28")
         .send()
         .await?;
-
-    if !response.status().is_success() {
-        return Ok(None);
+    let status = response.status();
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.parse::<i64>().ok())
+    };
+    // A 429 is a rate limit even without the quota headers; a 403 only
+    // when they say the quota is spent
+    let rate_limited = match status.as_u16() {
+        429 => true,
+        403 => header("x-ratelimit-remaining") == Some(0),
+        _ => false,
+    };
+    if rate_limited {
+        enrichment_cache::mark_github_rate_limited(config, Some(0), header("x-ratelimit-reset"))
+            .await;
+        anyhow::bail!("GitHub API rate limited: {}", status);
     }
+    if !status.is_success() {
+        anyhow::bail!("GitHub API error: {}", status);
+    }
+    Ok(response.json().await?)
+}
 
-    let statuses: Vec<serde_json::Value> = response.json().await?;
+/// Newest GitHub deployment created by Vercel's GitHub app, from a
+/// `GET /repos/{repo}/deployments` response (newest first)
+pub fn pick_vercel_deployment(deployments: &serde_json::Value) -> Option<&serde_json::Value> {
+    deployments.as_array()?.iter().find(|d| {
+        d["creator"]["login"]
+            .as_str()
+            .is_some_and(|login| login.to_lowercase().starts_with("vercel"))
+    })
+}
 
-    // Find Vercel deployment status
-    let vercel_status = statuses.iter().find(|s| {
+/// A deployment from a Vercel-created GitHub deployment and its latest status
+/// (`Null` when none has been reported yet)
+pub fn deployment_from_github(
+    deployment: &serde_json::Value,
+    status: &serde_json::Value,
+    sha: &str,
+) -> VercelDeployment {
+    let state = status["state"].as_str().unwrap_or("queued");
+    let url = status["environment_url"]
+        .as_str()
+        .filter(|u| !u.is_empty())
+        .or_else(|| status["target_url"].as_str())
+        .or_else(|| status["log_url"].as_str())
+        .unwrap_or("");
+    let production = deployment["production_environment"]
+        .as_bool()
+        .unwrap_or_else(|| {
+            deployment["environment"]
+                .as_str()
+                .is_some_and(|env| env.starts_with("Production"))
+        });
+    VercelDeployment {
+        id: deployment["id"].to_string(),
+        url: url.to_string(),
+        status: github_state_to_status(state),
+        created_at: deployment["created_at"]
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(Utc::now),
+        name: None,
+        project_id: None,
+        target: production.then(|| "production".to_string()),
+        commit_sha: Some(sha.to_string()),
+        commit_message: None,
+        source: DeploymentSource::GitHub,
+    }
+}
+
+/// A deployment from Vercel's commit status, from a
+/// `GET /repos/{repo}/commits/{sha}/statuses` response (newest first)
+pub fn deployment_from_commit_statuses(
+    statuses: &serde_json::Value,
+    sha: &str,
+) -> Option<VercelDeployment> {
+    let status = statuses.as_array()?.iter().find(|s| {
         s["context"]
             .as_str()
-            .map(|c| c.contains("vercel"))
-            .unwrap_or(false)
-    });
-
-    let deployment = vercel_status.map(|s| {
-        let state = s["state"].as_str().unwrap_or("pending");
-        let status = match state {
-            "pending" => VercelStatus::Building,
-            "success" => VercelStatus::Ready,
-            "failure" | "error" => VercelStatus::Error,
-            _ => VercelStatus::Queued,
-        };
-
-        VercelDeployment {
-            id: s["id"].to_string(),
-            url: s["target_url"].as_str().unwrap_or("").to_string(),
-            status,
-            created_at: s["created_at"]
-                .as_str()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or_else(Utc::now),
-        }
-    });
+            .is_some_and(|c| c.to_lowercase().contains("vercel"))
+    })?;
+    Some(VercelDeployment {
+        id: status["id"].to_string(),
+        url: status["target_url"].as_str().unwrap_or("").to_string(),
+        status: github_state_to_status(status["state"].as_str().unwrap_or("pending")),
+        created_at: status["created_at"]
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(Utc::now),
+        name: None,
+        project_id: None,
+        target: None,
+        commit_sha: Some(sha.to_string()),
+        commit_message: None,
+        source: DeploymentSource::GitHub,
+    })
+}
 
-    Ok(deployment)
+/// Deployment and commit status states, as GitHub reports them
+fn github_state_to_status(state: &str) -> VercelStatus {
+    match state {
+        "success" | "inactive" => VercelStatus::Ready,
+        "failure" | "error" => VercelStatus::Error,
+        "in_progress" | "pending" => VercelStatus::Building,
+        _ => VercelStatus::Queued,
+    }
 }
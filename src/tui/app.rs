@@ -1,21 +1,27 @@
 use crate::agents::UnifiedAgentWatcher;
 use crate::config::Config;
 use crate::data::{
-    AgentSession, AgentType, AppState, LinearChildRef, LinearCycle, LinearPriority, LinearStatus,
-    SectionType, SortMode, VisualItem, Workstream,
+    AgentSession, AgentType, AppState, BlockedMode, BuildLog, BuildLogCursor, CiCheck,
+    CycleProgress, DeploymentAction, DeploymentSource, GitHubPR, GroupBy, JobLog, LinearChildRef,
+    LinearCycle, LinearIssue, LinearNotification, LinearPriority, LinearRelationRef, LinearStatus,
+    MergeMethod, PrAction, ReviewRequest, SectionType, SessionOwnerFilter, SortMode,
+    VercelDeployment, VercelStatus, VisualItem, Workstream,
 };
 use crate::integrations;
+use crate::integrations::agent_cache;
 use crate::integrations::cache;
-use crate::integrations::linear::{ProjectInfo, TeamMemberInfo};
+use crate::integrations::linear::{ProjectInfo, TeamInfo, TeamMemberInfo};
+use crate::integrations::team_share::{self, TeamSnapshot, TeamUpdate};
+use crate::tui::issue_form::IssueForm;
 use crate::tui::search::FuzzySearch;
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::cmp::Reverse;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use unicode_width::UnicodeWidthStr;
 
 /// Timeout for refresh operations (60 seconds)
@@ -54,6 +60,16 @@ fn matches_repo_hint(session: &AgentSession, repo_name: &str) -> bool {
         .is_some_and(|name| name == repo_name)
 }
 
+/// Configured column widths, with the Owner column hidden (width 0) unless
+/// the team view is enabled
+fn owner_column_widths(config: &Config) -> [usize; NUM_COLUMNS] {
+    let mut widths = config.ui.column_widths;
+    if !config.team.enabled {
+        widths[COL_IDX_OWNER] = 0;
+    }
+    widths
+}
+
 fn pick_primary_session(sessions: &[AgentSession]) -> Option<AgentSession> {
     sessions
         .iter()
@@ -88,7 +104,14 @@ pub struct RefreshProgress {
 pub struct RefreshMetadata {
     pub projects: Option<Vec<ProjectInfo>>,
     pub team_members: Option<Vec<TeamMemberInfo>>,
-    pub current_user_id: Option<String>,
+    /// Teams (with labels) issues can be created in
+    pub teams: Option<Vec<TeamInfo>>,
+    /// Viewer ID in each Linear workspace
+    pub current_user_ids: Option<Vec<String>>,
+    /// Linear inbox notifications
+    pub notifications: Option<Vec<LinearNotification>>,
+    /// GitHub PRs waiting on the user's review
+    pub review_requests: Option<Vec<ReviewRequest>>,
 }
 
 /// Result from background refresh task
@@ -99,12 +122,52 @@ pub enum RefreshResult {
     Workstream(Box<Workstream>),
     /// Metadata update (projects, team members, current user)
     Metadata(RefreshMetadata),
+    /// Attached PRs and branch names from the Linear fetch
+    IssueLinks(HashMap<String, integrations::IssueLinks>),
     /// Refresh completed successfully
     Complete,
     /// Error occurred
     Error(String),
+    /// Pushed change from the Linear webhook listener (outside any refresh)
+    Live(integrations::linear_webhook::WebhookUpdate),
+}
+
+/// Background `issueCreate` call started from the create-issue form
+struct PendingIssueCreate {
+    /// Unlinked session the new issue takes over
+    session: Option<AgentSession>,
+    rx: oneshot::Receiver<Result<LinearIssue, String>>,
 }
 
+/// Outcome of background `notificationUpdate` calls for these notification
+/// IDs: the ones Linear didn't mark, with why
+type MarkReadResult = (Vec<String>, Vec<(String, String)>);
+
+/// Outcome of a background PR action on the PR with this URL
+type PrActionResult = (String, PrAction, Result<GitHubPR, String>);
+
+/// Log of the Actions job with this ID, fetched in the background
+type JobLogResult = (u64, Result<JobLog, String>);
+
+/// Deployments of the branch of the PR with this URL
+type DeploymentHistoryResult = (String, Result<Vec<VercelDeployment>, String>);
+
+/// Outcome of an action on a deployment: PR URL, deployment ID, action, and the
+/// deployment it affected
+type DeploymentActionResult = (
+    String,
+    String,
+    DeploymentAction,
+    Result<VercelDeployment, String>,
+);
+
+/// Build log of the Vercel deployment with this ID, fetched in the background
+/// after the given cursor (None = from the start)
+type BuildLogResult = (String, Option<BuildLogCursor>, Result<BuildLog, String>);
+
+/// How often a running build's log is fetched again
+const BUILD_LOG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 /// Column indices for resize mode
 pub const COL_IDX_STATUS: usize = 0;
 pub const COL_IDX_PRIORITY: usize = 1;
@@ -114,16 +177,19 @@ pub const COL_IDX_PR: usize = 4;
 pub const COL_IDX_AGENT: usize = 5;
 pub const COL_IDX_VERCEL: usize = 6;
 pub const COL_IDX_TIME: usize = 7;
-pub const NUM_COLUMNS: usize = 8;
+pub const COL_IDX_DUE: usize = 8;
+pub const COL_IDX_OWNER: usize = 9;
+pub const COL_IDX_CI: usize = 10;
+pub const NUM_COLUMNS: usize = 11;
 
 /// Column names for resize mode display
 pub const COLUMN_NAMES: [&str; NUM_COLUMNS] = [
-    "Status", "Priority", "ID", "Title", "PR", "Agent", "Vercel", "Time",
+    "Status", "Priority", "ID", "Title", "PR", "Agent", "Vercel", "Time", "Due", "Owner", "CI",
 ];
 
 /// Active modal state - only one modal can be active at a time
 /// This enum consolidates the previous 7 boolean modal flags
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum ModalState {
     #[default]
     None,
@@ -137,6 +203,24 @@ pub enum ModalState {
     FilterMenu,
     Description,
     Resize,
+    /// Quick-capture form (state lives in `App::issue_form`)
+    IssueForm,
+    /// Linear inbox
+    Inbox,
+    /// Actions on the details issue's PRs, with the action awaiting
+    /// confirmation and the URL of the PR it was asked for
+    PrActions {
+        confirm: Option<(PrAction, String)>,
+    },
+    /// Log of a failing CI job of the details issue
+    JobLog,
+    /// Build log of the selected issue's Vercel deployment
+    BuildLog,
+    /// Recent deployments of the details issue's PR, with the action awaiting
+    /// confirmation
+    Deployments {
+        confirm: Option<DeploymentAction>,
+    },
 }
 
 impl ModalState {
@@ -174,17 +258,26 @@ pub struct App {
     pub filter_priorities: HashSet<LinearPriority>,
     pub filter_projects: HashSet<String>,
     pub filter_assignees: HashSet<String>, // "me", "unassigned", or user IDs
+    pub filter_workspaces: HashSet<String>, // Linear account names
     pub available_cycles: Vec<LinearCycle>,
     pub available_projects: Vec<ProjectInfo>,
     pub available_team_members: Vec<TeamMemberInfo>,
-    pub current_user_id: Option<String>,
+    pub available_teams: Vec<TeamInfo>,
+    /// Linear account names (empty with a single workspace)
+    pub available_workspaces: Vec<String>,
+    /// "Me" in each Linear workspace (one ID per account)
+    pub current_user_ids: Vec<String>,
     pub show_sub_issues: bool,
     pub show_completed: bool,
     pub show_canceled: bool,
+    /// Only list issues past their due date or SLA
+    pub filter_overdue: bool,
 
     // Modal navigation state
     pub parent_selected: bool,
     pub selected_child_idx: Option<usize>,
+    /// Selected entry in the modal's relations list (after sub-issues)
+    pub selected_relation_idx: Option<usize>,
     pub issue_navigation_stack: Vec<String>,
     pub modal_issue_id: Option<String>,
     pub sub_issues_scroll: usize,
@@ -194,6 +287,65 @@ pub struct App {
     pub modal_search_mode: bool,
     pub modal_search_query: String,
 
+    /// Create-issue form (open while `modal` is `IssueForm`)
+    pub issue_form: Option<IssueForm>,
+    /// In-flight `issueCreate` call
+    pending_issue_create: Option<PendingIssueCreate>,
+
+    /// Linear inbox, newest first
+    pub notifications: Vec<LinearNotification>,
+    /// Selected notification in the inbox modal
+    pub inbox_selected: usize,
+    /// Notifications marked read here that Linear hasn't confirmed yet
+    /// (a refresh racing the mutation must not bring them back as unread)
+    pending_reads: HashSet<String>,
+    /// Pending reads Linear failed to mark, sent again after the next fetch
+    failed_reads: HashSet<String>,
+    inbox_tx: mpsc::Sender<MarkReadResult>,
+    inbox_rx: mpsc::Receiver<MarkReadResult>,
+
+    /// Selected PR in the PR actions popup (index into the linked PRs)
+    pub pr_action_selected: usize,
+    /// Merge method used by merge and auto-merge
+    pub merge_method: MergeMethod,
+    /// URLs of PRs with an action in flight
+    pub pending_pr_actions: HashSet<String>,
+    pr_action_tx: mpsc::Sender<PrActionResult>,
+    pr_action_rx: mpsc::Receiver<PrActionResult>,
+
+    /// Failing job shown in the job log modal (index into `failed_actions_jobs`)
+    pub job_log_selected: usize,
+    /// The shown job's log; None while it loads
+    pub job_log: Option<Result<JobLog, String>>,
+    /// Lines scrolled up from the end of the log (0 = tail)
+    pub job_log_scroll: usize,
+    job_log_tx: mpsc::Sender<JobLogResult>,
+    job_log_rx: mpsc::Receiver<JobLogResult>,
+
+    /// PR whose branch's deployments are listed
+    pub deployment_pr: Option<GitHubPR>,
+    /// Its recent deployments, newest first; None while they load
+    pub deployment_history: Option<Result<Vec<VercelDeployment>, String>>,
+    pub deployment_selected: usize,
+    /// IDs of deployments with an action in flight
+    pub pending_deployment_actions: HashSet<String>,
+    deployment_history_tx: mpsc::Sender<DeploymentHistoryResult>,
+    deployment_history_rx: mpsc::Receiver<DeploymentHistoryResult>,
+    deployment_action_tx: mpsc::Sender<DeploymentActionResult>,
+    deployment_action_rx: mpsc::Receiver<DeploymentActionResult>,
+
+    /// Issue ID and deployment shown in the build log modal
+    pub build_log_target: Option<(String, VercelDeployment)>,
+    /// The shown deployment's build log; None while it first loads
+    pub build_log: Option<Result<BuildLog, String>>,
+    /// Lines scrolled up from the end of the log (0 = follow the tail)
+    pub build_log_scroll: usize,
+    /// When the build log was last requested
+    build_log_requested_at: Option<Instant>,
+    build_log_in_flight: bool,
+    build_log_tx: mpsc::Sender<BuildLogResult>,
+    build_log_rx: mpsc::Receiver<BuildLogResult>,
+
     /// Channel receiver for background refresh results
     pub refresh_rx: Option<mpsc::Receiver<RefreshResult>>,
     /// Progress tracking for incremental updates
@@ -210,6 +362,26 @@ pub struct App {
     refresh_started_at: Option<Instant>,
     /// Unified file watcher for real-time agent session updates (Claude + OpenClaw)
     unified_watcher: Option<UnifiedAgentWatcher>,
+    /// Compiled `linear.identifier_pattern` for linking watcher sessions to issues
+    identifier_re: regex::Regex,
+    /// Channel receiver for Linear webhook deliveries (lives for the whole session)
+    webhook_rx: Option<mpsc::Receiver<RefreshResult>>,
+    /// When the last webhook delivery arrived (relaxes Linear polling while recent)
+    last_webhook_event: Option<Instant>,
+    /// Webhook deliveries received while a refresh is in flight, replayed over
+    /// its (possibly older) results so they aren't overwritten
+    pending_webhook_updates: Vec<integrations::linear_webhook::WebhookUpdate>,
+    /// Attached PRs and branch name per issue ID from the last Linear fetch,
+    /// for refreshes that skip Linear
+    issue_links: HashMap<String, integrations::IssueLinks>,
+    /// Channel receiver for teammates' snapshots (team view)
+    team_rx: Option<mpsc::Receiver<TeamUpdate>>,
+    /// Sender handed to background publish/scan tasks
+    team_tx: Option<mpsc::Sender<TeamUpdate>>,
+    /// Latest snapshot from each teammate, keyed by member name
+    pub team_snapshots: BTreeMap<String, TeamSnapshot>,
+    /// When this dashboard last published its own snapshot
+    last_team_publish: Option<Instant>,
     /// Cached current time for render frame (avoids repeated syscalls)
     pub frame_now: chrono::DateTime<chrono::Utc>,
 }
@@ -252,9 +424,62 @@ impl App {
         matches!(self.modal, ModalState::Description)
     }
 
+    pub fn show_issue_form(&self) -> bool {
+        matches!(self.modal, ModalState::IssueForm) && self.issue_form.is_some()
+    }
+
     pub fn resize_mode(&self) -> bool {
         matches!(self.modal, ModalState::Resize)
     }
+
+    pub fn show_inbox(&self) -> bool {
+        matches!(self.modal, ModalState::Inbox)
+    }
+
+    pub fn show_pr_actions(&self) -> bool {
+        matches!(self.modal, ModalState::PrActions { .. })
+    }
+
+    pub fn show_job_log(&self) -> bool {
+        matches!(self.modal, ModalState::JobLog)
+    }
+
+    pub fn show_build_log(&self) -> bool {
+        matches!(self.modal, ModalState::BuildLog)
+    }
+
+    pub fn show_deployments(&self) -> bool {
+        matches!(self.modal, ModalState::Deployments { .. })
+    }
+
+    /// Action waiting for a y/n answer in the deployments popup
+    pub fn pending_deployment_confirmation(&self) -> Option<DeploymentAction> {
+        match self.modal {
+            ModalState::Deployments { confirm } => confirm,
+            _ => None,
+        }
+    }
+
+    /// Action waiting for a y/n answer in the PR actions popup
+    pub fn pending_confirmation(&self) -> Option<PrAction> {
+        match &self.modal {
+            ModalState::PrActions {
+                confirm: Some((action, _)),
+            } => Some(*action),
+            _ => None,
+        }
+    }
+
+    /// PR the action awaiting confirmation was asked for (None once it's no
+    /// longer linked to the details issue)
+    pub fn pending_confirmation_pr(&self) -> Option<GitHubPR> {
+        match &self.modal {
+            ModalState::PrActions {
+                confirm: Some((_, url)),
+            } => self.modal_issue_pr(url),
+            _ => None,
+        }
+    }
 }
 
 impl App {
@@ -264,6 +489,19 @@ impl App {
         if let Some(mode) = SortMode::from_config_str(&config.ui.default_sort) {
             state.sort_mode = mode;
         }
+        if let Some(group_by) = GroupBy::from_config_str(&config.ui.default_group_by) {
+            state.group_by = group_by;
+        }
+        if let Some(mode) = BlockedMode::from_config_str(&config.ui.blocked_issues) {
+            state.blocked_mode = mode;
+        }
+
+        let (inbox_tx, inbox_rx) = mpsc::channel(16);
+        let (pr_action_tx, pr_action_rx) = mpsc::channel(16);
+        let (job_log_tx, job_log_rx) = mpsc::channel(4);
+        let (build_log_tx, build_log_rx) = mpsc::channel(4);
+        let (deployment_history_tx, deployment_history_rx) = mpsc::channel(4);
+        let (deployment_action_tx, deployment_action_rx) = mpsc::channel(16);
 
         let mut app = Self {
             config: Arc::clone(&config),
@@ -277,8 +515,8 @@ impl App {
             error_message: None,
             is_loading: false,
             spinner_frame: 0,
-            // Default widths: Status=1, Priority=3, ID=10, Title=26, PR=12, Agent=10, Vercel=3, Time=6
-            column_widths: config.ui.column_widths,
+            // Default widths: Status=1, Priority=3, ID=10, Title=26, PR=12, Agent=10, Vercel=3, Time=6, Due=8, Owner=8
+            column_widths: owner_column_widths(&config),
             resize_column_idx: COL_IDX_TITLE,
             search_all: false,
             search_excerpts: HashMap::new(),
@@ -286,15 +524,29 @@ impl App {
             filter_priorities: HashSet::new(),
             filter_projects: HashSet::new(),
             filter_assignees: HashSet::new(),
+            filter_workspaces: HashSet::new(),
             available_cycles: Vec::new(),
             available_projects: Vec::new(),
             available_team_members: Vec::new(),
-            current_user_id: None,
+            available_teams: Vec::new(),
+            available_workspaces: if config.has_multiple_linear_workspaces() {
+                config
+                    .linear
+                    .accounts
+                    .iter()
+                    .map(|a| a.name.clone())
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            current_user_ids: Vec::new(),
             show_sub_issues: config.ui.show_sub_issues,
             show_completed: config.ui.show_completed,
             show_canceled: config.ui.show_canceled,
+            filter_overdue: false,
             parent_selected: false,
             selected_child_idx: None,
+            selected_relation_idx: None,
             issue_navigation_stack: Vec::new(),
             modal_issue_id: None,
             sub_issues_scroll: 0,
@@ -302,12 +554,54 @@ impl App {
             description_scroll: 0,
             modal_search_mode: false,
             modal_search_query: String::new(),
+            issue_form: None,
+            pending_issue_create: None,
+            notifications: Vec::new(),
+            inbox_selected: 0,
+            pending_reads: HashSet::new(),
+            failed_reads: HashSet::new(),
+            inbox_tx,
+            inbox_rx,
+            pr_action_selected: 0,
+            merge_method: MergeMethod::default(),
+            pending_pr_actions: HashSet::new(),
+            pr_action_tx,
+            pr_action_rx,
+            job_log_selected: 0,
+            job_log: None,
+            job_log_scroll: 0,
+            job_log_tx,
+            job_log_rx,
+            deployment_pr: None,
+            deployment_history: None,
+            deployment_selected: 0,
+            pending_deployment_actions: HashSet::new(),
+            deployment_history_tx,
+            deployment_history_rx,
+            deployment_action_tx,
+            deployment_action_rx,
+            build_log_target: None,
+            build_log: None,
+            build_log_scroll: 0,
+            build_log_requested_at: None,
+            build_log_in_flight: false,
+            build_log_tx,
+            build_log_rx,
             refresh_rx: None,
             refresh_progress: None,
             shadow_workstreams: Vec::new(),
             shadow_metadata: None,
             refresh_started_at: None,
             unified_watcher: UnifiedAgentWatcher::new().ok(),
+            identifier_re: agent_cache::identifier_regex(&config.linear.identifier_pattern),
+            webhook_rx: None,
+            last_webhook_event: None,
+            pending_webhook_updates: Vec::new(),
+            issue_links: HashMap::new(),
+            team_rx: None,
+            team_tx: None,
+            team_snapshots: BTreeMap::new(),
+            last_team_publish: None,
             frame_now: chrono::Utc::now(),
         };
 
@@ -365,6 +659,11 @@ impl App {
             Message::CollapseSection => self.collapse_current_section(),
             Message::ToggleSectionFold => self.toggle_section_fold(),
             Message::OpenLinkMenu => self.open_link_menu(),
+            Message::SendReviewCommentsToAgent => {
+                if let Err(e) = self.send_review_comments_to_agent().await {
+                    self.error_message = Some(format!("Failed to send review comments: {}", e));
+                }
+            }
             Message::TeleportToSession => {
                 if let Err(e) = self.teleport_to_session().await {
                     self.error_message = Some(format!("Failed to focus session: {}", e));
@@ -412,6 +711,147 @@ impl App {
             // Sort modal
             // ─────────────────────────────────────────────────────────────────
             Message::SetSortMode(mode) => self.set_sort_mode(mode),
+            Message::SetGroupBy(group_by) => self.set_group_by(group_by),
+
+            // ─────────────────────────────────────────────────────────────────
+            // Create-issue form
+            // ─────────────────────────────────────────────────────────────────
+            Message::OpenIssueForm => self.open_issue_form(),
+            Message::IssueFormInput(c) => {
+                if let Some(form) = self.issue_form.as_mut() {
+                    form.input_char(c, &self.available_teams);
+                }
+            }
+            Message::IssueFormBackspace => {
+                if let Some(form) = self.issue_form.as_mut() {
+                    form.backspace();
+                }
+            }
+            Message::IssueFormNextField => {
+                if let Some(form) = self.issue_form.as_mut() {
+                    form.focus_next();
+                }
+            }
+            Message::IssueFormPrevField => {
+                if let Some(form) = self.issue_form.as_mut() {
+                    form.focus_prev();
+                }
+            }
+            Message::IssueFormCycle(delta) => {
+                if let Some(form) = self.issue_form.as_mut() {
+                    form.cycle(delta, &self.available_teams, &self.available_projects);
+                }
+            }
+            Message::SubmitIssueForm => self.submit_issue_form(),
+
+            // ─────────────────────────────────────────────────────────────────
+            // Linear inbox
+            // ─────────────────────────────────────────────────────────────────
+            Message::ToggleInbox => self.toggle_inbox(),
+            Message::MoveInboxSelection(delta) => self.move_inbox_selection(delta),
+            Message::OpenNotification => self.open_selected_notification(),
+            Message::MarkNotificationRead => self.mark_selected_notification_read(),
+            Message::MarkAllNotificationsRead => self.mark_all_notifications_read(),
+
+            // ─────────────────────────────────────────────────────────────────
+            // PR actions
+            // ─────────────────────────────────────────────────────────────────
+            Message::OpenPrActions => self.open_pr_actions(),
+            Message::MovePrActionSelection(delta) => self.move_pr_action_selection(delta),
+            Message::CycleMergeMethod => self.merge_method = self.merge_method.next(),
+            Message::RequestPrAction(action) => self.request_pr_action(action),
+            Message::ConfirmPrAction => self.confirm_pr_action(),
+            Message::CancelPrAction => {
+                self.modal = match self.modal {
+                    ModalState::PrActions { confirm: Some(_) } => {
+                        ModalState::PrActions { confirm: None }
+                    }
+                    _ => ModalState::LinkMenu {
+                        show_links_popup: false,
+                    },
+                };
+            }
+
+            // ─────────────────────────────────────────────────────────────────
+            // CI job logs
+            // ─────────────────────────────────────────────────────────────────
+            Message::OpenJobLog => self.open_job_log(0),
+            Message::NextJobLog => {
+                let count = self
+                    .modal_issue()
+                    .map_or(0, |ws| ws.failed_actions_jobs().len());
+                if count > 1 {
+                    self.open_job_log((self.job_log_selected + 1) % count);
+                }
+            }
+            Message::ScrollJobLog(delta) => self.scroll_job_log(delta),
+            Message::SendJobLogToAgent => {
+                if let Err(e) = self.send_job_log_to_agent().await {
+                    self.error_message = Some(format!("Failed to send CI failure: {}", e));
+                }
+            }
+            Message::OpenJobInBrowser => {
+                let url = self.selected_failed_job().and_then(|(_, check)| check.url);
+                if let Some(url) = url {
+                    if let Err(e) = open_url(&url) {
+                        self.error_message = Some(format!("Failed to open job: {}", e));
+                    }
+                }
+            }
+            Message::CloseJobLog => {
+                self.modal = ModalState::LinkMenu {
+                    show_links_popup: false,
+                };
+            }
+
+            // ─────────────────────────────────────────────────────────────────
+            // Vercel deployments
+            // ─────────────────────────────────────────────────────────────────
+            Message::OpenDeployments => self.open_deployments(),
+            Message::MoveDeploymentSelection(delta) => self.move_deployment_selection(delta),
+            Message::RequestDeploymentAction(action) => self.request_deployment_action(action),
+            Message::ConfirmDeploymentAction => self.confirm_deployment_action(),
+            Message::CancelDeploymentAction => {
+                self.modal = match self.modal {
+                    ModalState::Deployments { confirm: Some(_) } => {
+                        ModalState::Deployments { confirm: None }
+                    }
+                    _ => ModalState::LinkMenu {
+                        show_links_popup: false,
+                    },
+                };
+            }
+            Message::OpenSelectedDeployment => {
+                if let Some(deployment) = self.selected_deployment() {
+                    if let Err(e) = open_url(&deployment.url) {
+                        self.error_message = Some(format!("Failed to open deployment: {}", e));
+                    }
+                }
+            }
+
+            // ─────────────────────────────────────────────────────────────────
+            // Vercel build logs
+            // ─────────────────────────────────────────────────────────────────
+            Message::OpenBuildLog => self.open_build_log(),
+            Message::ScrollBuildLog(delta) => self.scroll_build_log(delta),
+            Message::JumpToBuildError => self.jump_to_build_error(),
+            Message::SendBuildLogToAgent => {
+                if let Err(e) = self.send_build_log_to_agent().await {
+                    self.error_message = Some(format!("Failed to send build failure: {}", e));
+                }
+            }
+            Message::OpenDeploymentInBrowser => {
+                if let Some((_, deployment)) = &self.build_log_target {
+                    if let Err(e) = open_url(&deployment.url) {
+                        self.error_message = Some(format!("Failed to open deployment: {}", e));
+                    }
+                }
+            }
+            Message::CloseBuildLog => {
+                self.modal = ModalState::None;
+                self.build_log_target = None;
+                self.build_log = None;
+            }
 
             // ─────────────────────────────────────────────────────────────────
             // Filter modal
@@ -422,9 +862,13 @@ impl App {
             Message::ClearProjectFilters => self.clear_project_filters(),
             Message::ToggleAssigneeFilter(idx) => self.toggle_assignee_filter(idx),
             Message::ClearAssigneeFilters => self.clear_assignee_filters(),
+            Message::ToggleWorkspaceFilter(idx) => self.toggle_workspace_filter(idx),
+            Message::ClearWorkspaceFilters => self.clear_workspace_filters(),
             Message::ToggleSubIssues => self.toggle_sub_issues(),
             Message::ToggleCompletedFilter => self.toggle_completed_filter(),
             Message::ToggleCanceledFilter => self.toggle_canceled_filter(),
+            Message::CycleBlockedMode => self.cycle_blocked_mode(),
+            Message::ToggleOverdueFilter => self.toggle_overdue_filter(),
             Message::ClearAllFilters => self.clear_all_filters(),
             Message::SelectAllFilters => self.select_all_filters(),
 
@@ -441,10 +885,15 @@ impl App {
             // Link menu modal
             // ─────────────────────────────────────────────────────────────────
             Message::OpenLinksPopup => {
-                // Works from both normal mode and issue details - opens links popup directly
-                self.modal = ModalState::LinkMenu {
-                    show_links_popup: true,
-                };
+                // Review rows have no details modal: open the linked Linear issue
+                if self.modal.is_none() && self.selected_review_request().is_some() {
+                    self.open_review_request_issue();
+                } else {
+                    // Works from both normal mode and issue details - opens links popup directly
+                    self.modal = ModalState::LinkMenu {
+                        show_links_popup: true,
+                    };
+                }
             }
             Message::CloseLinksPopup => {
                 self.modal = ModalState::LinkMenu {
@@ -464,6 +913,11 @@ impl App {
                 self.modal = ModalState::None;
                 self.clear_navigation();
             }
+            Message::OpenPrLink(idx) => {
+                self.open_pr_link(idx)?;
+                self.modal = ModalState::None;
+                self.clear_navigation();
+            }
             Message::OpenVercelLink => {
                 self.open_vercel_link().await?;
                 self.modal = ModalState::None;
@@ -476,12 +930,14 @@ impl App {
             Message::NextChildIssue => self.next_child_issue(),
             Message::PrevChildIssue => self.prev_child_issue(),
             Message::NavigateToSelectedChild => {
-                // Navigate to parent or child depending on what's selected
+                // Navigate to parent, child or related issue depending on what's selected
                 // Does nothing if issue not found in workstreams (use 'l' for links)
                 if self.parent_selected {
                     self.navigate_to_parent();
                 } else if self.selected_child_idx.is_some() {
                     self.navigate_to_selected_child();
+                } else if self.selected_relation_idx.is_some() {
+                    self.navigate_to_selected_relation();
                 }
             }
             Message::NavigateToParent => {
@@ -576,21 +1032,25 @@ impl App {
         self.section_counts.clear();
         for &idx in &self.filtered_indices {
             if let Some(ws) = self.state.workstreams.get(idx) {
-                if !ws.agent_sessions.is_empty() || ws.agent_session.is_some() {
-                    let count = if !ws.agent_sessions.is_empty() {
-                        ws.agent_sessions.len()
-                    } else {
-                        1
-                    };
-                    *self
-                        .section_counts
-                        .entry(SectionType::AgentSessions)
-                        .or_insert(0) += count;
-                } else {
-                    *self.section_counts.entry(SectionType::Issues).or_insert(0) += 1;
-                }
+                let count = match self.state.section_for(ws) {
+                    SectionType::AgentSessions if !ws.agent_sessions.is_empty() => (
+                        SectionType::AgentSessions,
+                        ws.agent_sessions
+                            .iter()
+                            .filter(|s| self.state.shows_session(s))
+                            .count(),
+                    ),
+                    section => (section, 1),
+                };
+                *self.section_counts.entry(count.0).or_insert(0) += count.1;
             }
         }
+        if !self.state.review_requests.is_empty() {
+            self.section_counts.insert(
+                SectionType::ReviewRequests,
+                self.state.review_requests.len(),
+            );
+        }
 
         // Ensure selection is valid
         if self.visual_items.is_empty() {
@@ -632,7 +1092,9 @@ impl App {
         for _ in 0..len {
             if matches!(
                 self.visual_items.get(pos),
-                Some(VisualItem::Workstream(_)) | Some(VisualItem::AgentSession { .. })
+                Some(VisualItem::Workstream(_))
+                    | Some(VisualItem::AgentSession { .. })
+                    | Some(VisualItem::ReviewRequest(_))
             ) {
                 self.visual_selected = pos;
                 return;
@@ -648,16 +1110,28 @@ impl App {
     pub async fn refresh(&mut self) -> Result<()> {
         self.is_loading = true;
 
-        let (workstreams_res, projects_res, members_res, current_user_res) = tokio::join!(
+        let (
+            workstreams_res,
+            projects_res,
+            members_res,
+            current_user_res,
+            teams_res,
+            notifications_res,
+            reviews_res,
+        ) = tokio::join!(
             integrations::fetch_workstreams(&self.config),
             integrations::linear::fetch_projects(&self.config),
             integrations::linear::fetch_team_members(&self.config),
-            integrations::linear::fetch_current_user_id(&self.config)
+            integrations::linear::fetch_current_user_ids(&self.config),
+            integrations::linear::fetch_teams(&self.config),
+            integrations::linear::fetch_notifications(&self.config),
+            integrations::fetch_review_requests(&self.config)
         );
 
         match workstreams_res {
-            Ok(workstreams) => {
+            Ok((workstreams, links)) => {
                 self.state.workstreams = workstreams;
+                self.issue_links = links;
                 self.state.last_refresh = Some(Utc::now());
 
                 if let Ok(projects) = projects_res {
@@ -666,8 +1140,17 @@ impl App {
                 if let Ok(members) = members_res {
                     self.available_team_members = members;
                 }
-                if let Ok(user_id) = current_user_res {
-                    self.current_user_id = Some(user_id);
+                if let Ok(user_ids) = current_user_res {
+                    self.current_user_ids = user_ids;
+                }
+                if let Ok(teams) = teams_res {
+                    self.available_teams = teams;
+                }
+                if let Ok(notifications) = notifications_res {
+                    self.set_notifications(notifications);
+                }
+                if let Ok(requests) = reviews_res {
+                    self.state.review_requests = requests;
                 }
 
                 // Extract available cycles from workstreams
@@ -687,6 +1170,10 @@ impl App {
                 ) {
                     tracing::debug!("Failed to save cache: {}", err);
                 }
+                if self.merge_team_sessions() {
+                    self.apply_filters();
+                    self.rebuild_visual_items();
+                }
             }
             Err(e) => {
                 self.error_message = Some(format!("Refresh failed: {}", e));
@@ -704,6 +1191,16 @@ impl App {
     /// only replacing main data on successful completion. This prevents
     /// data loss on transient errors.
     pub fn start_background_refresh(&mut self) {
+        self.start_refresh(false);
+    }
+
+    /// Refresh on a timer or user action. While Linear webhooks are active the
+    /// Linear fetch is skipped and only GitHub/Vercel data is reloaded.
+    fn start_periodic_refresh(&mut self) {
+        self.start_refresh(self.webhook_active());
+    }
+
+    fn start_refresh(&mut self, skip_linear: bool) {
         // Don't start another refresh if one is already in progress
         if self.refresh_rx.is_some() {
             return;
@@ -713,20 +1210,29 @@ impl App {
         self.refresh_started_at = Some(Instant::now()); // Track for timeout detection
         self.shadow_workstreams.clear(); // Clear shadow for new data (keep main data!)
         self.shadow_metadata = None;
+        self.pending_webhook_updates.clear();
         self.refresh_progress = Some(RefreshProgress {
             total_issues: 0,
             completed: 0,
-            current_stage: "Fetching Linear issues...".to_string(),
+            current_stage: if skip_linear {
+                "Refreshing GitHub and Vercel...".to_string()
+            } else {
+                "Fetching Linear issues...".to_string()
+            },
         });
 
         let (tx, rx) = mpsc::channel(100);
         self.refresh_rx = Some(rx);
 
         let config = Arc::clone(&self.config);
+        let known_issues = skip_linear
+            .then(|| integrations::known_linear_issues(&self.state.workstreams, &self.issue_links));
 
         // Spawn background task
         tokio::spawn(async move {
-            if let Err(e) = integrations::fetch_workstreams_incremental(&config, tx.clone()).await {
+            if let Err(e) =
+                integrations::fetch_workstreams_incremental(&config, tx.clone(), known_issues).await
+            {
                 let _ = tx.send(RefreshResult::Error(e.to_string())).await;
             }
         });
@@ -757,7 +1263,7 @@ impl App {
         };
 
         if should_refresh {
-            self.start_background_refresh();
+            self.start_periodic_refresh();
         }
     }
 
@@ -778,6 +1284,7 @@ impl App {
                 self.refresh_progress = None;
                 self.shadow_workstreams.clear();
                 self.shadow_metadata = None;
+                self.pending_webhook_updates.clear();
                 self.error_message = Some("Refresh timed out".to_string());
                 return true;
             }
@@ -805,8 +1312,13 @@ impl App {
                     }
                 }
                 RefreshResult::Workstream(ws) => {
+                    // Webhook deliveries that arrived meanwhile are newer than this fetch
+                    let Some(ws) = self.replay_webhook_updates(*ws) else {
+                        continue;
+                    };
+
                     // Track in shadow for final reconciliation
-                    self.shadow_workstreams.push(*ws.clone());
+                    self.shadow_workstreams.push(ws.clone());
 
                     // ALSO add/update in main state immediately for real-time display
                     // Find existing workstream by issue ID and update, or append if new
@@ -817,9 +1329,9 @@ impl App {
                         .iter_mut()
                         .find(|w| w.linear_issue.id == *issue_id)
                     {
-                        *existing = *ws;
+                        *existing = ws;
                     } else {
-                        self.state.workstreams.push(*ws);
+                        self.state.workstreams.push(ws);
                     }
 
                     // Monotonic progress: always derived from received count
@@ -836,7 +1348,15 @@ impl App {
                 RefreshResult::Metadata(metadata) => {
                     self.shadow_metadata = Some(metadata);
                 }
+                RefreshResult::IssueLinks(links) => {
+                    self.issue_links = links;
+                }
                 RefreshResult::Complete => {
+                    // Keep issues created or removed by webhooks during the refresh
+                    for update in std::mem::take(&mut self.pending_webhook_updates) {
+                        update.apply(&mut self.shadow_workstreams, &self.current_user_ids);
+                    }
+
                     // Success: reconcile main with shadow (remove stale items not in new set)
                     // Since we've been adding incrementally, main may have old items that
                     // were deleted from Linear. Remove any not present in shadow.
@@ -859,8 +1379,17 @@ impl App {
                         if let Some(members) = metadata.team_members {
                             self.available_team_members = members;
                         }
-                        if let Some(user_id) = metadata.current_user_id {
-                            self.current_user_id = Some(user_id);
+                        if let Some(user_ids) = metadata.current_user_ids {
+                            self.current_user_ids = user_ids;
+                        }
+                        if let Some(teams) = metadata.teams {
+                            self.available_teams = teams;
+                        }
+                        if let Some(notifications) = metadata.notifications {
+                            self.set_notifications(notifications);
+                        }
+                        if let Some(requests) = metadata.review_requests {
+                            self.state.review_requests = requests;
                         }
                     }
 
@@ -880,13 +1409,21 @@ impl App {
                     ) {
                         tracing::debug!("Failed to save cache: {}", err);
                     }
+                    if self.merge_team_sessions() {
+                        self.apply_filters();
+                        self.rebuild_visual_items();
+                    }
                     completed = true;
                     should_restore = false;
                 }
+                RefreshResult::Live(update) => {
+                    self.apply_webhook_update(update);
+                }
                 RefreshResult::Error(msg) => {
                     // Error: discard shadow, keep original data
                     self.shadow_workstreams.clear();
                     self.shadow_metadata = None;
+                    self.pending_webhook_updates.clear();
 
                     self.is_loading = false;
                     self.refresh_started_at = None;
@@ -906,6 +1443,7 @@ impl App {
             tracing::warn!("Refresh channel closed unexpectedly");
             self.shadow_workstreams.clear();
             self.shadow_metadata = None;
+            self.pending_webhook_updates.clear();
             self.is_loading = false;
             self.refresh_started_at = None;
             self.refresh_progress = None;
@@ -936,6 +1474,7 @@ impl App {
         let mut max_title_len = 8usize; // "Title" header + padding
         let mut max_pr_len = 8usize; // "PR" header + padding
         let mut max_agent_len = 8usize; // "Agent" header + padding
+        let mut max_owner_len = 7usize; // "Owner" header + padding
         let mut has_sub_issues = false;
 
         for ws in &self.state.workstreams {
@@ -958,108 +1497,1251 @@ impl App {
                 max_pr_len = max_pr_len.max(pr_text.width() + 2); // +2 for icon
             }
 
-            // Agent column
-            if let Some(session) = ws
-                .agent_session
-                .as_ref()
-                .or_else(|| ws.agent_sessions.first())
-            {
-                let label_len = match session.status {
-                    crate::data::AgentStatus::Running => 3,         // RUN
-                    crate::data::AgentStatus::Idle => 4,            // IDLE
-                    crate::data::AgentStatus::WaitingForInput => 4, // WAIT
-                    crate::data::AgentStatus::Done => 4,            // DONE
-                    crate::data::AgentStatus::Error => 3,           // ERR
-                };
-                // "CC <icon><ascii> <label>"
-                max_agent_len = max_agent_len.max(label_len + 5);
+            // Agent column
+            if let Some(session) = ws
+                .agent_session
+                .as_ref()
+                .or_else(|| ws.agent_sessions.first())
+            {
+                let label_len = match session.status {
+                    crate::data::AgentStatus::Running => 3,         // RUN
+                    crate::data::AgentStatus::Idle => 4,            // IDLE
+                    crate::data::AgentStatus::WaitingForInput => 4, // WAIT
+                    crate::data::AgentStatus::Done => 4,            // DONE
+                    crate::data::AgentStatus::Error => 3,           // ERR
+                };
+                // "CC <icon><ascii> <label>"
+                max_agent_len = max_agent_len.max(label_len + 5);
+            }
+
+            // Owner column (teammates' names)
+            for session in &ws.agent_sessions {
+                if let Some(owner) = &session.owner {
+                    max_owner_len = max_owner_len.max(owner.name.width());
+                }
+            }
+        }
+
+        // Apply calculated widths with some padding
+        // Add 2 extra chars for sub-issue prefix "└ " if any sub-issues exist
+        let sub_issue_padding = if has_sub_issues { 2 } else { 0 };
+        self.column_widths[COL_IDX_ID] = max_id_len + 1 + sub_issue_padding;
+        self.column_widths[COL_IDX_TITLE] = max_title_len.min(40); // Cap title at 40
+        self.column_widths[COL_IDX_PR] = max_pr_len.min(15);
+        self.column_widths[COL_IDX_AGENT] = max_agent_len.min(24);
+        if self.config.team.enabled {
+            self.column_widths[COL_IDX_OWNER] = max_owner_len.min(16);
+        }
+
+        // Status, Priority, CI, Vercel, Time, and Due have fixed widths
+        // (already set in defaults, no need to recalculate)
+    }
+
+    /// Recalculate column widths for given terminal width
+    pub fn recalculate_column_widths(&mut self, terminal_width: u16) {
+        // Calculate fixed widths (status, priority, ci, vercel, time, due, owner, separators)
+        let fixed_widths = self.column_widths[COL_IDX_STATUS]
+            + self.column_widths[COL_IDX_PRIORITY]
+            + self.column_widths[COL_IDX_CI]
+            + self.column_widths[COL_IDX_VERCEL]
+            + self.column_widths[COL_IDX_TIME]
+            + self.column_widths[COL_IDX_DUE]
+            + self.column_widths[COL_IDX_OWNER]
+            + 30; // Separators and padding
+
+        let available = (terminal_width as usize).saturating_sub(fixed_widths);
+
+        // Distribute remaining space to ID, Title, PR, Agent
+        // Title gets 45%, Agent gets 25%, ID gets 15%, PR gets 15%
+        if available > 40 {
+            let title_width = (available * 45 / 100).clamp(15, 50);
+            let agent_width = (available * 25 / 100).clamp(12, 28);
+            let id_width = (available * 15 / 100).clamp(6, 15);
+            let pr_width = (available * 15 / 100).clamp(8, 15);
+
+            self.column_widths[COL_IDX_ID] = id_width;
+            self.column_widths[COL_IDX_TITLE] = title_width;
+            self.column_widths[COL_IDX_PR] = pr_width;
+            self.column_widths[COL_IDX_AGENT] = agent_width;
+        }
+    }
+
+    /// Extract unique cycles from workstreams
+    fn update_available_cycles(&mut self) {
+        let mut seen_ids = HashSet::new();
+        self.available_cycles.clear();
+
+        for ws in &self.state.workstreams {
+            if let Some(cycle) = &ws.linear_issue.cycle {
+                if !seen_ids.contains(&cycle.id) {
+                    seen_ids.insert(cycle.id.clone());
+                    self.available_cycles.push(cycle.clone());
+                }
+            }
+        }
+
+        // Sort by cycle number (most recent first)
+        self.available_cycles.sort_by_key(|c| Reverse(c.number));
+    }
+
+    pub async fn on_tick(&mut self) {
+        // Advance spinner animation
+        self.tick_spinner();
+
+        // Periodic background refresh based on polling interval
+        if tokio::runtime::Handle::try_current().is_ok() && self.refresh_rx.is_none() {
+            let interval = Duration::from_secs(self.poll_interval_secs());
+            if interval.as_secs() > 0 {
+                let should_refresh = match self.state.last_refresh {
+                    Some(last) => {
+                        let elapsed = self.frame_now.signed_duration_since(last);
+                        elapsed.num_seconds() >= interval.as_secs() as i64
+                    }
+                    None => true,
+                };
+
+                if should_refresh {
+                    self.start_periodic_refresh();
+                }
+            }
+        }
+    }
+
+    /// Seconds between periodic refreshes.
+    ///
+    /// While Linear webhooks are arriving, Linear changes are pushed so only the
+    /// GitHub/Vercel intervals apply. After `idle_fallback_secs` without a
+    /// delivery we assume the hook is broken and poll Linear normally again.
+    fn poll_interval_secs(&self) -> u64 {
+        let polling = &self.config.polling;
+        let enrichment_secs = polling
+            .github_interval_secs
+            .min(polling.vercel_interval_secs);
+
+        if self.webhook_active() {
+            enrichment_secs
+        } else {
+            polling.linear_interval_secs.min(enrichment_secs)
+        }
+    }
+
+    /// True while webhook deliveries are recent enough to replace Linear polling
+    fn webhook_active(&self) -> bool {
+        let idle_fallback = Duration::from_secs(self.config.webhook.idle_fallback_secs);
+        self.last_webhook_event
+            .is_some_and(|at| at.elapsed() < idle_fallback)
+    }
+
+    /// Start the Linear webhook listener if enabled in config
+    pub fn start_webhook_listener(&mut self) {
+        if !self.config.webhook.enabled || self.webhook_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel(100);
+        self.webhook_rx = Some(rx);
+
+        let config = Arc::clone(&self.config);
+        tokio::spawn(async move {
+            if let Err(e) = integrations::linear_webhook::spawn(config, tx.clone()).await {
+                let _ = tx.send(RefreshResult::Error(e.to_string())).await;
+            }
+        });
+    }
+
+    /// Poll for webhook deliveries (non-blocking, call from event loop tick)
+    pub fn poll_webhook(&mut self) -> bool {
+        let Some(mut rx) = self.webhook_rx.take() else {
+            return false;
+        };
+
+        let mut changed = false;
+        let mut failed = false;
+        while let Ok(result) = rx.try_recv() {
+            match result {
+                RefreshResult::Live(update) => {
+                    changed |= self.apply_webhook_update(update);
+                }
+                RefreshResult::Error(msg) => {
+                    self.error_message = Some(format!("Webhook listener failed: {}", msg));
+                    failed = true;
+                }
+                _ => {}
+            }
+        }
+
+        if !failed {
+            self.webhook_rx = Some(rx);
+        }
+        changed
+    }
+
+    /// Apply a pushed Linear change to the main workstream list
+    fn apply_webhook_update(
+        &mut self,
+        update: integrations::linear_webhook::WebhookUpdate,
+    ) -> bool {
+        self.last_webhook_event = Some(Instant::now());
+
+        // A refresh in flight may still deliver older data for this issue
+        if self.is_loading {
+            self.pending_webhook_updates.push(update.clone());
+        }
+
+        let changed = update.apply(&mut self.state.workstreams, &self.current_user_ids);
+        if changed {
+            self.update_available_cycles();
+            self.apply_filters();
+            self.rebuild_visual_items();
+        }
+        changed
+    }
+
+    /// Re-apply webhook deliveries received during the current refresh to a
+    /// fetched workstream. Returns `None` if a delivery removed the issue.
+    fn replay_webhook_updates(&self, ws: Workstream) -> Option<Workstream> {
+        let mut workstreams = vec![ws];
+        for update in &self.pending_webhook_updates {
+            if update.issue_id() == workstreams.first()?.linear_issue.id {
+                update
+                    .clone()
+                    .apply(&mut workstreams, &self.current_user_ids);
+            }
+        }
+        workstreams.pop()
+    }
+
+    /// Start sharing sessions with teammates if `[team]` is enabled
+    pub fn start_team_share(&mut self) {
+        if !self.config.team.enabled || self.team_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = mpsc::channel(100);
+        self.team_rx = Some(rx);
+        self.team_tx = Some(tx.clone());
+
+        if let Some(addr) = self.config.team.listen_addr.clone() {
+            let secret = self.config.team.secret.clone();
+            tokio::spawn(async move {
+                if let Err(e) = team_share::spawn_listener(&addr, secret, tx.clone()).await {
+                    let _ = tx.send(TeamUpdate::Error(e.to_string())).await;
+                }
+            });
+        }
+    }
+
+    /// Publish our snapshot and collect teammates' (non-blocking, call from
+    /// event loop tick)
+    pub fn poll_team_share(&mut self) -> bool {
+        let Some(mut rx) = self.team_rx.take() else {
+            return false;
+        };
+
+        let mut changed = false;
+        while let Ok(update) = rx.try_recv() {
+            match update {
+                TeamUpdate::Snapshot(snapshot) => {
+                    self.team_snapshots
+                        .insert(snapshot.member.clone(), snapshot);
+                    changed = true;
+                }
+                TeamUpdate::Error(msg) => {
+                    self.error_message = Some(format!("Team share failed: {}", msg));
+                }
+            }
+        }
+
+        let interval = Duration::from_secs(self.config.team.publish_interval_secs.max(1));
+        if self
+            .last_team_publish
+            .is_none_or(|at| at.elapsed() >= interval)
+        {
+            self.last_team_publish = Some(Instant::now());
+            self.publish_team_snapshot();
+        }
+
+        let now = Utc::now();
+        let stale_after = self.config.team.stale_after_secs;
+        let before = self.team_snapshots.len();
+        self.team_snapshots
+            .retain(|_, snapshot| !snapshot.is_stale(now, stale_after));
+        changed |= self.team_snapshots.len() != before;
+
+        self.team_rx = Some(rx);
+        if changed {
+            self.merge_team_sessions();
+            self.apply_filters();
+            self.rebuild_visual_items();
+        }
+        changed
+    }
+
+    /// Write/POST our snapshot and scan the shared directory in the background
+    fn publish_team_snapshot(&self) {
+        let Some(tx) = self.team_tx.clone() else {
+            return;
+        };
+        let team = &self.config.team;
+        let member = team.member_name();
+        let snapshot = TeamSnapshot::from_workstreams(
+            &member,
+            self.current_user_ids.first().cloned(),
+            &self.state.workstreams,
+            Utc::now(),
+        );
+        let shared_dir = crate::config::team_shared_dir(&self.config);
+        let publish_url = team.publish_url.clone();
+        let secret = team.secret.clone();
+
+        tokio::spawn(async move {
+            if let Some(dir) = shared_dir {
+                // The shared directory may be a slow network mount
+                let ours = snapshot.clone();
+                let read = tokio::task::spawn_blocking(move || {
+                    if let Err(e) = team_share::write_snapshot(&dir, &ours) {
+                        tracing::warn!("Failed to write team snapshot: {}", e);
+                    }
+                    team_share::read_snapshots(&dir, &member)
+                })
+                .await
+                .unwrap_or_else(|e| Err(anyhow::anyhow!(e)));
+                match read {
+                    Ok(snapshots) => {
+                        for snapshot in snapshots {
+                            crate::util::send_or_log(
+                                &tx,
+                                TeamUpdate::Snapshot(snapshot),
+                                "team snapshot",
+                            )
+                            .await;
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to read team snapshots: {}", e),
+                }
+            }
+            if let Some(url) = publish_url {
+                if let Err(e) = team_share::publish_http(&url, &snapshot, secret.as_deref()).await {
+                    tracing::warn!("{}", e);
+                }
+            }
+        });
+    }
+
+    /// Re-attach teammates' sessions to the current workstreams. Returns
+    /// whether the team view is active.
+    fn merge_team_sessions(&mut self) -> bool {
+        if !self.config.team.enabled {
+            return false;
+        }
+        team_share::merge(&mut self.state.workstreams, self.team_snapshots.values());
+        true
+    }
+
+    /// Open the create-issue form, prefilled from an unlinked session row
+    pub fn open_issue_form(&mut self) {
+        let unlinked_session = self
+            .selected_workstream()
+            .filter(|ws| ws.linear_issue.identifier.is_empty())
+            .and_then(|_| self.selected_agent_session())
+            .cloned();
+        let team = self
+            .selected_workstream()
+            .and_then(|ws| ws.linear_issue.team.clone());
+
+        let mut form = match &unlinked_session {
+            Some(session) => IssueForm::for_session(session),
+            None => IssueForm::new(),
+        };
+        if let Some(team) = team {
+            form.select_team(&self.available_teams, &team);
+        }
+
+        self.issue_form = Some(form);
+        self.modal = ModalState::IssueForm;
+    }
+
+    /// Validate the form and send `issueCreate` in the background
+    pub fn submit_issue_form(&mut self) {
+        let Some(form) = self.issue_form.as_mut() else {
+            return;
+        };
+        if form.submitting {
+            return;
+        }
+
+        let (workspace, input) = match form.build(
+            &self.available_teams,
+            &self.available_projects,
+            &self.state.workstreams,
+        ) {
+            Ok(built) => built,
+            Err(msg) => {
+                form.error = Some(msg);
+                return;
+            }
+        };
+        form.submitting = true;
+        form.error = None;
+
+        let session = form.session.clone();
+        let (tx, rx) = oneshot::channel();
+        self.pending_issue_create = Some(PendingIssueCreate {
+            session: session.clone(),
+            rx,
+        });
+
+        let config = Arc::clone(&self.config);
+        tokio::spawn(async move {
+            let result =
+                integrations::linear::create_issue(&config, workspace.as_deref(), &input).await;
+            let result = result.map(|linked| {
+                if let Some(session) = &session {
+                    if let Err(e) =
+                        integrations::session_links::link_session(session, &linked.issue.identifier)
+                    {
+                        tracing::warn!("Failed to save session link: {}", e);
+                    }
+                }
+                linked.issue
+            });
+            let _ = tx.send(result.map_err(|e| e.to_string()));
+        });
+    }
+
+    /// Poll for the result of a pending issue creation (call from event loop tick)
+    pub fn poll_issue_create(&mut self) -> bool {
+        let Some(mut pending) = self.pending_issue_create.take() else {
+            return false;
+        };
+
+        match pending.rx.try_recv() {
+            Ok(result) => {
+                self.finish_issue_create(result, pending.session);
+                true
+            }
+            Err(oneshot::error::TryRecvError::Empty) => {
+                self.pending_issue_create = Some(pending);
+                false
+            }
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.finish_issue_create(Err("Issue creation interrupted".to_string()), None);
+                true
+            }
+        }
+    }
+
+    /// Show a created issue immediately, taking over the session's placeholder row
+    pub fn finish_issue_create(
+        &mut self,
+        result: Result<LinearIssue, String>,
+        session: Option<AgentSession>,
+    ) {
+        let issue = match result {
+            Ok(issue) => issue,
+            Err(msg) => {
+                let open_form = self
+                    .issue_form
+                    .as_mut()
+                    .filter(|_| matches!(self.modal, ModalState::IssueForm));
+                match open_form {
+                    Some(form) => {
+                        form.submitting = false;
+                        form.error = Some(msg);
+                    }
+                    None => {
+                        self.issue_form = None;
+                        self.error_message = Some(format!("Failed to create issue: {}", msg));
+                    }
+                }
+                return;
+            }
+        };
+
+        let issue_id = issue.id.clone();
+        let placeholder_id = session.as_ref().map(|s| format!("unlinked-{}", s.id));
+        let placeholder = placeholder_id.and_then(|id| {
+            self.state
+                .workstreams
+                .iter_mut()
+                .find(|ws| ws.linear_issue.id == id)
+        });
+        match placeholder {
+            Some(ws) => ws.linear_issue = issue,
+            None => self.state.workstreams.push(Workstream {
+                linear_issue: issue,
+                github_pr: None,
+                vercel_deployment: None,
+                linked_prs: vec![],
+                agent_sessions: session.iter().cloned().collect(),
+                agent_session: session,
+                stale: false,
+            }),
+        }
+
+        if self.show_issue_form() {
+            self.modal = ModalState::None;
+        }
+        self.issue_form = None;
+        self.update_available_cycles();
+        self.apply_filters();
+        self.rebuild_visual_items();
+
+        if let Some(pos) = self.visual_items.iter().position(|item| match item {
+            VisualItem::Workstream(idx) | VisualItem::AgentSession { ws_idx: idx, .. } => self
+                .state
+                .workstreams
+                .get(*idx)
+                .is_some_and(|ws| ws.linear_issue.id == issue_id),
+            VisualItem::SectionHeader(_) | VisualItem::ReviewRequest(_) => false,
+        }) {
+            self.visual_selected = pos;
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Linear inbox
+    // ─────────────────────────────────────────────────────────────────────────
+
+    pub fn toggle_inbox(&mut self) {
+        if self.show_inbox() {
+            self.modal = ModalState::None;
+        } else {
+            self.modal = ModalState::Inbox;
+            self.inbox_selected = 0;
+        }
+    }
+
+    pub fn unread_notification_count(&self) -> usize {
+        self.notifications.iter().filter(|n| n.is_unread()).count()
+    }
+
+    /// Replace the inbox with a fresh fetch, keeping reads Linear hasn't confirmed
+    pub fn set_notifications(&mut self, mut notifications: Vec<LinearNotification>) {
+        let failed = std::mem::take(&mut self.failed_reads);
+        let mut retry: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+        for notification in &mut notifications {
+            if notification.is_unread() && self.pending_reads.contains(&notification.id) {
+                if failed.contains(&notification.id) {
+                    retry
+                        .entry(notification.workspace.clone())
+                        .or_default()
+                        .push(notification.id.clone());
+                }
+                notification.read_at = self
+                    .notifications
+                    .iter()
+                    .find(|n| n.id == notification.id)
+                    .and_then(|n| n.read_at)
+                    .or(Some(Utc::now()));
+            }
+        }
+        self.notifications = notifications;
+        self.inbox_selected = self
+            .inbox_selected
+            .min(self.notifications.len().saturating_sub(1));
+
+        // Failed reads Linear no longer reports unread need no retry
+        for id in &failed {
+            if !retry.values().flatten().any(|retried| retried == id) {
+                self.pending_reads.remove(id);
+            }
+        }
+        self.send_reads(retry, Utc::now());
+    }
+
+    pub fn move_inbox_selection(&mut self, delta: i32) {
+        let len = self.notifications.len();
+        if len == 0 {
+            return;
+        }
+        self.inbox_selected =
+            (self.inbox_selected as i64 + delta as i64).clamp(0, len as i64 - 1) as usize;
+    }
+
+    /// Index of the workstream a notification's issue belongs to
+    pub fn notification_workstream(&self, notification: &LinearNotification) -> Option<usize> {
+        self.state.workstreams.iter().position(|ws| {
+            notification.issue_id.as_deref() == Some(ws.linear_issue.id.as_str())
+                || notification.issue_identifier.as_deref()
+                    == Some(ws.linear_issue.identifier.as_str())
+        })
+    }
+
+    pub fn mark_selected_notification_read(&mut self) {
+        if let Some(id) = self
+            .notifications
+            .get(self.inbox_selected)
+            .map(|n| n.id.clone())
+        {
+            self.mark_notifications_read(vec![id]);
+        }
+    }
+
+    pub fn mark_all_notifications_read(&mut self) {
+        let ids = self.notifications.iter().map(|n| n.id.clone()).collect();
+        self.mark_notifications_read(ids);
+    }
+
+    /// Mark notifications read immediately and tell Linear in the background
+    fn mark_notifications_read(&mut self, ids: Vec<String>) {
+        let now = Utc::now();
+        let mut by_workspace: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+        for notification in &mut self.notifications {
+            if notification.is_unread() && ids.contains(&notification.id) {
+                notification.read_at = Some(now);
+                self.pending_reads.insert(notification.id.clone());
+                by_workspace
+                    .entry(notification.workspace.clone())
+                    .or_default()
+                    .push(notification.id.clone());
+            }
+        }
+        self.send_reads(by_workspace, now);
+    }
+
+    /// Tell Linear about reads in the background, per workspace
+    fn send_reads(&self, by_workspace: BTreeMap<Option<String>, Vec<String>>, now: DateTime<Utc>) {
+        // Skip if not in a Tokio runtime (e.g., during tests)
+        if by_workspace.is_empty() || tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        for (workspace, ids) in by_workspace {
+            let config = Arc::clone(&self.config);
+            let tx = self.inbox_tx.clone();
+            tokio::spawn(async move {
+                let failed = integrations::linear::mark_notifications_read(
+                    &config,
+                    workspace.as_deref(),
+                    &ids,
+                    now,
+                )
+                .await
+                .into_iter()
+                .map(|(id, e)| (id, e.to_string()))
+                .collect();
+                let _ = tx.send((ids, failed)).await;
+            });
+        }
+    }
+
+    /// Open the selected notification: mark it read and jump to its row,
+    /// falling back to the issue details (row filtered out) or Linear itself
+    pub fn open_selected_notification(&mut self) {
+        let Some(notification) = self.notifications.get(self.inbox_selected).cloned() else {
+            return;
+        };
+        self.mark_notifications_read(vec![notification.id.clone()]);
+        self.modal = ModalState::None;
+
+        let Some(ws_idx) = self.notification_workstream(&notification) else {
+            if let Some(url) = &notification.issue_url {
+                if let Err(e) = open_linear_url(url) {
+                    self.error_message = Some(format!("Failed to open Linear: {}", e));
+                }
+            }
+            return;
+        };
+
+        let row = self.visual_items.iter().position(|item| match item {
+            VisualItem::Workstream(idx) | VisualItem::AgentSession { ws_idx: idx, .. } => {
+                *idx == ws_idx
+            }
+            VisualItem::SectionHeader(_) | VisualItem::ReviewRequest(_) => false,
+        });
+        match row {
+            Some(pos) => self.visual_selected = pos,
+            None => {
+                self.clear_navigation();
+                self.modal_issue_id = Some(self.state.workstreams[ws_idx].linear_issue.id.clone());
+                self.modal = ModalState::LinkMenu {
+                    show_links_popup: false,
+                };
+                self.pre_select_for_modal_issue();
+            }
+        }
+    }
+
+    /// Collect results of background mark-read calls (call from event loop tick)
+    pub fn poll_inbox(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((ids, failed)) = self.inbox_rx.try_recv() {
+            // Failed reads stay pending so a refresh doesn't bring them back
+            for id in &ids {
+                if !failed.iter().any(|(failed_id, _)| failed_id == id) {
+                    self.pending_reads.remove(id);
+                }
+            }
+            if let Some((_, msg)) = failed.first() {
+                self.error_message = Some(format!(
+                    "Failed to mark {} notification(s) read: {}",
+                    failed.len(),
+                    msg
+                ));
+            }
+            self.failed_reads
+                .extend(failed.into_iter().map(|(id, _)| id));
+            changed = true;
+        }
+        changed
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // PR actions
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Open the PR actions popup over the details modal
+    pub fn open_pr_actions(&mut self) {
+        if self.modal_issue().is_none_or(|ws| ws.pr_count() == 0) {
+            self.error_message = Some("No linked PR".to_string());
+            return;
+        }
+        self.pr_action_selected = 0;
+        self.modal = ModalState::PrActions { confirm: None };
+    }
+
+    pub fn move_pr_action_selection(&mut self, delta: i32) {
+        let len = self.modal_issue().map_or(0, |ws| ws.pr_count());
+        if len == 0 {
+            return;
+        }
+        self.pr_action_selected =
+            (self.pr_action_selected as i64 + delta as i64).clamp(0, len as i64 - 1) as usize;
+    }
+
+    /// PR linked to the details issue with this URL
+    fn modal_issue_pr(&self, url: &str) -> Option<GitHubPR> {
+        self.modal_issue()?
+            .all_linked_prs()
+            .into_iter()
+            .map(|linked| linked.pr)
+            .find(|pr| pr.url == url)
+    }
+
+    /// PR selected in the PR actions popup
+    pub fn selected_action_pr(&self) -> Option<GitHubPR> {
+        self.modal_issue()?
+            .all_linked_prs()
+            .into_iter()
+            .nth(self.pr_action_selected)
+            .map(|linked| linked.pr)
+    }
+
+    /// Ask for confirmation, unless the action can't apply to the selected PR
+    pub fn request_pr_action(&mut self, action: PrAction) {
+        let Some(pr) = self.selected_action_pr() else {
+            return;
+        };
+        if self.pending_pr_actions.contains(&pr.url) {
+            self.error_message = Some(format!("An action on #{} is still running", pr.number));
+        } else if !action.is_available(pr.status) {
+            self.error_message = Some(format!(
+                "{} isn't available for a PR in state {}",
+                action.label(),
+                pr.status.label()
+            ));
+        } else {
+            self.modal = ModalState::PrActions {
+                confirm: Some((action, pr.url)),
+            };
+        }
+    }
+
+    /// Run the confirmed action on GitHub in the background, on the PR it
+    /// was asked for (refreshes may have reordered the list since)
+    pub fn confirm_pr_action(&mut self) {
+        let Some(action) = self.pending_confirmation() else {
+            return;
+        };
+        let pr = self.pending_confirmation_pr();
+        self.modal = ModalState::PrActions { confirm: None };
+        let Some(pr) = pr else {
+            self.error_message = Some("The PR is no longer linked to this issue".to_string());
+            return;
+        };
+        if !action.is_available(pr.status) {
+            self.error_message = Some(format!(
+                "{} isn't available for a PR in state {}",
+                action.label(),
+                pr.status.label()
+            ));
+            return;
+        }
+        self.pending_pr_actions.insert(pr.url.clone());
+
+        // Skip if not in a Tokio runtime (e.g., during tests)
+        if tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        let config = Arc::clone(&self.config);
+        let tx = self.pr_action_tx.clone();
+        tokio::spawn(async move {
+            let result = integrations::github::run_pr_action(&config, &pr, action)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send((pr.url, action, result)).await;
+        });
+    }
+
+    /// Collect results of background PR actions (call from event loop tick)
+    pub fn poll_pr_actions(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((url, action, result)) = self.pr_action_rx.try_recv() {
+            self.pending_pr_actions.remove(&url);
+            match result {
+                Ok(pr) => self.apply_pr_update(pr),
+                Err(msg) => {
+                    self.error_message = Some(format!("{} failed: {}", action.label(), msg));
+                }
+            }
+            changed = true;
+        }
+        changed
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // CI job logs
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Failing Actions job shown in the job log modal, with its PR
+    pub fn selected_failed_job(&self) -> Option<(GitHubPR, CiCheck)> {
+        self.modal_issue()?
+            .failed_actions_jobs()
+            .into_iter()
+            .nth(self.job_log_selected)
+    }
+
+    /// Show the log of the details issue's `index`th failing job, fetching it
+    /// in the background
+    pub fn open_job_log(&mut self, index: usize) {
+        self.job_log_selected = index;
+        let Some((pr, check)) = self.selected_failed_job() else {
+            self.error_message = Some("No failing GitHub Actions job".to_string());
+            return;
+        };
+        let Some(job_id) = check.actions_job_id() else {
+            return;
+        };
+        self.job_log = None;
+        self.job_log_scroll = 0;
+        self.modal = ModalState::JobLog;
+
+        // Skip if not in a Tokio runtime (e.g., during tests)
+        if tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        let config = Arc::clone(&self.config);
+        let tx = self.job_log_tx.clone();
+        tokio::spawn(async move {
+            let result = integrations::github::fetch_job_log(&config, &pr, job_id)
+                .await
+                .map(|raw| JobLog::from_raw(&pr, &check, &raw))
+                .map_err(|e| e.to_string());
+            let _ = tx.send((job_id, result)).await;
+        });
+    }
+
+    /// Scroll the job log; positive deltas move toward the end
+    pub fn scroll_job_log(&mut self, delta: i32) {
+        let max = match &self.job_log {
+            Some(Ok(log)) => log.lines.len().saturating_sub(1),
+            _ => 0,
+        };
+        let scroll = self.job_log_scroll as i64 - delta as i64;
+        self.job_log_scroll = scroll.clamp(0, max as i64) as usize;
+    }
+
+    /// Collect a fetched job log (call from event loop tick). Logs of jobs
+    /// that are no longer shown are dropped.
+    pub fn poll_job_logs(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((job_id, result)) = self.job_log_rx.try_recv() {
+            let shown = self
+                .selected_failed_job()
+                .and_then(|(_, check)| check.actions_job_id());
+            if self.show_job_log() && shown == Some(job_id) {
+                self.job_log = Some(result);
+                self.job_log_scroll = 0;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Hand the shown job's failure excerpt to the issue's agent session
+    pub async fn send_job_log_to_agent(&self) -> Result<()> {
+        let Some(Ok(log)) = &self.job_log else {
+            anyhow::bail!("the job log hasn't loaded");
+        };
+        let Some(session) = self.modal_issue().and_then(|ws| {
+            ws.agent_session
+                .as_ref()
+                .or_else(|| ws.agent_sessions.first())
+        }) else {
+            anyhow::bail!("no agent session linked");
+        };
+        integrations::claude::hand_prompt_to_session(session, &log.agent_prompt()).await
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Vercel deployments
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// List the recent deployments of the details issue's PR (the first one
+    /// with a deployment), fetching them in the background
+    pub fn open_deployments(&mut self) {
+        let prs = self
+            .modal_issue()
+            .map(|ws| ws.all_linked_prs())
+            .unwrap_or_default();
+        let Some(linked) = prs
+            .iter()
+            .find(|linked| linked.vercel_deployment.is_some())
+            .or_else(|| prs.first())
+        else {
+            self.error_message = Some("No linked PR".to_string());
+            return;
+        };
+        let pr = linked.pr.clone();
+        self.deployment_pr = Some(pr.clone());
+        self.deployment_history = None;
+        self.deployment_selected = 0;
+        self.modal = ModalState::Deployments { confirm: None };
+
+        // Skip if not in a Tokio runtime (e.g., during tests)
+        if tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        let config = Arc::clone(&self.config);
+        let tx = self.deployment_history_tx.clone();
+        tokio::spawn(async move {
+            let result =
+                integrations::vercel::fetch_deployment_history(&config, &pr.repo, &pr.branch)
+                    .await
+                    .map_err(|e| e.to_string());
+            let _ = tx.send((pr.url, result)).await;
+        });
+    }
+
+    pub fn move_deployment_selection(&mut self, delta: i32) {
+        let len = match &self.deployment_history {
+            Some(Ok(history)) => history.len(),
+            _ => 0,
+        };
+        if len == 0 {
+            return;
+        }
+        self.deployment_selected =
+            (self.deployment_selected as i64 + delta as i64).clamp(0, len as i64 - 1) as usize;
+    }
+
+    /// Deployment selected in the deployments popup
+    pub fn selected_deployment(&self) -> Option<&VercelDeployment> {
+        match &self.deployment_history {
+            Some(Ok(history)) => history.get(self.deployment_selected),
+            _ => None,
+        }
+    }
+
+    /// Ask for confirmation, unless the action can't apply to the selected
+    /// deployment
+    pub fn request_deployment_action(&mut self, action: DeploymentAction) {
+        let Some(deployment) = self.selected_deployment() else {
+            return;
+        };
+        if self.pending_deployment_actions.contains(&deployment.id) {
+            self.error_message = Some("An action on this deployment is still running".to_string());
+        } else if !action.is_available(deployment) {
+            self.error_message = Some(format!(
+                "{} isn't available for a deployment in state {}",
+                action.label(),
+                deployment.status.label()
+            ));
+        } else {
+            self.modal = ModalState::Deployments {
+                confirm: Some(action),
+            };
+        }
+    }
+
+    /// Run the confirmed action on Vercel in the background
+    pub fn confirm_deployment_action(&mut self) {
+        let Some(action) = self.pending_deployment_confirmation() else {
+            return;
+        };
+        self.modal = ModalState::Deployments { confirm: None };
+        let (Some(deployment), Some(pr)) = (
+            self.selected_deployment().cloned(),
+            self.deployment_pr.clone(),
+        ) else {
+            return;
+        };
+        self.pending_deployment_actions
+            .insert(deployment.id.clone());
+
+        // Skip if not in a Tokio runtime (e.g., during tests)
+        if tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        let config = Arc::clone(&self.config);
+        let tx = self.deployment_action_tx.clone();
+        tokio::spawn(async move {
+            let result = integrations::vercel::run_deployment_action(
+                &config,
+                &pr.repo,
+                &pr.branch,
+                &deployment,
+                action,
+            )
+            .await
+            .map_err(|e| e.to_string());
+            let _ = tx.send((pr.url, deployment.id, action, result)).await;
+        });
+    }
+
+    /// Collect deployment histories and action results (call from event
+    /// loop tick)
+    pub fn poll_deployments(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((pr_url, result)) = self.deployment_history_rx.try_recv() {
+            let shown = self.deployment_pr.as_ref().map(|pr| pr.url.as_str());
+            if self.show_deployments() && shown == Some(pr_url.as_str()) {
+                self.deployment_history = Some(result);
+                self.deployment_selected = 0;
+                changed = true;
+            }
+        }
+        while let Ok((pr_url, id, action, result)) = self.deployment_action_rx.try_recv() {
+            self.pending_deployment_actions.remove(&id);
+            match result {
+                Ok(deployment) => self.apply_deployment_update(&pr_url, action, deployment),
+                Err(msg) => {
+                    self.error_message = Some(format!("{} failed: {}", action.label(), msg));
+                }
+            }
+            changed = true;
+        }
+        changed
+    }
+
+    /// Show the deployment an action returned: a redeploy becomes the
+    /// branch's latest deployment, other actions update the deployment in place
+    pub fn apply_deployment_update(
+        &mut self,
+        pr_url: &str,
+        action: DeploymentAction,
+        deployment: VercelDeployment,
+    ) {
+        let is_new = action == DeploymentAction::Redeploy;
+        let shown = self.deployment_pr.as_ref().map(|pr| pr.url.as_str());
+        if shown == Some(pr_url) {
+            if let Some(Ok(history)) = &mut self.deployment_history {
+                if is_new {
+                    history.insert(0, deployment.clone());
+                    self.deployment_selected = 0;
+                } else if let Some(existing) = history.iter_mut().find(|d| d.id == deployment.id) {
+                    *existing = deployment.clone();
+                }
+            }
+        }
+
+        for ws in &mut self.state.workstreams {
+            let mut prs = ws.all_linked_prs();
+            let mut updated = false;
+            for linked in prs.iter_mut().filter(|linked| linked.pr.url == pr_url) {
+                let latest = linked
+                    .vercel_deployment
+                    .as_ref()
+                    .is_some_and(|d| d.id == deployment.id);
+                if is_new || latest {
+                    linked.vercel_deployment = Some(deployment.clone());
+                    updated = true;
+                }
+            }
+            if updated {
+                ws.set_linked_prs(prs);
             }
         }
+        self.apply_filters();
+        self.rebuild_visual_items();
+    }
 
-        // Apply calculated widths with some padding
-        // Add 2 extra chars for sub-issue prefix "└ " if any sub-issues exist
-        let sub_issue_padding = if has_sub_issues { 2 } else { 0 };
-        self.column_widths[COL_IDX_ID] = max_id_len + 1 + sub_issue_padding;
-        self.column_widths[COL_IDX_TITLE] = max_title_len.min(40); // Cap title at 40
-        self.column_widths[COL_IDX_PR] = max_pr_len.min(15);
-        self.column_widths[COL_IDX_AGENT] = max_agent_len.min(24);
+    // ─────────────────────────────────────────────────────────────────────────
+    // Vercel build logs
+    // ─────────────────────────────────────────────────────────────────────────
 
-        // Status, Priority, Vercel, and Time have fixed widths
-        // (already set in defaults, no need to recalculate)
+    /// Show the build log of the selected issue's Vercel deployment
+    pub fn open_build_log(&mut self) {
+        let Some(ws) = self.selected_workstream() else {
+            return;
+        };
+        let Some(deployment) = ws.vercel_deployment.clone() else {
+            self.error_message = Some("No Vercel deployment".to_string());
+            return;
+        };
+        if deployment.source != DeploymentSource::Vercel {
+            self.error_message = Some("Build logs need a Vercel token".to_string());
+            return;
+        }
+        if !matches!(
+            deployment.status,
+            VercelStatus::Queued | VercelStatus::Building | VercelStatus::Error
+        ) {
+            self.error_message = Some(format!(
+                "No build log for a {} deployment (only failed or running builds)",
+                deployment.status.label().to_lowercase()
+            ));
+            return;
+        }
+        self.build_log_target = Some((ws.linear_issue.id.clone(), deployment));
+        self.build_log = None;
+        self.build_log_scroll = 0;
+        self.build_log_in_flight = false;
+        self.modal = ModalState::BuildLog;
+        self.request_build_log();
     }
 
-    /// Recalculate column widths for given terminal width
-    pub fn recalculate_column_widths(&mut self, terminal_width: u16) {
-        // Calculate fixed widths (status, priority, vercel, time, separators)
-        let fixed_widths = self.column_widths[COL_IDX_STATUS]
-            + self.column_widths[COL_IDX_PRIORITY]
-            + self.column_widths[COL_IDX_VERCEL]
-            + self.column_widths[COL_IDX_TIME]
-            + 24; // Separators and padding
-
-        let available = (terminal_width as usize).saturating_sub(fixed_widths);
+    /// Fetch the shown deployment's build log in the background, only the
+    /// events after those already loaded
+    fn request_build_log(&mut self) {
+        let Some((_, deployment)) = &self.build_log_target else {
+            return;
+        };
+        // Skip if not in a Tokio runtime (e.g., during tests)
+        if tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        self.build_log_requested_at = Some(Instant::now());
+        self.build_log_in_flight = true;
+        let config = Arc::clone(&self.config);
+        let id = deployment.id.clone();
+        let after = match &self.build_log {
+            Some(Ok(log)) => log.cursor.clone(),
+            _ => None,
+        };
+        let tx = self.build_log_tx.clone();
+        tokio::spawn(async move {
+            let result = integrations::vercel::fetch_build_log(&config, &id, after.as_ref())
+                .await
+                .map_err(|e| e.to_string());
+            let _ = tx.send((id, after, result)).await;
+        });
+    }
 
-        // Distribute remaining space to ID, Title, PR, Agent
-        // Title gets 45%, Agent gets 25%, ID gets 15%, PR gets 15%
-        if available > 40 {
-            let title_width = (available * 45 / 100).clamp(15, 50);
-            let agent_width = (available * 25 / 100).clamp(12, 28);
-            let id_width = (available * 15 / 100).clamp(6, 15);
-            let pr_width = (available * 15 / 100).clamp(8, 15);
+    /// Scroll the build log; positive deltas move toward the end
+    pub fn scroll_build_log(&mut self, delta: i32) {
+        let max = match &self.build_log {
+            Some(Ok(log)) => log.lines.len().saturating_sub(1),
+            _ => 0,
+        };
+        let scroll = self.build_log_scroll as i64 - delta as i64;
+        self.build_log_scroll = scroll.clamp(0, max as i64) as usize;
+    }
 
-            self.column_widths[COL_IDX_ID] = id_width;
-            self.column_widths[COL_IDX_TITLE] = title_width;
-            self.column_widths[COL_IDX_PR] = pr_width;
-            self.column_widths[COL_IDX_AGENT] = agent_width;
+    /// Scroll so the first error sits near the bottom of the view, with the
+    /// output leading up to it above
+    pub fn jump_to_build_error(&mut self) {
+        if let Some(Ok(log)) = &self.build_log {
+            if let Some(idx) = log.first_error {
+                self.build_log_scroll = log.lines.len().saturating_sub(idx + 10);
+            }
         }
     }
 
-    /// Extract unique cycles from workstreams
-    fn update_available_cycles(&mut self) {
-        let mut seen_ids = HashSet::new();
-        self.available_cycles.clear();
-
-        for ws in &self.state.workstreams {
-            if let Some(cycle) = &ws.linear_issue.cycle {
-                if !seen_ids.contains(&cycle.id) {
-                    seen_ids.insert(cycle.id.clone());
-                    self.available_cycles.push(cycle.clone());
+    /// Collect fetched build logs and keep polling while the build runs (call
+    /// from event loop tick). Logs of deployments no longer shown are dropped.
+    pub fn poll_build_logs(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((id, after, result)) = self.build_log_rx.try_recv() {
+            let shown = self.build_log_target.as_ref().map(|(_, d)| d.id.as_str());
+            if !self.show_build_log() || shown != Some(id.as_str()) {
+                continue;
+            }
+            self.build_log_in_flight = false;
+            // Requested before the modal was reopened or the log moved on;
+            // only the events after what's loaded may be appended
+            let loaded = match &self.build_log {
+                Some(Ok(log)) => log.cursor.as_ref(),
+                _ => None,
+            };
+            if after.as_ref() != loaded {
+                continue;
+            }
+            let first_load = self.build_log.is_none();
+            match (result, &mut self.build_log) {
+                (Ok(newer), Some(Ok(log))) => {
+                    // Keep the lines in view while scrolled up from the tail
+                    if self.build_log_scroll > 0 {
+                        self.build_log_scroll += newer.lines.len();
+                    }
+                    log.append(newer);
                 }
+                // A failed poll keeps showing what was already loaded
+                (Err(_), Some(Ok(_))) => {}
+                (result, slot) => *slot = Some(result),
             }
+            if first_load {
+                let failed = matches!(&self.build_log, Some(Ok(log)) if !log.is_live());
+                if failed {
+                    self.jump_to_build_error();
+                }
+            }
+            changed = true;
         }
 
-        // Sort by cycle number (most recent first)
-        self.available_cycles
-            .sort_by(|a, b| b.number.cmp(&a.number));
+        let live = matches!(&self.build_log, Some(Ok(log)) if log.is_live());
+        let due = self
+            .build_log_requested_at
+            .is_none_or(|at| at.elapsed() >= BUILD_LOG_POLL_INTERVAL);
+        if self.show_build_log() && live && !self.build_log_in_flight && due {
+            self.request_build_log();
+        }
+        changed
     }
 
-    pub async fn on_tick(&mut self) {
-        // Advance spinner animation
-        self.tick_spinner();
-
-        // Periodic background refresh based on polling interval
-        if tokio::runtime::Handle::try_current().is_ok() && self.refresh_rx.is_none() {
-            let interval_secs = self
-                .config
-                .polling
-                .linear_interval_secs
-                .min(self.config.polling.github_interval_secs)
-                .min(self.config.polling.vercel_interval_secs);
-            let interval = Duration::from_secs(interval_secs);
-            if interval.as_secs() > 0 {
-                let should_refresh = match self.state.last_refresh {
-                    Some(last) => {
-                        let elapsed = self.frame_now.signed_duration_since(last);
-                        elapsed.num_seconds() >= interval.as_secs() as i64
-                    }
-                    None => true,
-                };
+    /// Hand the output from the first build error on to the issue's agent
+    /// session
+    pub async fn send_build_log_to_agent(&self) -> Result<()> {
+        let (Some(Ok(log)), Some((issue_id, deployment))) =
+            (&self.build_log, &self.build_log_target)
+        else {
+            anyhow::bail!("the build log hasn't loaded");
+        };
+        let Some(session) = self
+            .state
+            .workstreams
+            .iter()
+            .find(|ws| &ws.linear_issue.id == issue_id)
+            .and_then(|ws| {
+                ws.agent_session
+                    .as_ref()
+                    .or_else(|| ws.agent_sessions.first())
+            })
+        else {
+            anyhow::bail!("no agent session linked");
+        };
+        integrations::claude::hand_prompt_to_session(session, &log.agent_prompt(&deployment.url))
+            .await
+    }
 
-                if should_refresh {
-                    self.start_background_refresh();
-                }
+    /// Replace a PR everywhere it's shown with GitHub's latest copy
+    pub fn apply_pr_update(&mut self, pr: GitHubPR) {
+        for ws in &mut self.state.workstreams {
+            if ws
+                .all_linked_prs()
+                .iter()
+                .any(|linked| linked.pr.url == pr.url)
+            {
+                ws.upsert_pr(pr.clone());
+            }
+        }
+        for request in &mut self.state.review_requests {
+            if request.pr.url == pr.url {
+                request.pr = pr.clone();
             }
         }
+        self.apply_filters();
+        self.rebuild_visual_items();
     }
 
     /// Poll unified watcher for agent session changes (real-time updates)
@@ -1123,7 +2805,11 @@ impl App {
             };
 
             for current in existing_sessions {
-                if let Some(updated) = session_by_id.get(current.id.as_str()) {
+                // Teammates' sessions are updated by their snapshots
+                if current.owner.is_some() {
+                    seen_ids.insert(current.id.clone());
+                    updated_sessions.push(current);
+                } else if let Some(updated) = session_by_id.get(current.id.as_str()) {
                     if current.status != updated.status {
                         structure_changed = true;
                     }
@@ -1134,8 +2820,9 @@ impl App {
                 }
             }
 
-            // Add new sessions matching PR branch + repo
-            if let Some(pr) = &ws.github_pr {
+            // Add new sessions matching any linked PR's branch + repo
+            for linked in ws.all_linked_prs() {
+                let pr = &linked.pr;
                 if let Some(candidates) = session_by_branch.get(pr.branch.as_str()) {
                     let repo_name = repo_name_from_hint(&pr.repo);
                     for candidate in candidates {
@@ -1158,7 +2845,9 @@ impl App {
                         continue;
                     }
                     if let Some(branch) = candidate.git_branch.as_deref() {
-                        if branch.to_uppercase().contains(&identifier) {
+                        if agent_cache::extract_issue_id(&self.identifier_re, branch).as_deref()
+                            == Some(identifier.as_str())
+                        {
                             seen_ids.insert(candidate.id.clone());
                             updated_sessions.push(candidate.clone());
                             structure_changed = true;
@@ -1224,7 +2913,7 @@ impl App {
             }
 
             // Sort by score (higher is better)
-            results.sort_by(|a, b| b.1.cmp(&a.1));
+            results.sort_by_key(|r| Reverse(r.1));
 
             // Store filtered indices and excerpts
             self.filtered_indices = results.iter().map(|(i, _, _)| *i).collect();
@@ -1386,20 +3075,7 @@ impl App {
 
     /// Toggle fold of the section containing the current item
     pub fn toggle_section_fold(&mut self) {
-        let section = match self.visual_items.get(self.visual_selected) {
-            Some(VisualItem::SectionHeader(section)) => Some(*section),
-            Some(VisualItem::AgentSession { .. }) => Some(SectionType::AgentSessions),
-            Some(VisualItem::Workstream(idx)) => self.state.workstreams.get(*idx).map(|ws| {
-                if !ws.agent_sessions.is_empty() || ws.agent_session.is_some() {
-                    SectionType::AgentSessions
-                } else {
-                    SectionType::Issues
-                }
-            }),
-            None => None,
-        };
-
-        if let Some(section) = section {
+        if let Some(section) = self.selected_section() {
             if self.state.collapsed_sections.contains(&section) {
                 self.state.collapsed_sections.remove(&section);
             } else {
@@ -1418,6 +3094,14 @@ impl App {
         }
     }
 
+    /// Get the currently selected review request (if any)
+    pub fn selected_review_request(&self) -> Option<&ReviewRequest> {
+        match self.visual_items.get(self.visual_selected) {
+            Some(VisualItem::ReviewRequest(idx)) => self.state.review_requests.get(*idx),
+            _ => None,
+        }
+    }
+
     /// Get the currently selected agent session (if any)
     pub fn selected_agent_session(&self) -> Option<&AgentSession> {
         match self.visual_items.get(self.visual_selected) {
@@ -1447,15 +3131,14 @@ impl App {
     /// Get the currently selected section
     pub fn selected_section(&self) -> Option<SectionType> {
         match self.visual_items.get(self.visual_selected) {
-            Some(VisualItem::SectionHeader(section)) => Some(*section),
+            Some(VisualItem::SectionHeader(section)) => Some(section.clone()),
             Some(VisualItem::AgentSession { .. }) => Some(SectionType::AgentSessions),
-            Some(VisualItem::Workstream(idx)) => self.state.workstreams.get(*idx).map(|ws| {
-                if !ws.agent_sessions.is_empty() || ws.agent_session.is_some() {
-                    SectionType::AgentSessions
-                } else {
-                    SectionType::Issues
-                }
-            }),
+            Some(VisualItem::ReviewRequest(_)) => Some(SectionType::ReviewRequests),
+            Some(VisualItem::Workstream(idx)) => self
+                .state
+                .workstreams
+                .get(*idx)
+                .map(|ws| self.state.section_for(ws)),
             None => None,
         }
     }
@@ -1521,6 +3204,7 @@ impl App {
         self.modal_issue_id = None;
         self.parent_selected = false;
         self.selected_child_idx = None;
+        self.selected_relation_idx = None;
         self.sub_issues_scroll = 0;
         self.modal_search_mode = false;
         self.modal_search_query.clear();
@@ -1578,6 +3262,17 @@ impl App {
         Ok(())
     }
 
+    /// Open a linked PR by index (0 is the primary PR)
+    pub fn open_pr_link(&self, idx: usize) -> Result<()> {
+        if let Some(linked) = self
+            .modal_issue()
+            .and_then(|ws| ws.all_linked_prs().into_iter().nth(idx))
+        {
+            open_url(&linked.pr.url)?;
+        }
+        Ok(())
+    }
+
     pub async fn open_vercel_link(&self) -> Result<()> {
         if let Some(ws) = self.modal_issue() {
             if let Some(deploy) = &ws.vercel_deployment {
@@ -1591,6 +3286,11 @@ impl App {
         if self.show_link_menu() {
             self.modal = ModalState::None;
             self.clear_navigation();
+        } else if let Some(request) = self.selected_review_request() {
+            // Review rows open the PR itself
+            if let Err(e) = open_url(&request.pr.url) {
+                self.error_message = Some(format!("Failed to open PR: {}", e));
+            }
         } else {
             self.modal = ModalState::LinkMenu {
                 show_links_popup: false,
@@ -1602,12 +3302,26 @@ impl App {
         }
     }
 
+    /// Open the Linear issue named in the selected review request's branch
+    fn open_review_request_issue(&mut self) {
+        let Some(request) = self.selected_review_request() else {
+            return;
+        };
+        let result = match &request.linked_issue {
+            Some(issue) => open_linear_url(&issue.url),
+            None => Err(anyhow::anyhow!("PR is not linked to a Linear issue")),
+        };
+        if let Err(e) = result {
+            self.error_message = Some(format!("Failed to open Linear: {}", e));
+        }
+    }
+
     /// Pre-select the first navigable item in link menu (parent or first child)
     fn pre_select_in_link_menu(&mut self) {
         self.pre_select_for_modal_issue();
     }
 
-    /// Pre-select parent or first child for the current modal issue
+    /// Pre-select parent, first child or first relation for the current modal issue
     /// Uses modal_issue() which respects modal_issue_id if set
     fn pre_select_for_modal_issue(&mut self) {
         let has_parent = self
@@ -1616,6 +3330,8 @@ impl App {
             .unwrap_or(false);
         // Use sorted/filtered children count (matches UI display)
         let children_count = self.get_sorted_filtered_children().len();
+        let relations_count = self.modal_relations().len();
+        self.selected_relation_idx = None;
 
         if has_parent {
             self.parent_selected = true;
@@ -1627,6 +3343,9 @@ impl App {
         } else {
             self.parent_selected = false;
             self.selected_child_idx = None;
+            if relations_count > 0 {
+                self.selected_relation_idx = Some(0);
+            }
         }
     }
 
@@ -1637,6 +3356,30 @@ impl App {
         Ok(())
     }
 
+    /// Hand the modal issue's unresolved review threads to its agent session
+    pub async fn send_review_comments_to_agent(&self) -> Result<()> {
+        let Some(ws) = self.modal_issue() else {
+            return Ok(());
+        };
+        let prompts: Vec<String> = ws
+            .all_linked_prs()
+            .iter()
+            .filter_map(|linked| linked.pr.unresolved_threads_prompt())
+            .collect();
+        if prompts.is_empty() {
+            anyhow::bail!("no unresolved review threads");
+        }
+        let prompt = prompts.join("\n");
+        let Some(session) = ws
+            .agent_session
+            .as_ref()
+            .or_else(|| ws.agent_sessions.first())
+        else {
+            anyhow::bail!("no agent session linked");
+        };
+        integrations::claude::hand_prompt_to_session(session, &prompt).await
+    }
+
     /// Open a document attachment by index (0-based)
     pub fn open_document(&self, index: usize) -> Result<()> {
         if let Some(ws) = self.modal_issue() {
@@ -1662,7 +3405,7 @@ impl App {
         self.frame_now = now;
     }
 
-    /// Navigate to next item in link menu (parent → children cycle)
+    /// Navigate to next item in link menu (parent → children → relations)
     /// Uses sorted/filtered children count to match what's displayed in UI
     pub fn next_child_issue(&mut self) {
         let has_parent = self
@@ -1672,13 +3415,21 @@ impl App {
 
         // Use sorted/filtered children count (matches UI display)
         let children_count = self.get_sorted_filtered_children().len();
+        let relations_count = self.modal_relations().len();
 
-        if self.parent_selected {
-            // Move from parent to first child (if any)
+        if let Some(idx) = self.selected_relation_idx {
+            if idx + 1 < relations_count {
+                self.selected_relation_idx = Some(idx + 1);
+            }
+        } else if self.parent_selected {
+            // Move from parent to first child (or first relation)
             if children_count > 0 {
                 self.parent_selected = false;
                 self.selected_child_idx = Some(0);
                 self.sub_issues_scroll = 0;
+            } else if relations_count > 0 {
+                self.parent_selected = false;
+                self.selected_relation_idx = Some(0);
             }
         } else if let Some(idx) = self.selected_child_idx {
             // Move to next child
@@ -1690,19 +3441,25 @@ impl App {
                 if idx + 1 >= visible_end {
                     self.sub_issues_scroll = (idx + 1).saturating_sub(visible_height - 1);
                 }
+            } else if relations_count > 0 {
+                // Move from last child to first relation
+                self.selected_child_idx = None;
+                self.selected_relation_idx = Some(0);
             }
         } else {
-            // Nothing selected - select parent first if exists, else first child
+            // Nothing selected - select parent first if exists, else first child/relation
             if has_parent {
                 self.parent_selected = true;
             } else if children_count > 0 {
                 self.selected_child_idx = Some(0);
                 self.sub_issues_scroll = 0;
+            } else if relations_count > 0 {
+                self.selected_relation_idx = Some(0);
             }
         }
     }
 
-    /// Navigate to previous item in link menu (children → parent cycle)
+    /// Navigate to previous item in link menu (relations → children → parent)
     /// Uses sorted/filtered children to match what's displayed in UI
     pub fn prev_child_issue(&mut self) {
         let has_parent = self
@@ -1710,7 +3467,23 @@ impl App {
             .map(|ws| ws.linear_issue.parent.is_some())
             .unwrap_or(false);
 
-        if self.parent_selected {
+        if let Some(idx) = self.selected_relation_idx {
+            if idx > 0 {
+                self.selected_relation_idx = Some(idx - 1);
+                return;
+            }
+            // Move from first relation to last child, or to the parent
+            let children_count = self.get_sorted_filtered_children().len();
+            if children_count > 0 {
+                let last = children_count - 1;
+                self.selected_relation_idx = None;
+                self.selected_child_idx = Some(last);
+                self.sub_issues_scroll = (last + 1).saturating_sub(self.sub_issues_visible_height);
+            } else if has_parent {
+                self.selected_relation_idx = None;
+                self.parent_selected = true;
+            }
+        } else if self.parent_selected {
             // Already at top (parent), do nothing
         } else if let Some(idx) = self.selected_child_idx {
             if idx == 0 && has_parent {
@@ -1838,6 +3611,54 @@ impl App {
         false
     }
 
+    /// Relations of the modal issue, filtered by the modal search (matches UI rendering)
+    pub fn modal_relations(&self) -> Vec<&LinearRelationRef> {
+        let Some(ws) = self.modal_issue() else {
+            return Vec::new();
+        };
+        if self.modal_search_query.is_empty() {
+            return ws.linear_issue.relations.iter().collect();
+        }
+
+        let mut fuzzy = FuzzySearch::new();
+        ws.linear_issue
+            .relations
+            .iter()
+            .filter(|relation| {
+                let text = format!(
+                    "{} {} {} {}",
+                    relation.kind.label(),
+                    relation.identifier,
+                    relation.title,
+                    relation.status.display_name()
+                );
+                fuzzy
+                    .multi_term_match(&self.modal_search_query, &text)
+                    .is_some()
+            })
+            .collect()
+    }
+
+    /// Navigate to the selected related issue in modal if it exists in workstreams
+    pub fn navigate_to_selected_relation(&mut self) -> bool {
+        let Some(idx) = self.selected_relation_idx else {
+            return false;
+        };
+        let Some(relation) = self.modal_relations().get(idx).copied() else {
+            return false;
+        };
+
+        let identifier = relation.identifier.clone();
+        if let Some(target_id) = self
+            .find_by_identifier(&identifier)
+            .map(|ws| ws.linear_issue.id.clone())
+        {
+            self.navigate_to_issue(&target_id);
+            return true;
+        }
+        false
+    }
+
     pub fn toggle_preview(&mut self) {
         self.show_preview = !self.show_preview;
     }
@@ -1903,6 +3724,12 @@ impl App {
         self.rebuild_visual_items();
     }
 
+    pub fn set_group_by(&mut self, group_by: GroupBy) {
+        self.state.group_by = group_by;
+        self.modal = ModalState::None;
+        self.rebuild_visual_items();
+    }
+
     // Resize mode
     pub fn toggle_resize_mode(&mut self) {
         if self.resize_mode() {
@@ -2025,11 +3852,29 @@ impl App {
         self.rebuild_visual_items();
     }
 
+    pub fn toggle_workspace_filter(&mut self, idx: usize) {
+        if let Some(name) = self.available_workspaces.get(idx) {
+            if !self.filter_workspaces.remove(name) {
+                self.filter_workspaces.insert(name.clone());
+            }
+            self.apply_filters();
+            self.rebuild_visual_items();
+        }
+    }
+
+    pub fn clear_workspace_filters(&mut self) {
+        self.filter_workspaces.clear();
+        self.apply_filters();
+        self.rebuild_visual_items();
+    }
+
     pub fn clear_all_filters(&mut self) {
         self.filter_cycles.clear();
         self.filter_priorities.clear();
         self.filter_projects.clear();
         self.filter_assignees.clear();
+        self.filter_workspaces.clear();
+        self.filter_overdue = false;
         self.apply_filters();
         self.rebuild_visual_items();
     }
@@ -2047,9 +3892,10 @@ impl App {
         ]
         .into_iter()
         .collect();
-        // Show all projects/assignees
+        // Show all projects/assignees/workspaces
         self.filter_projects.clear();
         self.filter_assignees.clear();
+        self.filter_workspaces.clear();
         self.apply_filters();
         self.rebuild_visual_items();
     }
@@ -2072,6 +3918,18 @@ impl App {
         self.rebuild_visual_items();
     }
 
+    pub fn cycle_blocked_mode(&mut self) {
+        self.state.blocked_mode = self.state.blocked_mode.next();
+        self.apply_filters();
+        self.rebuild_visual_items();
+    }
+
+    pub fn toggle_overdue_filter(&mut self) {
+        self.filter_overdue = !self.filter_overdue;
+        self.apply_filters();
+        self.rebuild_visual_items();
+    }
+
     /// Apply filters to create filtered_indices
     pub fn apply_filters(&mut self) {
         // Pre-build project name → ID lookup map (O(1) instead of O(n) per workstream)
@@ -2080,6 +3938,8 @@ impl App {
             .iter()
             .map(|p| (p.name.as_str(), p.id.as_str()))
             .collect();
+        let now = Utc::now();
+        self.state.session_owner_filter = self.session_owner_filter();
 
         self.filtered_indices = self
             .state
@@ -2087,10 +3947,22 @@ impl App {
             .iter()
             .enumerate()
             .filter(|(_, ws)| {
-                // Always show unlinked agent sessions regardless of filters
-                if (!ws.agent_sessions.is_empty() || ws.agent_session.is_some())
-                    && ws.linear_issue.identifier.is_empty()
+                let has_sessions = !ws.agent_sessions.is_empty() || ws.agent_session.is_some();
+                let by_session_owner = has_sessions && self.state.session_owner_filter.is_some();
+
+                // In team mode agent rows follow the assignee filter by who runs the session
+                if by_session_owner
+                    && !ws
+                        .agent_sessions
+                        .iter()
+                        .chain(ws.agent_session.iter())
+                        .any(|s| self.state.shows_session(s))
                 {
+                    return false;
+                }
+
+                // Always show unlinked agent sessions regardless of other filters
+                if has_sessions && ws.linear_issue.identifier.is_empty() {
                     return true;
                 }
 
@@ -2108,6 +3980,16 @@ impl App {
                     return false;
                 }
 
+                // Blocked filter (hide issues waiting on open blockers)
+                if self.state.blocked_mode == BlockedMode::Hide && ws.linear_issue.is_blocked() {
+                    return false;
+                }
+
+                // Overdue filter (only issues past due date or SLA)
+                if self.filter_overdue && !ws.linear_issue.is_overdue(now) {
+                    return false;
+                }
+
                 // Cycle filter (empty = show all)
                 if !self.filter_cycles.is_empty() {
                     match &ws.linear_issue.cycle {
@@ -2138,8 +4020,8 @@ impl App {
                     }
                 }
 
-                // Assignee filter (empty = show all)
-                if !self.filter_assignees.is_empty() {
+                // Assignee filter (empty = show all; team agent rows handled above)
+                if !self.filter_assignees.is_empty() && !by_session_owner {
                     let assignee_id = ws.linear_issue.assignee_id.as_deref();
                     let mut matched = false;
 
@@ -2149,12 +4031,11 @@ impl App {
                     }
 
                     // Me
-                    if self.filter_assignees.contains("me") {
-                        if let Some(me) = self.current_user_id.as_deref() {
-                            if assignee_id == Some(me) {
-                                matched = true;
-                            }
-                        }
+                    if self.filter_assignees.contains("me")
+                        && assignee_id
+                            .is_some_and(|id| self.current_user_ids.iter().any(|me| me == id))
+                    {
+                        matched = true;
                     }
 
                     // Specific user IDs
@@ -2169,6 +4050,17 @@ impl App {
                     }
                 }
 
+                // Workspace filter (empty = show all)
+                if !self.filter_workspaces.is_empty()
+                    && !ws
+                        .linear_issue
+                        .workspace
+                        .as_ref()
+                        .is_some_and(|name| self.filter_workspaces.contains(name))
+                {
+                    return false;
+                }
+
                 // Sub-issue filter (if disabled, hide issues that have a parent)
                 if !self.show_sub_issues && ws.linear_issue.parent.is_some() {
                     return false;
@@ -2180,6 +4072,50 @@ impl App {
             .collect();
     }
 
+    /// The assignee filter as it applies to agent sessions ("me" = sessions on
+    /// this machine, team members = their shared sessions). Only with team
+    /// sharing on - otherwise agent rows filter by issue assignee like the rest.
+    fn session_owner_filter(&self) -> Option<SessionOwnerFilter> {
+        if !self.config.team.enabled || self.filter_assignees.is_empty() {
+            return None;
+        }
+
+        let local = self.filter_assignees.contains("me");
+        let mut user_ids: HashSet<String> = self
+            .filter_assignees
+            .iter()
+            .filter(|id| *id != "me" && *id != "unassigned")
+            .cloned()
+            .collect();
+        if local {
+            user_ids.extend(self.current_user_ids.iter().cloned());
+        }
+
+        Some(SessionOwnerFilter {
+            local,
+            unknown: self.filter_assignees.contains("unassigned"),
+            user_ids,
+        })
+    }
+
+    /// Active cycle progress across my issues (header summary)
+    pub fn cycle_progress(&self) -> Option<CycleProgress> {
+        let mine = self
+            .state
+            .workstreams
+            .iter()
+            .map(|ws| &ws.linear_issue)
+            .filter(|issue| {
+                !issue.identifier.is_empty()
+                    && (self.current_user_ids.is_empty()
+                        || issue
+                            .assignee_id
+                            .as_ref()
+                            .is_some_and(|id| self.current_user_ids.contains(id)))
+            });
+        CycleProgress::compute(mine, self.frame_now)
+    }
+
     /// Check if any filter is active
     #[allow(dead_code)]
     pub fn has_active_filters(&self) -> bool {
@@ -2187,6 +4123,8 @@ impl App {
             || !self.filter_priorities.is_empty()
             || !self.filter_projects.is_empty()
             || !self.filter_assignees.is_empty()
+            || !self.filter_workspaces.is_empty()
+            || self.filter_overdue
     }
 }
 
@@ -0,0 +1,324 @@
+//! Quick-capture form for creating Linear issues from the TUI.
+//!
+//! The form only holds what the user typed/selected; team and project choices
+//! are indices into the lists the App already loaded during refresh.
+
+use crate::data::{AgentSession, LinearPriority, Workstream};
+use crate::integrations::linear::{IssueCreate, ProjectInfo, TeamInfo};
+
+/// Longest title prefilled from a session prompt
+const MAX_PREFILL_TITLE: usize = 80;
+
+/// Priority choices in the order Left/Right cycles through them
+const PRIORITIES: [LinearPriority; 5] = [
+    LinearPriority::NoPriority,
+    LinearPriority::Urgent,
+    LinearPriority::High,
+    LinearPriority::Medium,
+    LinearPriority::Low,
+];
+
+/// Focusable form fields, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IssueFormField {
+    #[default]
+    Title,
+    Team,
+    Priority,
+    Project,
+    Labels,
+    Parent,
+}
+
+impl IssueFormField {
+    pub const ALL: [Self; 6] = [
+        Self::Title,
+        Self::Team,
+        Self::Priority,
+        Self::Project,
+        Self::Labels,
+        Self::Parent,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Title => "Title",
+            Self::Team => "Team",
+            Self::Priority => "Priority",
+            Self::Project => "Project",
+            Self::Labels => "Labels",
+            Self::Parent => "Parent",
+        }
+    }
+
+    /// Free-text fields (everything else is picked with Left/Right)
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Title | Self::Parent)
+    }
+}
+
+/// State of the create-issue modal
+#[derive(Debug, Clone, Default)]
+pub struct IssueForm {
+    pub title: String,
+    /// Prefilled from the session; not editable in the form
+    pub description: Option<String>,
+    pub team_idx: usize,
+    pub priority: LinearPriority,
+    pub project_idx: Option<usize>,
+    /// Selected label IDs (from the current team's labels)
+    pub label_ids: Vec<String>,
+    pub label_cursor: usize,
+    /// Parent issue identifier (e.g., "DRE-100"), empty for none
+    pub parent: String,
+    pub focus: IssueFormField,
+    /// Unlinked session the new issue gets linked to
+    pub session: Option<AgentSession>,
+    /// Waiting for Linear to answer
+    pub submitting: bool,
+    pub error: Option<String>,
+}
+
+impl IssueForm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Form prefilled from an unlinked session's last prompt and branch
+    pub fn for_session(session: &AgentSession) -> Self {
+        Self {
+            title: prefill_title(session),
+            description: prefill_description(session),
+            session: Some(session.clone()),
+            ..Self::default()
+        }
+    }
+
+    /// Preselect a team by name (e.g. the team of the selected issue)
+    pub fn select_team(&mut self, teams: &[TeamInfo], name: &str) {
+        if let Some(idx) = teams.iter().position(|t| t.name == name) {
+            self.team_idx = idx;
+        }
+    }
+
+    pub fn focus_next(&mut self) {
+        let idx = self.focus_index();
+        self.focus = IssueFormField::ALL[(idx + 1) % IssueFormField::ALL.len()];
+    }
+
+    pub fn focus_prev(&mut self) {
+        let idx = self.focus_index();
+        let len = IssueFormField::ALL.len();
+        self.focus = IssueFormField::ALL[(idx + len - 1) % len];
+    }
+
+    fn focus_index(&self) -> usize {
+        IssueFormField::ALL
+            .iter()
+            .position(|f| *f == self.focus)
+            .unwrap_or(0)
+    }
+
+    /// Type into the focused field (Space toggles the label under the cursor)
+    pub fn input_char(&mut self, c: char, teams: &[TeamInfo]) {
+        match self.focus {
+            IssueFormField::Title => self.title.push(c),
+            IssueFormField::Parent => self.parent.push(c),
+            IssueFormField::Labels if c == ' ' => self.toggle_label(teams),
+            _ => {}
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        match self.focus {
+            IssueFormField::Title => {
+                self.title.pop();
+            }
+            IssueFormField::Parent => {
+                self.parent.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// Move the focused picker by `delta` (wraps around)
+    pub fn cycle(&mut self, delta: i32, teams: &[TeamInfo], projects: &[ProjectInfo]) {
+        match self.focus {
+            IssueFormField::Team if !teams.is_empty() => {
+                let idx = wrap(self.team_idx, delta, teams.len());
+                if idx != self.team_idx {
+                    // Labels belong to the team, projects to its workspace
+                    self.label_ids.clear();
+                    self.label_cursor = 0;
+                    if self.team(teams).map(|t| &t.workspace) != Some(&teams[idx].workspace) {
+                        self.project_idx = None;
+                    }
+                }
+                self.team_idx = idx;
+            }
+            IssueFormField::Priority => {
+                let idx = PRIORITIES
+                    .iter()
+                    .position(|p| *p == self.priority)
+                    .unwrap_or(0);
+                self.priority = PRIORITIES[wrap(idx, delta, PRIORITIES.len())];
+            }
+            IssueFormField::Project => {
+                // Slot 0 is "no project"
+                let current = self.project_idx.map(|i| i + 1).unwrap_or(0);
+                let count = self.projects(teams, projects).len();
+                let next = wrap(current, delta, count + 1);
+                self.project_idx = next.checked_sub(1);
+            }
+            IssueFormField::Labels => {
+                let count = self.team(teams).map(|t| t.labels.len()).unwrap_or(0);
+                if count > 0 {
+                    self.label_cursor = wrap(self.label_cursor, delta, count);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn toggle_label(&mut self, teams: &[TeamInfo]) {
+        let Some(label) = self
+            .team(teams)
+            .and_then(|t| t.labels.get(self.label_cursor))
+        else {
+            return;
+        };
+        if let Some(pos) = self.label_ids.iter().position(|id| *id == label.id) {
+            self.label_ids.remove(pos);
+        } else {
+            self.label_ids.push(label.id.clone());
+        }
+    }
+
+    pub fn team<'a>(&self, teams: &'a [TeamInfo]) -> Option<&'a TeamInfo> {
+        teams.get(self.team_idx)
+    }
+
+    /// Projects the picker offers: those in the selected team's workspace
+    pub fn projects<'a>(
+        &self,
+        teams: &[TeamInfo],
+        projects: &'a [ProjectInfo],
+    ) -> Vec<&'a ProjectInfo> {
+        let workspace = self.team(teams).map(|t| t.workspace.as_deref());
+        projects
+            .iter()
+            .filter(|p| workspace.is_none_or(|w| p.workspace.as_deref() == w))
+            .collect()
+    }
+
+    /// Project selected in the picker
+    pub fn project<'a>(
+        &self,
+        teams: &[TeamInfo],
+        projects: &'a [ProjectInfo],
+    ) -> Option<&'a ProjectInfo> {
+        let idx = self.project_idx?;
+        self.projects(teams, projects).get(idx).copied()
+    }
+
+    /// Validate the form and build the mutation input plus target workspace
+    pub fn build(
+        &self,
+        teams: &[TeamInfo],
+        projects: &[ProjectInfo],
+        workstreams: &[Workstream],
+    ) -> Result<(Option<String>, IssueCreate), String> {
+        let title = self.title.trim();
+        if title.is_empty() {
+            return Err("Title is required".to_string());
+        }
+        let team = self
+            .team(teams)
+            .ok_or_else(|| "No Linear teams loaded yet - try again after refresh".to_string())?;
+
+        let parent = self.parent.trim();
+        let parent_id = if parent.is_empty() {
+            None
+        } else {
+            let ws = workstreams
+                .iter()
+                .find(|ws| ws.linear_issue.identifier.eq_ignore_ascii_case(parent))
+                .ok_or_else(|| format!("Unknown parent issue {}", parent))?;
+            Some(ws.linear_issue.id.clone())
+        };
+
+        let input = IssueCreate {
+            team_id: team.id.clone(),
+            title: title.to_string(),
+            description: self.description.clone(),
+            priority: self.priority,
+            project_id: self.project(teams, projects).map(|p| p.id.clone()),
+            label_ids: self.label_ids.clone(),
+            parent_id,
+        };
+        Ok((team.workspace.clone(), input))
+    }
+}
+
+fn wrap(idx: usize, delta: i32, len: usize) -> usize {
+    (idx as i64 + delta as i64).rem_euclid(len as i64) as usize
+}
+
+/// First line of the last prompt, else a title derived from the branch name
+fn prefill_title(session: &AgentSession) -> String {
+    if let Some(line) = session
+        .activity
+        .last_prompt
+        .as_deref()
+        .and_then(|p| p.lines().map(str::trim).find(|l| !l.is_empty()))
+    {
+        return shorten_title(line);
+    }
+
+    session
+        .git_branch
+        .as_deref()
+        .filter(|b| !matches!(*b, "main" | "master" | "develop"))
+        .map(|branch| {
+            let name = branch.rsplit('/').next().unwrap_or(branch);
+            let words = name.replace(['-', '_'], " ");
+            let mut chars = words.trim().chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+}
+
+/// Cut long prompts at a word boundary
+fn shorten_title(line: &str) -> String {
+    if line.chars().count() <= MAX_PREFILL_TITLE {
+        return line.to_string();
+    }
+    let cut: String = line.chars().take(MAX_PREFILL_TITLE).collect();
+    let cut = match cut.rfind(' ') {
+        Some(pos) if pos > MAX_PREFILL_TITLE / 2 => &cut[..pos],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end())
+}
+
+fn prefill_description(session: &AgentSession) -> Option<String> {
+    let mut lines = Vec::new();
+    if let Some(prompt) = &session.activity.last_prompt {
+        lines.push(format!("> {}", prompt.trim()));
+        lines.push(String::new());
+    }
+    if let Some(branch) = &session.git_branch {
+        lines.push(format!("Branch: `{}`", branch));
+    }
+    if let Some(dir) = &session.working_directory {
+        lines.push(format!("Directory: `{}`", dir));
+    }
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
@@ -5,7 +5,7 @@
 
 use super::registry::BINDINGS;
 use super::{KeyPattern, Mode};
-use crate::data::{LinearPriority, SortMode};
+use crate::data::{DeploymentAction, GroupBy, LinearPriority, PrAction, SortMode};
 use crate::tui::input::InputState;
 use crate::tui::{App, Message};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -14,6 +14,8 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 fn current_mode(app: &App) -> Mode {
     if app.state.search_mode {
         Mode::Search
+    } else if app.show_issue_form() {
+        Mode::IssueForm
     } else if app.show_description_modal() {
         Mode::Description
     } else if app.show_help() {
@@ -24,6 +26,24 @@ fn current_mode(app: &App) -> Mode {
         Mode::SortMenu
     } else if app.show_filter_menu() {
         Mode::FilterMenu
+    } else if app.show_inbox() {
+        Mode::Inbox
+    } else if app.show_build_log() {
+        Mode::BuildLog
+    } else if app.show_job_log() {
+        Mode::JobLog
+    } else if app.show_deployments() {
+        if app.pending_deployment_confirmation().is_some() {
+            Mode::DeploymentActionConfirm
+        } else {
+            Mode::Deployments
+        }
+    } else if app.show_pr_actions() {
+        if app.pending_confirmation().is_some() {
+            Mode::PrActionConfirm
+        } else {
+            Mode::PrActions
+        }
     } else if app.show_link_menu() {
         if app.show_links_popup() {
             Mode::LinksPopup
@@ -64,7 +84,7 @@ pub fn dispatch(app: &App, input: &mut InputState, key: KeyEvent) -> Message {
     dispatch_single_key(app, mode, key)
 }
 
-/// Dispatch for text input modes (Search, ModalSearch).
+/// Dispatch for text input modes (Search, ModalSearch, IssueForm).
 fn dispatch_text_input(mode: Mode, key: KeyEvent) -> Message {
     match mode {
         Mode::Search => match key.code {
@@ -82,6 +102,17 @@ fn dispatch_text_input(mode: Mode, key: KeyEvent) -> Message {
             KeyCode::Char(c) => Message::ModalSearchInput(c),
             _ => Message::None,
         },
+        Mode::IssueForm => match key.code {
+            KeyCode::Esc => Message::CloseModal,
+            KeyCode::Enter => Message::SubmitIssueForm,
+            KeyCode::Tab | KeyCode::Down => Message::IssueFormNextField,
+            KeyCode::BackTab | KeyCode::Up => Message::IssueFormPrevField,
+            KeyCode::Left => Message::IssueFormCycle(-1),
+            KeyCode::Right => Message::IssueFormCycle(1),
+            KeyCode::Backspace => Message::IssueFormBackspace,
+            KeyCode::Char(c) => Message::IssueFormInput(c),
+            _ => Message::None,
+        },
         _ => Message::None,
     }
 }
@@ -95,26 +126,17 @@ fn check_chord_start(
 ) -> Option<Message> {
     // Only specific modes support chords
     match mode {
-        Mode::Normal => {
-            // 'g' starts gg chord
-            if key.code == KeyCode::Char('g') && key.modifiers.is_empty() {
-                input.set_pending(KeyCode::Char('g'));
-                return Some(Message::None);
-            }
-        }
-        Mode::Description => {
-            // 'g' starts gg chord for scroll to top
-            if key.code == KeyCode::Char('g') && key.modifiers.is_empty() {
-                input.set_pending(KeyCode::Char('g'));
-                return Some(Message::None);
-            }
+        // 'g' starts gg chord (scroll to top in description and logs)
+        Mode::Normal | Mode::Description | Mode::JobLog | Mode::BuildLog
+            if key.code == KeyCode::Char('g') && key.modifiers.is_empty() =>
+        {
+            input.set_pending(KeyCode::Char('g'));
+            return Some(Message::None);
         }
-        Mode::LinkMenu => {
-            // 'd' starts d1-d9 chord (or opens description on timeout)
-            if key.modifiers.is_empty() && key.code == KeyCode::Char('d') {
-                input.set_pending(KeyCode::Char('d'));
-                return Some(Message::None);
-            }
+        // 'd' starts d1-d9 chord (or opens description on timeout)
+        Mode::LinkMenu if key.modifiers.is_empty() && key.code == KeyCode::Char('d') => {
+            input.set_pending(KeyCode::Char('d'));
+            return Some(Message::None);
         }
         Mode::FilterMenu => {
             // 'p' starts p0-p9 chord for project filters
@@ -127,6 +149,11 @@ fn check_chord_start(
                 input.set_pending(KeyCode::Char('s'));
                 return Some(Message::None);
             }
+            // 'w' starts w0-w9 chord for workspace filters
+            if key.modifiers.is_empty() && key.code == KeyCode::Char('w') {
+                input.set_pending(KeyCode::Char('w'));
+                return Some(Message::None);
+            }
         }
         _ => {}
     }
@@ -136,10 +163,14 @@ fn check_chord_start(
 /// Handle the second key of a chord sequence.
 fn handle_chord(app: &App, first: KeyCode, second: KeyCode) -> Message {
     match (first, second) {
-        // gg -> go to top (works in normal mode and description modal)
+        // gg -> go to top (works in normal mode, description modal and job log)
         (KeyCode::Char('g'), KeyCode::Char('g')) => {
             if app.show_description_modal() {
                 Message::ScrollDescription(-10000) // Jump to top
+            } else if app.show_job_log() {
+                Message::ScrollJobLog(-i32::MAX) // Start of the log
+            } else if app.show_build_log() {
+                Message::ScrollBuildLog(-i32::MAX)
             } else {
                 Message::GotoTop
             }
@@ -175,6 +206,16 @@ fn handle_chord(app: &App, first: KeyCode, second: KeyCode) -> Message {
             }
         }
 
+        // w0-w9 -> workspace filters (in filter menu)
+        (KeyCode::Char('w'), KeyCode::Char(c)) if c.is_ascii_digit() && app.show_filter_menu() => {
+            let digit = c.to_digit(10).unwrap() as usize;
+            if digit == 0 {
+                Message::ClearWorkspaceFilters
+            } else {
+                Message::ToggleWorkspaceFilter(digit - 1)
+            }
+        }
+
         // d + non-digit -> open description (in link menu)
         (KeyCode::Char('d'), _) if app.show_link_menu() => Message::OpenDescriptionModal,
 
@@ -268,6 +309,13 @@ fn binding_to_message(
         Mode::FilterMenu => match_filter_menu(key),
         Mode::LinkMenu => match_link_menu(app, key),
         Mode::LinksPopup => match_links_popup(key),
+        Mode::Inbox => match_inbox(key),
+        Mode::PrActions => match_pr_actions(app, key),
+        Mode::PrActionConfirm => match_pr_action_confirm(key),
+        Mode::JobLog => match_job_log(key),
+        Mode::BuildLog => match_build_log(key),
+        Mode::Deployments => match_deployments(key),
+        Mode::DeploymentActionConfirm => match_deployment_action_confirm(key),
         _ => None,
     };
     result.unwrap_or_else(|| {
@@ -304,6 +352,9 @@ fn match_normal_mode(key: &KeyEvent) -> Option<Message> {
         KeyCode::Char('t') => Message::TeleportToSession,
         KeyCode::Char('p') => Message::TogglePreview,
         KeyCode::Char('r') => Message::Refresh,
+        KeyCode::Char('n') => Message::OpenIssueForm,
+        KeyCode::Char('i') => Message::ToggleInbox,
+        KeyCode::Char('b') => Message::OpenBuildLog,
         KeyCode::Char('?') => Message::ToggleHelp,
         KeyCode::Char('z') => Message::ToggleSectionFold,
         KeyCode::Left => Message::CollapseSection,
@@ -368,6 +419,7 @@ fn match_sort_menu(key: &KeyEvent) -> Option<Message> {
                 return None;
             }
         }
+        KeyCode::Char(c) => Message::SetGroupBy(GroupBy::from_key(c)?),
         _ => return None,
     })
 }
@@ -388,6 +440,8 @@ fn match_filter_menu(key: &KeyEvent) -> Option<Message> {
         KeyCode::Char('t') => Message::ToggleSubIssues,
         KeyCode::Char('d') => Message::ToggleCompletedFilter,
         KeyCode::Char('x') => Message::ToggleCanceledFilter,
+        KeyCode::Char('b') => Message::CycleBlockedMode,
+        KeyCode::Char('o') => Message::ToggleOverdueFilter,
         KeyCode::Char('a') => Message::SelectAllFilters,
         KeyCode::Char('c') => Message::ClearAllFilters,
         _ => return None,
@@ -411,6 +465,10 @@ fn match_link_menu(app: &App, key: &KeyEvent) -> Option<Message> {
         KeyCode::Char('o') | KeyCode::Enter => Message::NavigateToSelectedChild,
         KeyCode::Char('l') => Message::OpenLinksPopup,
         KeyCode::Char('t') => Message::TeleportToSession,
+        KeyCode::Char('a') => Message::SendReviewCommentsToAgent,
+        KeyCode::Char('p') => Message::OpenPrActions,
+        KeyCode::Char('c') => Message::OpenJobLog,
+        KeyCode::Char('v') => Message::OpenDeployments,
         _ => return None,
     })
 }
@@ -423,6 +481,108 @@ fn match_links_popup(key: &KeyEvent) -> Option<Message> {
         KeyCode::Char('2') => Message::OpenGithubLink,
         KeyCode::Char('3') => Message::OpenVercelLink,
         KeyCode::Char('4') => Message::TeleportToSession,
+        KeyCode::Char(c @ '5'..='9') => Message::OpenPrLink(c as usize - '4' as usize),
+        _ => return None,
+    })
+}
+
+/// Match inbox keys to messages.
+fn match_inbox(key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('i') => Message::CloseModal,
+        KeyCode::Char('j') | KeyCode::Down => Message::MoveInboxSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Message::MoveInboxSelection(-1),
+        KeyCode::Char('o') | KeyCode::Enter => Message::OpenNotification,
+        KeyCode::Char('m') => Message::MarkNotificationRead,
+        KeyCode::Char('a') => Message::MarkAllNotificationsRead,
+        _ => return None,
+    })
+}
+
+/// Match PR actions popup keys to messages.
+fn match_pr_actions(app: &App, key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('p') => Message::CancelPrAction,
+        KeyCode::Char('j') | KeyCode::Down => Message::MovePrActionSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Message::MovePrActionSelection(-1),
+        KeyCode::Tab => Message::CycleMergeMethod,
+        KeyCode::Char('r') => Message::RequestPrAction(PrAction::MarkReady),
+        KeyCode::Char('m') => Message::RequestPrAction(PrAction::Merge(app.merge_method)),
+        KeyCode::Char('a') => Message::RequestPrAction(PrAction::EnableAutoMerge(app.merge_method)),
+        KeyCode::Char('v') => Message::RequestPrAction(PrAction::RerequestReviews),
+        KeyCode::Char('x') => Message::RequestPrAction(PrAction::Close),
+        _ => return None,
+    })
+}
+
+/// Match PR action confirmation keys to messages.
+fn match_pr_action_confirm(key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Char('y') | KeyCode::Enter => Message::ConfirmPrAction,
+        KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('q') => Message::CancelPrAction,
+        _ => return None,
+    })
+}
+
+/// Match deployments popup keys to messages.
+fn match_deployments(key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('v') => Message::CancelDeploymentAction,
+        KeyCode::Char('j') | KeyCode::Down => Message::MoveDeploymentSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Message::MoveDeploymentSelection(-1),
+        KeyCode::Char('r') => Message::RequestDeploymentAction(DeploymentAction::Redeploy),
+        KeyCode::Char('x') => Message::RequestDeploymentAction(DeploymentAction::Cancel),
+        KeyCode::Char('p') => Message::RequestDeploymentAction(DeploymentAction::Promote),
+        KeyCode::Char('o') | KeyCode::Enter => Message::OpenSelectedDeployment,
+        _ => return None,
+    })
+}
+
+/// Match deployment action confirmation keys to messages.
+fn match_deployment_action_confirm(key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Char('y') | KeyCode::Enter => Message::ConfirmDeploymentAction,
+        KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('q') => Message::CancelDeploymentAction,
+        _ => return None,
+    })
+}
+
+/// Match job log keys to messages.
+fn match_job_log(key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('c') => Message::CloseJobLog,
+        KeyCode::Char('j') | KeyCode::Down => Message::ScrollJobLog(1),
+        KeyCode::Char('k') | KeyCode::Up => Message::ScrollJobLog(-1),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Message::ScrollJobLog(20)
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Message::ScrollJobLog(-20)
+        }
+        KeyCode::Char('G') => Message::ScrollJobLog(i32::MAX),
+        KeyCode::Tab => Message::NextJobLog,
+        KeyCode::Char('a') => Message::SendJobLogToAgent,
+        KeyCode::Char('o') => Message::OpenJobInBrowser,
+        _ => return None,
+    })
+}
+
+/// Match build log keys to messages.
+fn match_build_log(key: &KeyEvent) -> Option<Message> {
+    Some(match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => Message::CloseBuildLog,
+        KeyCode::Char('j') | KeyCode::Down => Message::ScrollBuildLog(1),
+        KeyCode::Char('k') | KeyCode::Up => Message::ScrollBuildLog(-1),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Message::ScrollBuildLog(20)
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Message::ScrollBuildLog(-20)
+        }
+        KeyCode::Char('G') => Message::ScrollBuildLog(i32::MAX),
+        KeyCode::Char('e') => Message::JumpToBuildError,
+        KeyCode::Char('a') => Message::SendBuildLogToAgent,
+        KeyCode::Char('o') => Message::OpenDeploymentInBrowser,
         _ => return None,
     })
 }
@@ -7,15 +7,27 @@ use super::{Category, KeyPattern, Mode};
 /// Returns a string like "l: links | /: search | Esc: back"
 pub fn generate_footer_hints(mode: Mode) -> &'static str {
     match mode {
-        Mode::LinkMenu => "  j/k: nav | o: enter | l: links | /: search | d: desc | Esc: back",
-        Mode::LinksPopup => "  1-4: open link | l/Esc: close",
+        Mode::LinkMenu => {
+            "  j/k: nav | o: enter | l: links | /: search | d: desc | p: PR actions | c: CI log | v: deploys | a: review → agent | Esc: back"
+        }
+        Mode::LinksPopup => "  1-4: open link | 5-9: other PRs | l/Esc: close",
         Mode::Description => "  j/k: scroll | gg/G: top/bottom | Esc: close",
         Mode::Help => "  1/2: tabs | Esc: close",
-        Mode::SortMenu => "  1-6: select | Esc: close",
-        Mode::FilterMenu => "  0-9: cycles | p0-9: projects | s0-8: assignees (s9=all) | u/h/m/l/n: priority | Esc: close",
+        Mode::SortMenu => "  1-8: sort | n/p/c/t/l/a/r: group | Esc: close",
+        Mode::FilterMenu => "  0-9: cycles | p0-9: projects | s0-8: assignees (s9=all) | u/h/m/l/n: priority | b: blocked | o: overdue | Esc: close",
         Mode::Resize => "  h/l: width | Tab: column | Esc: done",
+        Mode::Inbox => "  j/k: nav | o: open | m: mark read | a: mark all read | Esc: close",
+        Mode::PrActions => "  j/k: PR | r: ready | m: merge | a: auto-merge | Tab: method | v: re-request | x: close PR | Esc: back",
+        Mode::PrActionConfirm => "  y: confirm | n/Esc: cancel",
+        Mode::JobLog => "  j/k: scroll | gg/G: top/tail | Tab: next job | a: send to agent | o: open | Esc: back",
+        Mode::Deployments => "  j/k: deployment | r: redeploy | x: cancel | p: promote | o: open | Esc: back",
+        Mode::DeploymentActionConfirm => "  y: confirm | n/Esc: cancel",
+        Mode::BuildLog => "  j/k: scroll | gg/G: top/tail | e: first error | a: send to agent | o: open | Esc: close",
         Mode::Normal => "  j/k: nav | o: details | l: links | /: search | ?: help",
         Mode::Search | Mode::ModalSearch => "  Enter: confirm | Esc: cancel",
+        Mode::IssueForm => {
+            "  Tab: next field | ←/→: choose | Space: toggle label | Enter: create | Esc: cancel"
+        }
     }
 }
 
@@ -54,6 +66,9 @@ pub fn generate_keyboard_shortcuts() -> Vec<&'static str> {
         "  s            Open sort menu",
         "  f            Open filter menu",
         "  r            Refresh data",
+        "  n            New Linear issue (links unlinked sessions)",
+        "  i            Linear inbox (notifications)",
+        "  b            Vercel build log",
         "",
         "  q            Quit",
         "  ?            Toggle this help",
@@ -36,12 +36,28 @@ pub enum Mode {
     LinkMenu,
     /// Links popup (nested within link menu)
     LinksPopup,
+    /// Create-issue form
+    IssueForm,
+    /// Linear inbox popup
+    Inbox,
+    /// PR actions popup (nested within link menu)
+    PrActions,
+    /// Confirmation prompt for a PR action
+    PrActionConfirm,
+    /// Failing CI job log (nested within link menu)
+    JobLog,
+    /// Vercel build log popup
+    BuildLog,
+    /// Deployment history popup (nested within link menu)
+    Deployments,
+    /// Confirmation prompt for a deployment action
+    DeploymentActionConfirm,
 }
 
 impl Mode {
     /// Returns true if this mode accepts text input (chars are not dispatched as commands).
     pub fn is_text_input(&self) -> bool {
-        matches!(self, Mode::Search | Mode::ModalSearch)
+        matches!(self, Mode::Search | Mode::ModalSearch | Mode::IssueForm)
     }
 }
 
@@ -180,6 +180,30 @@ pub static BINDINGS: &[KeyBinding] = &[
         alternatives: &[],
         show_in_help: true,
     },
+    KeyBinding {
+        modes: &[Mode::Normal],
+        pattern: KeyPattern::Single(KeyCode::Char('n')),
+        description: "New Linear issue",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: true,
+    },
+    KeyBinding {
+        modes: &[Mode::Normal],
+        pattern: KeyPattern::Single(KeyCode::Char('i')),
+        description: "Open Linear inbox",
+        category: Category::Modals,
+        alternatives: &[],
+        show_in_help: true,
+    },
+    KeyBinding {
+        modes: &[Mode::Normal],
+        pattern: KeyPattern::Single(KeyCode::Char('b')),
+        description: "Open Vercel build log",
+        category: Category::Modals,
+        alternatives: &[],
+        show_in_help: true,
+    },
     // Modals
     KeyBinding {
         modes: &[Mode::Normal],
@@ -423,12 +447,27 @@ pub static BINDINGS: &[KeyBinding] = &[
     },
     KeyBinding {
         modes: &[Mode::SortMenu],
-        pattern: KeyPattern::DigitRange(1..=6),
+        pattern: KeyPattern::DigitRange(1..=8),
         description: "Select sort mode",
         category: Category::Actions,
         alternatives: &[],
         show_in_help: false,
     },
+    KeyBinding {
+        modes: &[Mode::SortMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('n')),
+        description: "Select grouping",
+        category: Category::Actions,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Char('p')),
+            KeyPattern::Single(KeyCode::Char('c')),
+            KeyPattern::Single(KeyCode::Char('t')),
+            KeyPattern::Single(KeyCode::Char('l')),
+            KeyPattern::Single(KeyCode::Char('a')),
+            KeyPattern::Single(KeyCode::Char('r')),
+        ],
+        show_in_help: false,
+    },
     // ═══════════════════════════════════════════════════════════════════════════
     // FILTER MENU
     // ═══════════════════════════════════════════════════════════════════════════
@@ -512,6 +551,22 @@ pub static BINDINGS: &[KeyBinding] = &[
         alternatives: &[],
         show_in_help: false,
     },
+    KeyBinding {
+        modes: &[Mode::FilterMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('b')),
+        description: "Cycle blocked issues (show/demote/hide)",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::FilterMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('o')),
+        description: "Toggle overdue issues only",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
     KeyBinding {
         modes: &[Mode::FilterMenu],
         pattern: KeyPattern::Single(KeyCode::Char('a')),
@@ -587,6 +642,38 @@ pub static BINDINGS: &[KeyBinding] = &[
         alternatives: &[],
         show_in_help: false,
     },
+    KeyBinding {
+        modes: &[Mode::LinkMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('a')),
+        description: "Send unresolved review comments to agent",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::LinkMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('p')),
+        description: "PR actions",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::LinkMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('c')),
+        description: "Failing CI job log",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::LinkMenu],
+        pattern: KeyPattern::Single(KeyCode::Char('v')),
+        description: "Vercel deployments",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
     // Chord starter for documents
     KeyBinding {
         modes: &[Mode::LinkMenu],
@@ -645,6 +732,436 @@ pub static BINDINGS: &[KeyBinding] = &[
         alternatives: &[],
         show_in_help: false,
     },
+    KeyBinding {
+        modes: &[Mode::LinksPopup],
+        pattern: KeyPattern::DigitRange(5..=9),
+        description: "Open another linked PR",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    // ═══════════════════════════════════════════════════════════════════════════
+    // LINEAR INBOX
+    // ═══════════════════════════════════════════════════════════════════════════
+    KeyBinding {
+        modes: &[Mode::Inbox],
+        pattern: KeyPattern::Single(KeyCode::Esc),
+        description: "Close inbox",
+        category: Category::Modals,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Char('q')),
+            KeyPattern::Single(KeyCode::Char('i')),
+        ],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Inbox],
+        pattern: KeyPattern::Single(KeyCode::Char('j')),
+        description: "Next notification",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Down)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Inbox],
+        pattern: KeyPattern::Single(KeyCode::Char('k')),
+        description: "Previous notification",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Up)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Inbox],
+        pattern: KeyPattern::Single(KeyCode::Enter),
+        description: "Open notification",
+        category: Category::Actions,
+        alternatives: &[KeyPattern::Single(KeyCode::Char('o'))],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Inbox],
+        pattern: KeyPattern::Single(KeyCode::Char('m')),
+        description: "Mark read",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Inbox],
+        pattern: KeyPattern::Single(KeyCode::Char('a')),
+        description: "Mark all read",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    // ═══════════════════════════════════════════════════════════════════════════
+    // PR ACTIONS (nested within link menu)
+    // ═══════════════════════════════════════════════════════════════════════════
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Esc),
+        description: "Close PR actions",
+        category: Category::Modals,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Char('q')),
+            KeyPattern::Single(KeyCode::Char('p')),
+        ],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('j')),
+        description: "Next PR",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Down)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('k')),
+        description: "Previous PR",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Up)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('r')),
+        description: "Mark ready for review",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('m')),
+        description: "Merge",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('a')),
+        description: "Enable auto-merge",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Tab),
+        description: "Cycle merge method",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('v')),
+        description: "Re-request reviews",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActions],
+        pattern: KeyPattern::Single(KeyCode::Char('x')),
+        description: "Close PR",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActionConfirm],
+        pattern: KeyPattern::Single(KeyCode::Char('y')),
+        description: "Confirm PR action",
+        category: Category::Actions,
+        alternatives: &[KeyPattern::Single(KeyCode::Enter)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::PrActionConfirm],
+        pattern: KeyPattern::Single(KeyCode::Char('n')),
+        description: "Cancel PR action",
+        category: Category::Actions,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Esc),
+            KeyPattern::Single(KeyCode::Char('q')),
+        ],
+        show_in_help: false,
+    },
+    // ═══════════════════════════════════════════════════════════════════════════
+    // VERCEL DEPLOYMENTS (nested within link menu)
+    // ═══════════════════════════════════════════════════════════════════════════
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Esc),
+        description: "Close deployments",
+        category: Category::Modals,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Char('q')),
+            KeyPattern::Single(KeyCode::Char('v')),
+        ],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Char('j')),
+        description: "Next deployment",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Down)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Char('k')),
+        description: "Previous deployment",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Up)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Char('r')),
+        description: "Redeploy",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Char('x')),
+        description: "Cancel build",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Char('p')),
+        description: "Promote to production",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::Deployments],
+        pattern: KeyPattern::Single(KeyCode::Char('o')),
+        description: "Open deployment in browser",
+        category: Category::Actions,
+        alternatives: &[KeyPattern::Single(KeyCode::Enter)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::DeploymentActionConfirm],
+        pattern: KeyPattern::Single(KeyCode::Char('y')),
+        description: "Confirm deployment action",
+        category: Category::Actions,
+        alternatives: &[KeyPattern::Single(KeyCode::Enter)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::DeploymentActionConfirm],
+        pattern: KeyPattern::Single(KeyCode::Char('n')),
+        description: "Cancel deployment action",
+        category: Category::Actions,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Esc),
+            KeyPattern::Single(KeyCode::Char('q')),
+        ],
+        show_in_help: false,
+    },
+    // ═══════════════════════════════════════════════════════════════════════════
+    // CI JOB LOG (nested within link menu)
+    // ═══════════════════════════════════════════════════════════════════════════
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Esc),
+        description: "Close job log",
+        category: Category::Modals,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Char('q')),
+            KeyPattern::Single(KeyCode::Char('c')),
+        ],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Char('j')),
+        description: "Scroll down",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Down)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Char('k')),
+        description: "Scroll up",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Up)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::WithModifier {
+            key: KeyCode::Char('d'),
+            mods: KeyModifiers::CONTROL,
+        },
+        description: "Page down",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::WithModifier {
+            key: KeyCode::Char('u'),
+            mods: KeyModifiers::CONTROL,
+        },
+        description: "Page up",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Char('G')),
+        description: "Jump to end of log",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Chord {
+            first: KeyCode::Char('g'),
+            second: KeyCode::Char('g'),
+        },
+        description: "Jump to start of log",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Tab),
+        description: "Next failing job",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Char('a')),
+        description: "Send failure to agent",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::JobLog],
+        pattern: KeyPattern::Single(KeyCode::Char('o')),
+        description: "Open job in browser",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    // ═══════════════════════════════════════════════════════════════════════════
+    // VERCEL BUILD LOG
+    // ═══════════════════════════════════════════════════════════════════════════
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Esc),
+        description: "Close build log",
+        category: Category::Modals,
+        alternatives: &[
+            KeyPattern::Single(KeyCode::Char('q')),
+            KeyPattern::Single(KeyCode::Char('b')),
+        ],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Char('j')),
+        description: "Scroll down",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Down)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Char('k')),
+        description: "Scroll up",
+        category: Category::Navigation,
+        alternatives: &[KeyPattern::Single(KeyCode::Up)],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::WithModifier {
+            key: KeyCode::Char('d'),
+            mods: KeyModifiers::CONTROL,
+        },
+        description: "Page down",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::WithModifier {
+            key: KeyCode::Char('u'),
+            mods: KeyModifiers::CONTROL,
+        },
+        description: "Page up",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Char('G')),
+        description: "Jump to end of log",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Chord {
+            first: KeyCode::Char('g'),
+            second: KeyCode::Char('g'),
+        },
+        description: "Jump to start of log",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Char('e')),
+        description: "Jump to first error",
+        category: Category::Navigation,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Char('a')),
+        description: "Send failure to agent",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
+    KeyBinding {
+        modes: &[Mode::BuildLog],
+        pattern: KeyPattern::Single(KeyCode::Char('o')),
+        description: "Open deployment in browser",
+        category: Category::Actions,
+        alternatives: &[],
+        show_in_help: false,
+    },
     // ═══════════════════════════════════════════════════════════════════════════
     // MODAL SEARCH (within link menu)
     // ═══════════════════════════════════════════════════════════════════════════
@@ -3,7 +3,7 @@
 //! All possible user actions in the application are represented as messages.
 //! This enables unidirectional data flow and testable update logic.
 
-use crate::data::{LinearPriority, SortMode};
+use crate::data::{DeploymentAction, GroupBy, LinearPriority, PrAction, SortMode};
 
 /// All possible user actions in the application.
 ///
@@ -50,6 +50,8 @@ pub enum Message {
     OpenLinkMenu,
     /// Teleport to Claude session
     TeleportToSession,
+    /// Hand the PR's unresolved review comments to the linked agent session
+    SendReviewCommentsToAgent,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Search mode
@@ -92,6 +94,104 @@ pub enum Message {
     // ─────────────────────────────────────────────────────────────────────────
     /// Set sort mode
     SetSortMode(SortMode),
+    /// Set grouping dimension for the issue sections
+    SetGroupBy(GroupBy),
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Create-issue form
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Open the quick-capture form (prefilled on unlinked session rows)
+    OpenIssueForm,
+    /// Type a character into the focused field
+    IssueFormInput(char),
+    /// Delete the last character of the focused field
+    IssueFormBackspace,
+    /// Focus the next field
+    IssueFormNextField,
+    /// Focus the previous field
+    IssueFormPrevField,
+    /// Change the focused picker (team, priority, project, label cursor)
+    IssueFormCycle(i32),
+    /// Create the issue in Linear
+    SubmitIssueForm,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Linear inbox
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Toggle the Linear inbox modal
+    ToggleInbox,
+    /// Move the inbox selection by delta
+    MoveInboxSelection(i32),
+    /// Mark the selected notification read and jump to its issue
+    OpenNotification,
+    /// Mark the selected notification read
+    MarkNotificationRead,
+    /// Mark every notification read
+    MarkAllNotificationsRead,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // PR actions
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Open the PR actions popup for the issue in the details modal
+    OpenPrActions,
+    /// Move the selection between the issue's linked PRs by delta
+    MovePrActionSelection(i32),
+    /// Cycle the merge method used by merge and auto-merge
+    CycleMergeMethod,
+    /// Ask to confirm an action on the selected PR
+    RequestPrAction(PrAction),
+    /// Run the action being confirmed
+    ConfirmPrAction,
+    /// Back out of the confirmation, or close the popup
+    CancelPrAction,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // CI job logs
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Open the log of the details issue's first failing Actions job
+    OpenJobLog,
+    /// Show the next failing job's log
+    NextJobLog,
+    /// Scroll the job log by delta lines (positive = toward the end)
+    ScrollJobLog(i32),
+    /// Hand the failure excerpt to the linked agent session
+    SendJobLogToAgent,
+    /// Open the failing job in the browser
+    OpenJobInBrowser,
+    /// Close the job log, back to the issue details
+    CloseJobLog,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Vercel deployments
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Open the details issue's recent Vercel deployments
+    OpenDeployments,
+    /// Move the selection in the deployments popup
+    MoveDeploymentSelection(i32),
+    /// Ask to confirm an action on the selected deployment
+    RequestDeploymentAction(DeploymentAction),
+    /// Run the action being confirmed
+    ConfirmDeploymentAction,
+    /// Back out of the confirmation, or close the popup
+    CancelDeploymentAction,
+    /// Open the selected deployment in the browser
+    OpenSelectedDeployment,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Vercel build logs
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Open the build log of the selected issue's Vercel deployment
+    OpenBuildLog,
+    /// Scroll the build log by delta lines (positive = toward the end)
+    ScrollBuildLog(i32),
+    /// Scroll to the first error line
+    JumpToBuildError,
+    /// Hand the output from the first error on to the linked agent session
+    SendBuildLogToAgent,
+    /// Open the deployment in the browser
+    OpenDeploymentInBrowser,
+    /// Close the build log
+    CloseBuildLog,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Filter modal
@@ -108,12 +208,20 @@ pub enum Message {
     ToggleAssigneeFilter(usize),
     /// Clear assignee filters
     ClearAssigneeFilters,
+    /// Toggle a Linear workspace filter by index
+    ToggleWorkspaceFilter(usize),
+    /// Clear workspace filters
+    ClearWorkspaceFilters,
     /// Toggle showing sub-issues
     ToggleSubIssues,
     /// Toggle showing completed issues
     ToggleCompletedFilter,
     /// Toggle showing canceled issues
     ToggleCanceledFilter,
+    /// Cycle how blocked issues are listed (show, demote, hide)
+    CycleBlockedMode,
+    /// Toggle showing only overdue issues (past due date or SLA)
+    ToggleOverdueFilter,
     /// Clear all filters (show all)
     ClearAllFilters,
     /// Select all filters
@@ -144,6 +252,8 @@ pub enum Message {
     OpenLinearLink,
     /// Open GitHub link
     OpenGithubLink,
+    /// Open one of the issue's other linked PRs (index into its linked PRs)
+    OpenPrLink(usize),
     /// Open Vercel link
     OpenVercelLink,
 
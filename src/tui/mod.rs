@@ -1,5 +1,6 @@
 mod app;
 pub mod input;
+pub mod issue_form;
 pub mod keybindings;
 mod message;
 pub mod search;
@@ -38,6 +39,12 @@ pub async fn run(config: Config) -> Result<()> {
     // Initial data fetch (non-blocking - UI shows immediately with loading state)
     app.start_background_refresh();
 
+    // Realtime Linear updates (no-op unless [webhook] is enabled)
+    app.start_webhook_listener();
+
+    // Share sessions with teammates (no-op unless [team] is enabled)
+    app.start_team_share();
+
     let result = run_app(&mut terminal, &mut app).await;
 
     // Restore terminal
@@ -106,6 +113,30 @@ async fn run_app(
             // Poll for background refresh results (non-blocking)
             app.poll_refresh();
 
+            // Apply pushed Linear webhook deliveries
+            app.poll_webhook();
+
+            // Publish our sessions and merge teammates' snapshots
+            app.poll_team_share();
+
+            // Pick up the result of a quick-capture issue creation
+            app.poll_issue_create();
+
+            // Confirm (or report) notifications marked read in the inbox
+            app.poll_inbox();
+
+            // Apply the result of PR actions (merge, close, ...)
+            app.poll_pr_actions();
+
+            // Show CI job logs once they've downloaded
+            app.poll_job_logs();
+
+            // Show Vercel build logs, and keep fetching while the build runs
+            app.poll_build_logs();
+
+            // Apply deployment histories and the result of deployment actions
+            app.poll_deployments();
+
             // Poll unified watcher for real-time agent session updates
             app.poll_unified_watcher();
 
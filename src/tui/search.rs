@@ -348,8 +348,9 @@ impl FuzzySearch {
             }
         }
 
-        // GitHub PR (content field - branch names are meaningful)
-        if let Some(pr) = &ws.github_pr {
+        // GitHub PRs (content field - branch names are meaningful)
+        for linked in ws.all_linked_prs() {
+            let pr = &linked.pr;
             let pr_text = format!("PR#{} {}", pr.number, pr.branch);
             if let Some((base_score, match_type)) = self.multi_term_match(query, &pr_text) {
                 let score = Self::calculate_score(base_score, match_type, 300)
@@ -7,6 +7,9 @@ pub const HEADER_PR: &str = ""; // nf-dev-github_badge
 pub const HEADER_AGENT: &str = "󰚩"; // nf-md-robot
 pub const HEADER_VERCEL: &str = "▲"; // Vercel triangle
 pub const HEADER_TIME: &str = "󰥔"; // nf-md-clock_outline
+pub const HEADER_DUE: &str = "󰃯"; // nf-md-calendar_check
+pub const HEADER_OWNER: &str = ""; // nf-fa-user
+pub const HEADER_CI: &str = "󰙨"; // nf-md-test_tube
 
 // Priority icons (signal bar style)
 pub const PRIORITY_NONE: &str = "╌╌╌"; // Gray dashes - no priority
@@ -63,6 +66,7 @@ pub const COLLAPSED: &str = "▶";
 // Issue detail category icons
 pub const ICON_TEAM: &str = "󰏬"; // nf-md-account_group
 pub const ICON_PROJECT: &str = "󰈙"; // nf-md-folder
+pub const ICON_WORKSPACE: &str = "󰘚"; // nf-md-domain
 pub const ICON_CYCLE: &str = "󰃰"; // nf-md-calendar_clock
 pub const ICON_ESTIMATE: &str = "󰎚"; // nf-md-numeric
 pub const ICON_LABELS: &str = "󰌕"; // nf-md-tag_multiple
@@ -71,6 +75,23 @@ pub const ICON_UPDATED: &str = "󰦒"; // nf-md-calendar_edit
 pub const ICON_DOCUMENT: &str = "󰈚"; // nf-md-file_document
 pub const ICON_PARENT: &str = "󰁝"; // nf-md-arrow_up_bold
 pub const ICON_CHILDREN: &str = "󰁅"; // nf-md-arrow_down_bold
+pub const ICON_RELATIONS: &str = "󰌹"; // nf-md-link_variant
+pub const BLOCKED: &str = "󰌾"; // nf-md-lock
+pub const INBOX: &str = "󰂚"; // nf-md-bell
+
+// CI check status
+pub const CI_PASSING: &str = "󰄬"; // nf-md-check
+pub const CI_FAILING: &str = "󰅚"; // nf-md-close_circle
+pub const CI_PENDING: &str = "󰔟"; // nf-md-timer_sand
+pub const CI_NONE: &str = "󰝦"; // nf-md-minus_circle_outline
+
+// PR review threads
+pub const REVIEW_THREADS: &str = ""; // nf-fa-comments
+
+// Due date badges
+pub const DUE_OVERDUE: &str = "󰀠"; // nf-md-alarm
+pub const DUE_SOON: &str = "󰔟"; // nf-md-timer_sand
+pub const DUE_UPCOMING: &str = "󰃭"; // nf-md-calendar
 
 // Tool activity icons (for agent status display)
 pub const TOOL_READ: &str = "󰈙"; // nf-md-file_document
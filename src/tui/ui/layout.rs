@@ -25,8 +25,8 @@ fn get_padding(width: usize) -> &'static str {
 }
 
 use crate::tui::app::{
-    COL_IDX_AGENT, COL_IDX_ID, COL_IDX_PR, COL_IDX_PRIORITY, COL_IDX_STATUS, COL_IDX_TIME,
-    COL_IDX_TITLE, COL_IDX_VERCEL, NUM_COLUMNS,
+    COL_IDX_AGENT, COL_IDX_CI, COL_IDX_DUE, COL_IDX_ID, COL_IDX_OWNER, COL_IDX_PR,
+    COL_IDX_PRIORITY, COL_IDX_STATUS, COL_IDX_TIME, COL_IDX_TITLE, COL_IDX_VERCEL, NUM_COLUMNS,
 };
 
 // Layout constants
@@ -35,10 +35,13 @@ pub const PREFIX_WIDTH: usize = 2;
 pub const SEP: &str = " │ ";
 pub const SEP_WIDTH: usize = 3;
 
-pub const COL_MIN_WIDTHS: [usize; NUM_COLUMNS] = [1, 3, 6, 12, 8, 14, 3, 6];
-pub const COL_HIDE_ORDER: [usize; 6] = [
+pub const COL_MIN_WIDTHS: [usize; NUM_COLUMNS] = [1, 3, 6, 12, 8, 14, 3, 6, 8, 6, 3];
+pub const COL_HIDE_ORDER: [usize; 9] = [
     COL_IDX_TIME,
+    COL_IDX_DUE,
     COL_IDX_VERCEL,
+    COL_IDX_CI,
+    COL_IDX_OWNER,
     COL_IDX_AGENT,
     COL_IDX_PR,
     COL_IDX_PRIORITY,
@@ -74,6 +77,8 @@ pub fn compute_column_layout(
     }
 
     let mut visible = [true; NUM_COLUMNS];
+    // Owner only shows in the team view (width 0 otherwise)
+    visible[COL_IDX_OWNER] = preferred[COL_IDX_OWNER] > 0;
     let mut min_total = min_total_width(&visible);
 
     for &idx in &COL_HIDE_ORDER {
@@ -408,3 +413,18 @@ pub fn truncate_str(s: &str, max_len: usize) -> String {
     let truncated = truncate_to_width(s, max_len.saturating_sub(3));
     format!("{truncated}...")
 }
+
+/// Short age of a timestamp ("5m", "3h", "2d")
+pub fn short_age(
+    created_at: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let age = now.signed_duration_since(created_at);
+    if age.num_hours() < 1 {
+        format!("{}m", age.num_minutes().max(0))
+    } else if age.num_days() < 1 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}d", age.num_days())
+    }
+}
@@ -2,7 +2,7 @@
 
 use super::layout::{fit_lines_to_area, popup_rect, truncate_str};
 use super::status::{priority_config, StatusConfig};
-use crate::data::LinearPriority;
+use crate::data::{BlockedMode, LinearPriority};
 use crate::tui::App;
 use ratatui::{
     style::{Color, Modifier, Style},
@@ -12,9 +12,9 @@ use ratatui::{
 };
 
 pub fn draw_sort_menu(f: &mut Frame, app: &App) {
-    use crate::data::SortMode;
+    use crate::data::{GroupBy, SortMode};
 
-    let area = popup_rect(50, 45, 34, 12, f.area());
+    let area = popup_rect(50, 70, 52, 24, f.area());
 
     f.render_widget(Clear, area);
 
@@ -51,6 +51,8 @@ pub fn draw_sort_menu(f: &mut Frame, app: &App) {
             "",
             "PR Activity (needs attention)",
         ),
+        (7, SortMode::ByDueDate, "󰃯", "Due Date (soonest first)"),
+        (8, SortMode::ByCIStatus, "󰙨", "CI Status (failing first)"),
     ];
 
     let mut lines: Vec<Line> = vec![Line::from("")];
@@ -77,7 +79,34 @@ pub fn draw_sort_menu(f: &mut Frame, app: &App) {
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        "  Press 1-6 to select | Esc: Cancel",
+        "  GROUP BY",
+        Style::default()
+            .fg(Color::Magenta)
+            .add_modifier(Modifier::BOLD),
+    )));
+
+    for group_by in GroupBy::all() {
+        let is_selected = app.state.group_by == group_by;
+        let marker = if is_selected { "●" } else { "○" };
+        let text_style = if is_selected { active_style } else { dim_style };
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} ", marker),
+                if is_selected {
+                    Style::default().fg(Color::Green)
+                } else {
+                    dim_style
+                },
+            ),
+            Span::styled(format!("[{}] ", group_by.key()), text_style),
+            Span::styled(group_by.label(), text_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  1-8: sort | n/p/c/t/l/a/r: group | Esc: Cancel",
         dim_style,
     )));
 
@@ -96,7 +125,7 @@ pub fn draw_sort_menu(f: &mut Frame, app: &App) {
 
 pub fn draw_filter_menu(f: &mut Frame, app: &App) {
     // Calculate height based on content
-    let base_height = 25; // Base height for headers and footer
+    let base_height = 26; // Base height for headers and footer
     let cycle_height = app.available_cycles.len().min(5) + 2;
     let project_height = if app.available_projects.is_empty() {
         0
@@ -104,8 +133,14 @@ pub fn draw_filter_menu(f: &mut Frame, app: &App) {
         app.available_projects.len().min(5) + 2
     };
     let assignee_height = 5 + app.available_team_members.len().min(5); // header + all + me/unassigned + members + spacer
+    let workspace_height = if app.available_workspaces.is_empty() {
+        0
+    } else {
+        app.available_workspaces.len().min(9) + 3
+    };
     let total_height =
-        (base_height + cycle_height + project_height + assignee_height).min(45) as u16;
+        (base_height + cycle_height + project_height + assignee_height + workspace_height).min(45)
+            as u16;
 
     let area = popup_rect(55, 80, 42, total_height, f.area());
 
@@ -235,6 +270,30 @@ pub fn draw_filter_menu(f: &mut Frame, app: &App) {
     }
     lines.push(Line::from(""));
 
+    // ─────────────────────────────────────────────────────────────────
+    // Workspace section (only with multiple Linear accounts)
+    // ─────────────────────────────────────────────────────────────────
+    if !app.available_workspaces.is_empty() {
+        lines.push(Line::from(Span::styled("  WORKSPACE", header_style)));
+        lines.extend(render_filter_checkbox(
+            "w0",
+            "All workspaces",
+            app.filter_workspaces.is_empty(),
+            active_style,
+            dim_style,
+        ));
+        for (idx, name) in app.available_workspaces.iter().enumerate().take(9) {
+            lines.extend(render_filter_checkbox(
+                &format!("w{}", idx + 1),
+                &truncate_str(name, 20),
+                app.filter_workspaces.contains(name),
+                active_style,
+                dim_style,
+            ));
+        }
+        lines.push(Line::from(""));
+    }
+
     // ─────────────────────────────────────────────────────────────────
     // Status section
     // ─────────────────────────────────────────────────────────────────
@@ -253,6 +312,26 @@ pub fn draw_filter_menu(f: &mut Frame, app: &App) {
         active_style,
         dim_style,
     ));
+    let blocked_style = if app.state.blocked_mode == BlockedMode::Show {
+        dim_style
+    } else {
+        active_style
+    };
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  [b] {:<28} {}",
+            "Blocked issues",
+            app.state.blocked_mode.label()
+        ),
+        blocked_style,
+    )));
+    lines.extend(render_filter_checkbox(
+        "o",
+        "Only overdue",
+        app.filter_overdue,
+        active_style,
+        dim_style,
+    ));
     lines.push(Line::from(""));
 
     // ─────────────────────────────────────────────────────────────────
@@ -7,7 +7,7 @@
 //! - `layout` - Layout calculations and text utilities
 //! - `status` - Status configuration and status bar rendering
 //! - `table` - Issue table rendering (header, workstreams)
-//! - `modals` - Modal popup rendering (help, links, description)
+//! - `modals` - Modal popup rendering (help, links, description, create issue, inbox)
 //! - `menus` - Menu rendering (sort, filter)
 
 pub mod icons;
@@ -23,7 +23,10 @@ pub use self::draw::draw;
 mod draw {
 
     use super::menus::{draw_filter_menu, draw_sort_menu};
-    use super::modals::{draw_description_modal, draw_link_menu, draw_links_popup};
+    use super::modals::{
+        draw_build_log, draw_deployments, draw_description_modal, draw_inbox, draw_issue_form,
+        draw_job_log, draw_link_menu, draw_links_popup, draw_pr_actions,
+    };
     use super::status::{draw_help_popup, draw_status_bar};
     use super::table::{draw_header, draw_workstreams};
     use crate::tui::App;
@@ -59,6 +62,28 @@ mod draw {
             }
         }
 
+        // PR actions sit on top of the issue details they were opened from
+        if app.show_pr_actions() {
+            draw_link_menu(f, app);
+            draw_pr_actions(f, app);
+        }
+
+        // Deployments sit on top of the issue details they were opened from
+        if app.show_deployments() {
+            draw_link_menu(f, app);
+            draw_deployments(f, app);
+        }
+
+        // Job logs open from the failing checks in the issue details
+        if app.show_job_log() {
+            draw_link_menu(f, app);
+            draw_job_log(f, app);
+        }
+
+        if app.show_build_log() {
+            draw_build_log(f, app);
+        }
+
         if app.show_sort_menu() {
             draw_sort_menu(f, app);
         }
@@ -70,5 +95,13 @@ mod draw {
         if app.show_description_modal() {
             draw_description_modal(f, app);
         }
+
+        if app.show_issue_form() {
+            draw_issue_form(f, app);
+        }
+
+        if app.show_inbox() {
+            draw_inbox(f, app);
+        }
     }
 }
@@ -1,12 +1,19 @@
-//! Modal popup rendering - issue details, links, description.
+//! Modal popup rendering - issue details, links, description, create issue, inbox.
 
 use super::icons;
 use super::layout::{
-    display_width, fit_lines_to_area, popup_rect, render_two_col_line, truncate_str, SEP_WIDTH,
+    display_width, fit_lines_to_area, popup_rect, render_two_col_line, short_age, truncate_str,
+    SEP_WIDTH,
+};
+use super::status::{
+    agent_status_config, ci_status_config, due_state_config, linear_status_config,
+    pr_status_config, priority_config, vercel_status_config,
 };
-use super::status::{agent_status_config, linear_status_config, priority_config};
 use super::table::highlight_search_matches;
-use crate::data::{sort_children, AgentStatus, AgentType, LinearChildRef};
+use crate::data::{
+    sort_children, AgentStatus, AgentType, DeploymentAction, DeploymentSource, DueState,
+    GitHubPRStatus, JobLog, LinearChildRef, LinkedPr, PrAction, RelationKind, ReviewState,
+};
 use crate::tui::keybindings::{generate_footer_hints, Mode};
 use crate::tui::search::FuzzySearch;
 use crate::tui::App;
@@ -191,6 +198,15 @@ pub fn draw_link_menu(f: &mut Frame, app: &App) {
             }
         }
 
+        // Linear workspace (only tagged when several accounts are configured)
+        if let Some(workspace) = &issue.workspace {
+            push_plain!(Line::from(vec![
+                Span::styled(format!("  {} ", icons::ICON_WORKSPACE), label_style),
+                Span::styled("Workspace: ", label_style),
+                Span::styled(workspace.clone(), active_style),
+            ]));
+        }
+
         // Cycle with icon - with highlighting
         if let Some(cycle) = &issue.cycle {
             let mut spans = vec![
@@ -206,13 +222,57 @@ pub fn draw_link_menu(f: &mut Frame, app: &App) {
             push_plain!(Line::from(spans));
         }
 
-        // Estimate with icon
-        if let Some(est) = issue.estimate {
-            push_plain!(Line::from(vec![
+        // Estimate with icon, rolled up from sub-issues
+        if let Some(total) = issue.rolled_up_estimate() {
+            let mut spans = vec![
                 Span::styled(format!("  {} ", icons::ICON_ESTIMATE), label_style),
                 Span::styled("Estimate: ", label_style),
-                Span::styled(format!("{} points", est), active_style),
-            ]));
+                Span::styled(format!("{} points", total), active_style),
+            ];
+            if let Some(children) = issue.children_estimate() {
+                let breakdown = match issue.estimate {
+                    Some(own) => format!(" ({} own + {} from sub-issues)", own, children),
+                    None => " (from sub-issues)".to_string(),
+                };
+                spans.push(Span::styled(breakdown, label_style));
+            }
+            push_plain!(Line::from(spans));
+        }
+
+        // Due date / SLA with overdue badge
+        if issue.due_date.is_some() || issue.sla_breaches_at.is_some() {
+            let mut spans = vec![
+                Span::styled(format!("  {} ", icons::HEADER_DUE), label_style),
+                Span::styled("Due: ", label_style),
+            ];
+            if let Some(due) = issue.due_date {
+                spans.push(Span::styled(
+                    due.format("%Y-%m-%d").to_string(),
+                    active_style,
+                ));
+            }
+            if let Some(sla) = issue.sla_breaches_at {
+                let local = sla.with_timezone(&chrono::Local);
+                spans.push(Span::styled(
+                    format!(
+                        "{}SLA {}",
+                        if issue.due_date.is_some() { "  " } else { "" },
+                        local.format("%Y-%m-%d %H:%M")
+                    ),
+                    active_style,
+                ));
+            }
+            if let Some(state) = issue
+                .due_state(app.frame_now)
+                .filter(|s| *s != DueState::Upcoming)
+            {
+                let cfg = due_state_config(state);
+                spans.push(Span::styled(
+                    format!("  {} {}", cfg.icon, state.label()),
+                    cfg.style,
+                ));
+            }
+            push_plain!(Line::from(spans));
         }
 
         // Labels with icon - with highlighting
@@ -258,6 +318,146 @@ pub fn draw_link_menu(f: &mut Frame, app: &App) {
             ],
         }));
 
+        // Linked PRs with CI state and preview; failing checks are listed with their links
+        let linked_prs = ws.all_linked_prs();
+        for linked in &linked_prs {
+            let pr = &linked.pr;
+            push_plain!(Line::from(""));
+            let pr_cfg = pr_status_config(pr.status);
+            let ci = match pr.ci_status {
+                Some(status) => {
+                    let cfg = ci_status_config(status);
+                    vec![
+                        Span::styled(format!("{} ", cfg.icon), cfg.style),
+                        Span::styled("CI: ", label_style),
+                        Span::styled(status.label(), cfg.style),
+                    ]
+                }
+                None => vec![
+                    Span::styled(format!("{} ", icons::CI_NONE), inactive_style),
+                    Span::styled("CI: ", label_style),
+                    Span::styled("no checks", inactive_style),
+                ],
+            };
+            items.push(IssueLine::TwoCol(TwoColRow {
+                left: vec![
+                    Span::styled(format!("  {} ", icons::HEADER_PR), label_style),
+                    Span::styled("PR: ", label_style),
+                    Span::styled(format!("#{} ", pr.number), active_style),
+                    Span::styled(pr.status.label(), pr_cfg.style),
+                ],
+                right: ci,
+            }));
+            if linked_prs.len() > 1 {
+                push_plain!(Line::from(vec![
+                    Span::raw("    "),
+                    Span::styled(pr.repo.clone(), inactive_style),
+                    Span::styled(format!("  {}", truncate_str(&pr.title, 50)), active_style),
+                ]));
+            }
+            if let Some(deploy) = &linked.vercel_deployment {
+                let cfg = vercel_status_config(deploy.status);
+                let mut spans = vec![
+                    Span::styled(format!("    {} ", icons::HEADER_VERCEL), label_style),
+                    Span::styled("Vercel: ", label_style),
+                    Span::styled(deploy.status.label(), cfg.style),
+                    Span::styled(format!("  {}", deploy.url), inactive_style),
+                ];
+                if deploy.source == DeploymentSource::GitHub {
+                    spans.push(Span::styled("  (via GitHub)", inactive_style));
+                }
+                push_plain!(Line::from(spans));
+            }
+            for check in pr.failing_checks() {
+                let failing = ci_status_config(check.status);
+                let mut spans = vec![
+                    Span::styled(format!("    {} ", failing.icon), failing.style),
+                    Span::styled(truncate_str(&check.name, 30).to_string(), active_style),
+                ];
+                if let Some(url) = &check.url {
+                    spans.push(Span::styled(format!("  {}", url), inactive_style));
+                }
+                push_plain!(Line::from(spans));
+            }
+
+            // Each reviewer's latest verdict
+            for review in &pr.reviews {
+                let (icon, style) = match review.state {
+                    ReviewState::Approved => {
+                        let cfg = pr_status_config(GitHubPRStatus::Approved);
+                        (cfg.icon, cfg.style)
+                    }
+                    ReviewState::ChangesRequested => {
+                        let cfg = pr_status_config(GitHubPRStatus::ChangesRequested);
+                        (cfg.icon, cfg.style)
+                    }
+                    ReviewState::Commented => (icons::REVIEW_THREADS, inactive_style),
+                };
+                let author = review.author.as_deref().unwrap_or("ghost");
+                let mut spans = vec![
+                    Span::styled(format!("    {} ", icon), style),
+                    Span::styled(format!("@{} ", author), active_style),
+                    Span::styled(review.state.label(), style),
+                ];
+                if let Some(line) = review.body.lines().find(|l| !l.trim().is_empty()) {
+                    spans.push(Span::styled(
+                        format!(": {}", truncate_str(line.trim(), 60)),
+                        inactive_style,
+                    ));
+                }
+                push_plain!(Line::from(spans));
+            }
+
+            // Unresolved review threads (file, line, author, first comment)
+            if !pr.review_threads.is_empty() {
+                let unresolved = pr.unresolved_thread_count();
+                let count_style = if unresolved > 0 {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    inactive_style
+                };
+                let mut spans = vec![
+                    Span::styled(format!("  {} ", icons::REVIEW_THREADS), label_style),
+                    Span::styled("Threads: ", label_style),
+                    Span::styled(format!("{} unresolved", unresolved), count_style),
+                    Span::styled(
+                        format!(", {} resolved", pr.review_threads.len() - unresolved),
+                        inactive_style,
+                    ),
+                ];
+                let has_session = ws.agent_session.is_some() || !ws.agent_sessions.is_empty();
+                if unresolved > 0 && has_session {
+                    spans.push(Span::styled("  (a: send to agent)", inactive_style));
+                }
+                push_plain!(Line::from(spans));
+                for thread in pr.unresolved_threads() {
+                    let mut spans = vec![
+                        Span::raw("    "),
+                        Span::styled(truncate_str(&thread.location(), 50), active_style),
+                    ];
+                    if let Some(author) = &thread.author {
+                        spans.push(Span::styled(
+                            format!(" @{}", author),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                    }
+                    if thread.comment_count > 1 {
+                        spans.push(Span::styled(
+                            format!(" +{} replies", thread.comment_count - 1),
+                            inactive_style,
+                        ));
+                    }
+                    push_plain!(Line::from(spans));
+                    if let Some(line) = thread.body.lines().find(|l| !l.trim().is_empty()) {
+                        push_plain!(Line::from(Span::styled(
+                            format!("      {}", truncate_str(line.trim(), 70)),
+                            inactive_style,
+                        )));
+                    }
+                }
+            }
+        }
+
         // Agent session details (if linked)
         if let Some(session) = ws
             .agent_session
@@ -646,6 +846,82 @@ pub fn draw_link_menu(f: &mut Frame, app: &App) {
             }
         }
 
+        // Relations (blocked by / blocks / related / duplicate) - j/k continues from sub-issues
+        let relations = app.modal_relations();
+        if !relations.is_empty() {
+            push_plain!(Line::from(""));
+            let blockers = issue.open_blockers().count();
+            let mut header = vec![
+                Span::styled(
+                    format!("  {} ", icons::ICON_RELATIONS),
+                    Style::default().fg(Color::Magenta),
+                ),
+                Span::styled(format!("Relations ({}):", relations.len()), label_style),
+            ];
+            if blockers > 0 {
+                header.push(Span::styled(
+                    format!(" {} blocked by {} open", icons::BLOCKED, blockers),
+                    Style::default().fg(Color::Red),
+                ));
+            }
+            push_plain!(Line::from(header));
+
+            for (i, relation) in relations.iter().enumerate() {
+                let is_selected = app.selected_relation_idx == Some(i);
+                let status_cfg = linear_status_config(relation.status);
+                let open_blocker =
+                    relation.kind == RelationKind::BlockedBy && !relation.status.is_closed();
+
+                let row_style = if is_selected {
+                    selected_child_style
+                } else {
+                    Style::default()
+                };
+                let kind_style = if is_selected {
+                    selected_child_style
+                } else if open_blocker {
+                    Style::default().fg(Color::Red)
+                } else {
+                    label_style
+                };
+                let id_style = if is_selected {
+                    selected_child_style
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+                let title_style = if is_selected {
+                    selected_child_style
+                } else {
+                    active_style
+                };
+
+                let mut spans = vec![
+                    Span::styled(if is_selected { " >> " } else { "    " }, row_style),
+                    Span::styled(format!("{:<11}", relation.kind.label()), kind_style),
+                    Span::styled(
+                        format!("{} ", status_cfg.icon),
+                        if is_selected {
+                            row_style
+                        } else {
+                            status_cfg.style
+                        },
+                    ),
+                ];
+                spans.extend(highlight_search_matches(
+                    &relation.identifier,
+                    search_q,
+                    id_style,
+                ));
+                spans.push(Span::styled(" ", row_style));
+                spans.extend(highlight_search_matches(
+                    &truncate_str(&relation.title, 32),
+                    search_q,
+                    title_style,
+                ));
+                push_plain!(Line::from(spans));
+            }
+        }
+
         // Attachments (documents) - with highlighting
         if !issue.attachments.is_empty() {
             push_plain!(Line::from(""));
@@ -767,8 +1043,14 @@ pub fn draw_link_menu(f: &mut Frame, app: &App) {
 
 /// Draw the quick links popup (overlays issue details)
 pub fn draw_links_popup(f: &mut Frame, app: &App) {
+    // Other PRs beyond the primary one get keys 5-9
+    let other_prs: Vec<LinkedPr> = app
+        .modal_issue()
+        .map(|ws| ws.all_linked_prs().into_iter().skip(1).take(5).collect())
+        .unwrap_or_default();
+
     // Small centered popup
-    let area = popup_rect(40, 30, 30, 8, f.area());
+    let area = popup_rect(40, 30, 30, 8 + other_prs.len() as u16, f.area());
 
     f.render_widget(Clear, area);
 
@@ -782,7 +1064,7 @@ pub fn draw_links_popup(f: &mut Frame, app: &App) {
         let has_vercel = ws.vercel_deployment.is_some();
         let has_session = !ws.agent_sessions.is_empty() || ws.agent_session.is_some();
 
-        vec![
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 if has_linear {
@@ -805,8 +1087,13 @@ pub fn draw_links_popup(f: &mut Frame, app: &App) {
                 if has_pr { active_style } else { inactive_style },
             )),
             Line::from(Span::styled(
-                if ws.vercel_deployment.is_some() {
-                    "  [3] ▲ Vercel: preview".to_string()
+                if let Some(deploy) = &ws.vercel_deployment {
+                    match deploy.source {
+                        DeploymentSource::Vercel => "  [3] ▲ Vercel: preview".to_string(),
+                        DeploymentSource::GitHub => {
+                            "  [3] ▲ Vercel: preview (via GitHub)".to_string()
+                        }
+                    }
                 } else {
                     "  [3] ▲ Vercel: (no deploy)".to_string()
                 },
@@ -829,8 +1116,31 @@ pub fn draw_links_popup(f: &mut Frame, app: &App) {
                 },
             )),
             Line::from(""),
-            Line::from(Span::styled("  1-4: open | Esc: close", inactive_style)),
-        ]
+            Line::from(Span::styled(
+                if other_prs.is_empty() {
+                    "  1-4: open | Esc: close"
+                } else {
+                    "  1-4: open | 5-9: other PRs | Esc: close"
+                },
+                inactive_style,
+            )),
+        ];
+        // Other PRs right below the primary one
+        lines.splice(
+            3..3,
+            other_prs.iter().enumerate().map(|(i, linked)| {
+                Line::from(Span::styled(
+                    format!(
+                        "  [{}]  GitHub: {}#{}",
+                        i + 5,
+                        linked.pr.repo,
+                        linked.pr.number
+                    ),
+                    active_style,
+                ))
+            }),
+        );
+        lines
     } else {
         vec![Line::from(Span::styled("  No issue", inactive_style))]
     };
@@ -846,6 +1156,399 @@ pub fn draw_links_popup(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+pub fn draw_pr_actions(f: &mut Frame, app: &App) {
+    let prs: Vec<LinkedPr> = app
+        .modal_issue()
+        .map(|ws| ws.all_linked_prs())
+        .unwrap_or_default();
+    let area = popup_rect(50, 40, 46, 13 + prs.len() as u16, f.area());
+    f.render_widget(Clear, area);
+
+    let confirm = app.pending_confirmation();
+    let mode = if confirm.is_some() {
+        Mode::PrActionConfirm
+    } else {
+        Mode::PrActions
+    };
+    let block = Block::default()
+        .title(format!(" {} PR Actions ", icons::PR_MERGED))
+        .title_bottom(Line::from(generate_footer_hints(mode)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+
+    let text_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::DarkGray);
+    let key_style = Style::default().fg(Color::Cyan);
+    let selected_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![Line::from("")];
+    for (idx, linked) in prs.iter().enumerate() {
+        let pr = &linked.pr;
+        let cfg = pr_status_config(pr.status);
+        let marker = if idx == app.pr_action_selected {
+            "▸"
+        } else {
+            " "
+        };
+        let mut spans = vec![
+            Span::styled(format!(" {} ", marker), selected_style),
+            Span::styled(format!("{} ", cfg.icon), cfg.style),
+            Span::styled(format!("{}#{} ", pr.repo, pr.number), text_style),
+            Span::styled(pr.status.label(), cfg.style),
+        ];
+        if app.pending_pr_actions.contains(&pr.url) {
+            spans.push(Span::styled(format!(" {}", app.spinner_char()), key_style));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+
+    let selected = prs.get(app.pr_action_selected).map(|linked| &linked.pr);
+    match (confirm, app.pending_confirmation_pr(), selected) {
+        (Some(action), Some(pr), _) => {
+            lines.push(Line::from(Span::styled(
+                format!("  {} {}#{}?", action.label(), pr.repo, pr.number),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("  [y] ", key_style),
+                Span::styled("Yes   ", text_style),
+                Span::styled("[n] ", key_style),
+                Span::styled("No", text_style),
+            ]));
+        }
+        (None, _, Some(pr)) => {
+            let method = app.merge_method;
+            let actions = [
+                ('r', PrAction::MarkReady),
+                ('m', PrAction::Merge(method)),
+                ('a', PrAction::EnableAutoMerge(method)),
+                ('v', PrAction::RerequestReviews),
+                ('x', PrAction::Close),
+            ];
+            for (key, action) in actions {
+                let style = if action.is_available(pr.status) {
+                    text_style
+                } else {
+                    dim_style
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  [{}] ", key), key_style),
+                    Span::styled(action.label(), style),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("  Tab: merge method ({})", method.label()),
+                dim_style,
+            )));
+        }
+        _ => lines.push(Line::from(Span::styled("  No linked PR", dim_style))),
+    }
+
+    let lines = fit_lines_to_area(lines, inner, 1);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+pub fn draw_deployments(f: &mut Frame, app: &App) {
+    let count = match &app.deployment_history {
+        Some(Ok(history)) => history.len().max(1),
+        _ => 1,
+    };
+    let area = popup_rect(70, 60, 60, 12 + count as u16, f.area());
+    f.render_widget(Clear, area);
+
+    let confirm = app.pending_deployment_confirmation();
+    let mode = if confirm.is_some() {
+        Mode::DeploymentActionConfirm
+    } else {
+        Mode::Deployments
+    };
+    let title = match &app.deployment_pr {
+        Some(pr) => format!(
+            " {} Deployments · {} {} ",
+            icons::HEADER_VERCEL,
+            pr.repo,
+            pr.branch
+        ),
+        None => format!(" {} Deployments ", icons::HEADER_VERCEL),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(Line::from(generate_footer_hints(mode)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+
+    let text_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::DarkGray);
+    let key_style = Style::default().fg(Color::Cyan);
+    let selected_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![Line::from("")];
+    let history = match &app.deployment_history {
+        None => {
+            lines.push(Line::from(Span::styled(
+                format!("  {} Loading deployments…", app.spinner_char()),
+                key_style,
+            )));
+            &[][..]
+        }
+        Some(Err(e)) => {
+            lines.push(Line::from(Span::styled(
+                format!("  Failed to load deployments: {}", e),
+                Style::default().fg(Color::Red),
+            )));
+            &[][..]
+        }
+        Some(Ok(history)) => {
+            if history.is_empty() {
+                lines.push(Line::from(Span::styled("  No deployments", dim_style)));
+            }
+            history.as_slice()
+        }
+    };
+    for (idx, deployment) in history.iter().enumerate() {
+        let cfg = vercel_status_config(deployment.status);
+        let marker = if idx == app.deployment_selected {
+            "▸"
+        } else {
+            " "
+        };
+        let mut spans = vec![
+            Span::styled(format!(" {} ", marker), selected_style),
+            Span::styled(format!("{} ", cfg.icon), cfg.style),
+            Span::styled(format!("{:<9}", deployment.status.label()), cfg.style),
+            Span::styled(
+                format!("{:<8}", deployment.short_sha().unwrap_or("-")),
+                key_style,
+            ),
+            Span::styled(
+                format!("{:>4}  ", short_age(deployment.created_at, app.frame_now)),
+                dim_style,
+            ),
+        ];
+        if deployment.is_production() {
+            spans.push(Span::styled("prod ", selected_style));
+        }
+        if let Some(message) = &deployment.commit_message {
+            spans.push(Span::styled(truncate_str(message, 40), text_style));
+        }
+        spans.push(Span::styled(
+            format!("  {}", deployment.url.trim_start_matches("https://")),
+            dim_style,
+        ));
+        if app.pending_deployment_actions.contains(&deployment.id) {
+            spans.push(Span::styled(format!(" {}", app.spinner_char()), key_style));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+
+    match (confirm, app.selected_deployment()) {
+        (Some(action), Some(deployment)) => {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {} {}?",
+                    action.label(),
+                    deployment.url.trim_start_matches("https://")
+                ),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("  [y] ", key_style),
+                Span::styled("Yes   ", text_style),
+                Span::styled("[n] ", key_style),
+                Span::styled("No", text_style),
+            ]));
+        }
+        (None, Some(deployment)) => {
+            let actions = [
+                ('r', DeploymentAction::Redeploy),
+                ('x', DeploymentAction::Cancel),
+                ('p', DeploymentAction::Promote),
+            ];
+            for (key, action) in actions {
+                let style = if action.is_available(deployment) {
+                    text_style
+                } else {
+                    dim_style
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  [{}] ", key), key_style),
+                    Span::styled(action.label(), style),
+                ]));
+            }
+        }
+        (_, None) => {}
+    }
+
+    let lines = fit_lines_to_area(lines, inner, 1);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+pub fn draw_job_log(f: &mut Frame, app: &App) {
+    let area = popup_rect(90, 85, 60, 12, f.area());
+    f.render_widget(Clear, area);
+
+    let job_count = app
+        .modal_issue()
+        .map(|ws| ws.failed_actions_jobs().len())
+        .unwrap_or(0);
+    let title = match app.selected_failed_job() {
+        Some((pr, check)) if job_count > 1 => format!(
+            " {} {} · {}#{} ({}/{}) ",
+            icons::CI_FAILING,
+            check.name,
+            pr.repo,
+            pr.number,
+            app.job_log_selected + 1,
+            job_count
+        ),
+        Some((pr, check)) => format!(
+            " {} {} · {}#{} ",
+            icons::CI_FAILING,
+            check.name,
+            pr.repo,
+            pr.number
+        ),
+        None => " CI Job Log ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(Line::from(generate_footer_hints(Mode::JobLog)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+
+    let text_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::DarkGray);
+    let error_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+
+    let lines: Vec<Line> = match &app.job_log {
+        None => vec![Line::from(Span::styled(
+            format!(" {} Loading log…", app.spinner_char()),
+            Style::default().fg(Color::Cyan),
+        ))],
+        Some(Err(e)) => vec![Line::from(Span::styled(
+            format!(" Failed to load log: {}", e),
+            Style::default().fg(Color::Red),
+        ))],
+        Some(Ok(log)) => {
+            // Scroll is measured up from the tail so new logs open on the failure
+            let height = inner.height as usize;
+            let end = log.lines.len().saturating_sub(app.job_log_scroll);
+            let start = end.saturating_sub(height);
+            let mut lines = Vec::with_capacity(height);
+            if start == 0 && log.skipped > 0 {
+                lines.push(Line::from(Span::styled(
+                    format!(" … {} earlier lines not shown", log.skipped),
+                    dim_style,
+                )));
+            }
+            for line in &log.lines[start..end] {
+                let style = if JobLog::is_error_line(line) {
+                    error_style
+                } else {
+                    text_style
+                };
+                lines.push(Line::from(Span::styled(format!(" {}", line), style)));
+            }
+            lines
+        }
+    };
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+pub fn draw_build_log(f: &mut Frame, app: &App) {
+    let area = popup_rect(90, 85, 60, 12, f.area());
+    f.render_widget(Clear, area);
+
+    // Status of the latest fetch, falling back to the one from the table
+    let status = match (&app.build_log, &app.build_log_target) {
+        (Some(Ok(log)), _) => Some(log.status),
+        (_, Some((_, deployment))) => Some(deployment.status),
+        _ => None,
+    };
+    let mut title = vec![Span::raw(format!(" {} Build Log ", icons::HEADER_VERCEL))];
+    if let Some((_, deployment)) = &app.build_log_target {
+        title.push(Span::styled(
+            format!("· {} ", deployment.url.trim_start_matches("https://")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if let Some(status) = status {
+        let cfg = vercel_status_config(status);
+        let live = matches!(&app.build_log, Some(Ok(log)) if log.is_live());
+        let label = if live {
+            format!("{} {} {} ", cfg.icon, status.label(), app.spinner_char())
+        } else {
+            format!("{} {} ", cfg.icon, status.label())
+        };
+        title.push(Span::styled(label, cfg.style));
+    }
+    let block = Block::default()
+        .title(Line::from(title))
+        .title_bottom(Line::from(generate_footer_hints(Mode::BuildLog)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+
+    let text_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::DarkGray);
+    let error_style = Style::default().fg(Color::Red);
+    let first_error_style = Style::default()
+        .fg(Color::Red)
+        .add_modifier(Modifier::BOLD | Modifier::REVERSED);
+
+    let lines: Vec<Line> = match &app.build_log {
+        None => vec![Line::from(Span::styled(
+            format!(" {} Loading build log…", app.spinner_char()),
+            Style::default().fg(Color::Cyan),
+        ))],
+        Some(Err(e)) => vec![Line::from(Span::styled(
+            format!(" Failed to load build log: {}", e),
+            Style::default().fg(Color::Red),
+        ))],
+        Some(Ok(log)) if log.lines.is_empty() => {
+            vec![Line::from(Span::styled(" No build output yet", dim_style))]
+        }
+        Some(Ok(log)) => {
+            // Scroll is measured up from the tail so running builds follow it
+            let end = log.lines.len().saturating_sub(app.build_log_scroll);
+            let start = end.saturating_sub(inner.height as usize);
+            log.lines[start..end]
+                .iter()
+                .enumerate()
+                .map(|(offset, line)| {
+                    let style = if log.first_error == Some(start + offset) {
+                        first_error_style
+                    } else if JobLog::is_error_line(line) {
+                        error_style
+                    } else {
+                        text_style
+                    };
+                    Line::from(Span::styled(format!(" {}", line), style))
+                })
+                .collect()
+        }
+    };
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 pub fn draw_description_modal(f: &mut Frame, app: &App) {
     let area = popup_rect(80, 80, 50, 12, f.area());
 
@@ -866,7 +1569,8 @@ pub fn draw_description_modal(f: &mut Frame, app: &App) {
     let content_width = inner.width.saturating_sub(2) as usize;
 
     let mut scroll_line = app.description_scroll + 1;
-    let lines: Vec<Line> = if let Some(ws) = app.modal_issue().or_else(|| app.selected_workstream()) {
+    let lines: Vec<Line> = if let Some(ws) = app.modal_issue().or_else(|| app.selected_workstream())
+    {
         let issue = &ws.linear_issue;
         let mut lines = vec![
             Line::from(vec![
@@ -910,6 +1614,247 @@ pub fn draw_description_modal(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+pub fn draw_issue_form(f: &mut Frame, app: &App) {
+    use crate::tui::issue_form::IssueFormField;
+
+    let Some(form) = app.issue_form.as_ref() else {
+        return;
+    };
+
+    let area = popup_rect(70, 60, 60, 18, f.area());
+    f.render_widget(Clear, area);
+
+    let label_style = Style::default().fg(Color::DarkGray);
+    let focus_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let value_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::DarkGray);
+
+    let team = form.team(&app.available_teams);
+    let mut lines: Vec<Line> = vec![Line::from("")];
+
+    for field in IssueFormField::ALL {
+        let focused = form.focus == field;
+        let marker = if focused { "▸" } else { " " };
+        let mut spans = vec![
+            Span::styled(format!(" {} ", marker), focus_style),
+            Span::styled(
+                format!("{:<10}", field.label()),
+                if focused { focus_style } else { label_style },
+            ),
+        ];
+
+        match field {
+            IssueFormField::Title | IssueFormField::Parent => {
+                let text = if field == IssueFormField::Title {
+                    &form.title
+                } else {
+                    &form.parent
+                };
+                if text.is_empty() && !focused {
+                    let hint = if field == IssueFormField::Title {
+                        "(required)"
+                    } else {
+                        "(none, e.g. DRE-100)"
+                    };
+                    spans.push(Span::styled(hint, dim_style));
+                } else {
+                    spans.push(Span::styled(text.clone(), value_style));
+                }
+                if focused {
+                    spans.push(Span::styled("▏", focus_style));
+                }
+            }
+            IssueFormField::Team => {
+                let name = match team {
+                    Some(t) => match &t.workspace {
+                        Some(ws) => format!("{} ({}) · {}", t.name, t.key, ws),
+                        None => format!("{} ({})", t.name, t.key),
+                    },
+                    None => "No teams loaded".to_string(),
+                };
+                spans.push(Span::styled(format!("‹ {} ›", name), value_style));
+            }
+            IssueFormField::Priority => {
+                let priority_cfg = priority_config(form.priority);
+                spans.push(Span::styled(
+                    format!("‹ {} ", priority_cfg.icon),
+                    priority_cfg.style,
+                ));
+                spans.push(Span::styled(
+                    format!("{} ›", form.priority.label()),
+                    value_style,
+                ));
+            }
+            IssueFormField::Project => {
+                let name = form
+                    .project(&app.available_teams, &app.available_projects)
+                    .map(|p| p.name.as_str())
+                    .unwrap_or("None");
+                spans.push(Span::styled(format!("‹ {} ›", name), value_style));
+            }
+            IssueFormField::Labels => {
+                let labels = team.map(|t| t.labels.as_slice()).unwrap_or_default();
+                if labels.is_empty() {
+                    spans.push(Span::styled("No labels", dim_style));
+                }
+                for (idx, label) in labels.iter().enumerate() {
+                    let checked = form.label_ids.contains(&label.id);
+                    let text = format!("[{}] {} ", if checked { "x" } else { " " }, label.name);
+                    let style = if focused && idx == form.label_cursor {
+                        focus_style.add_modifier(Modifier::REVERSED)
+                    } else if checked {
+                        value_style
+                    } else {
+                        dim_style
+                    };
+                    spans.push(Span::styled(text, style));
+                }
+            }
+        }
+
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    if let Some(session) = &form.session {
+        let target = session
+            .git_branch
+            .clone()
+            .or_else(|| session.working_directory.clone())
+            .unwrap_or_else(|| session.id.clone());
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("   {} Links session ", icons::HEADER_AGENT),
+                label_style,
+            ),
+            Span::styled(target, Style::default().fg(Color::Cyan)),
+        ]));
+    }
+    if form.description.is_some() {
+        lines.push(Line::from(Span::styled(
+            "   Description prefilled from the session prompt",
+            dim_style,
+        )));
+    }
+    if form.submitting {
+        lines.push(Line::from(Span::styled(
+            format!("   {} Creating issue...", app.spinner_char()),
+            Style::default().fg(Color::Yellow),
+        )));
+    } else if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            format!("   {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let block = Block::default()
+        .title(" 󰐕 New Linear Issue ")
+        .title_bottom(Line::from(generate_footer_hints(Mode::IssueForm)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    let lines = fit_lines_to_area(lines, inner, 0);
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
+/// Linear inbox: two lines per notification, unread ones highlighted
+pub fn draw_inbox(f: &mut Frame, app: &App) {
+    let area = popup_rect(70, 70, 50, 12, f.area());
+    f.render_widget(Clear, area);
+
+    let unread = app.unread_notification_count();
+    let block = Block::default()
+        .title(format!(
+            " {} Linear Inbox ({} unread) ",
+            icons::INBOX,
+            unread
+        ))
+        .title_bottom(Line::from(generate_footer_hints(Mode::Inbox)).centered())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+
+    let id_style = Style::default().fg(Color::Yellow);
+    let text_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::DarkGray);
+    let selected_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines: Vec<Line> = Vec::new();
+    if app.notifications.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("  Inbox zero", dim_style)));
+    }
+
+    // Keep the selection in view (two lines per notification)
+    let per_page = (inner.height as usize / 2).max(1);
+    let skip = app.inbox_selected.saturating_sub(per_page - 1);
+    let max_width = inner.width.saturating_sub(6) as usize;
+
+    for (idx, notification) in app.notifications.iter().enumerate().skip(skip) {
+        let selected = idx == app.inbox_selected;
+        let marker = if selected { "▸" } else { " " };
+        let (dot, dot_style) = if notification.is_unread() {
+            ("●", Style::default().fg(Color::Cyan))
+        } else {
+            ("○", dim_style)
+        };
+        let actor = notification.actor.as_deref().unwrap_or("Linear");
+        let mut header = vec![
+            Span::styled(format!(" {} ", marker), selected_style),
+            Span::styled(format!("{} ", dot), dot_style),
+            Span::styled(
+                format!(
+                    "{} ",
+                    notification.issue_identifier.as_deref().unwrap_or("—")
+                ),
+                id_style,
+            ),
+            Span::styled(
+                format!("{} {}", actor, notification.kind.label()),
+                if notification.is_unread() {
+                    text_style.add_modifier(Modifier::BOLD)
+                } else {
+                    text_style
+                },
+            ),
+            Span::styled(
+                format!(" · {}", short_age(notification.created_at, app.frame_now)),
+                dim_style,
+            ),
+        ];
+        if let Some(workspace) = &notification.workspace {
+            header.push(Span::styled(format!(" · {}", workspace), dim_style));
+        }
+        if app.notification_workstream(notification).is_none() {
+            header.push(Span::styled(" · not in list", dim_style));
+        }
+        lines.push(Line::from(header));
+
+        let detail = match (&notification.excerpt, &notification.issue_title) {
+            (Some(excerpt), _) => format!("\"{}\"", excerpt),
+            (None, Some(title)) => title.clone(),
+            (None, None) => String::new(),
+        };
+        lines.push(Line::from(Span::styled(
+            format!("     {}", truncate_str(&detail, max_width)),
+            dim_style,
+        )));
+    }
+
+    let lines = fit_lines_to_area(lines, inner, 0);
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, area);
+}
+
 /// Parse markdown text into styled ratatui Lines
 fn parse_markdown_to_lines(markdown: &str, max_width: usize) -> Vec<Line<'static>> {
     let parser = Parser::new(markdown);
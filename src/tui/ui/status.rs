@@ -2,7 +2,10 @@
 
 use super::icons;
 use super::layout::{fit_lines_to_area, popup_rect};
-use crate::data::{AgentStatus, GitHubPRStatus, LinearPriority, LinearStatus, VercelStatus};
+use crate::data::{
+    AgentStatus, CiStatus, DueState, GitHubPRStatus, GroupBy, LinearPriority, LinearStatus,
+    VercelStatus,
+};
 use crate::tui::App;
 use ratatui::{
     layout::Rect,
@@ -183,6 +186,44 @@ impl StatusConfigurable for VercelStatus {
     }
 }
 
+impl StatusConfigurable for CiStatus {
+    fn status_config(&self) -> StatusConfig {
+        match self {
+            CiStatus::Passing => StatusConfig {
+                icon: icons::CI_PASSING,
+                style: Style::default().fg(Color::Green),
+            },
+            CiStatus::Failing => StatusConfig {
+                icon: icons::CI_FAILING,
+                style: Style::default().fg(Color::Red),
+            },
+            CiStatus::Pending => StatusConfig {
+                icon: icons::CI_PENDING,
+                style: Style::default().fg(Color::Yellow),
+            },
+        }
+    }
+}
+
+impl StatusConfigurable for DueState {
+    fn status_config(&self) -> StatusConfig {
+        match self {
+            DueState::Overdue => StatusConfig {
+                icon: icons::DUE_OVERDUE,
+                style: Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            },
+            DueState::DueSoon => StatusConfig {
+                icon: icons::DUE_SOON,
+                style: Style::default().fg(Color::Yellow),
+            },
+            DueState::Upcoming => StatusConfig {
+                icon: icons::DUE_UPCOMING,
+                style: Style::default().fg(Color::DarkGray),
+            },
+        }
+    }
+}
+
 // Convenience functions
 pub fn linear_status_config(status: LinearStatus) -> StatusConfig {
     status.status_config()
@@ -204,6 +245,14 @@ pub fn vercel_status_config(status: VercelStatus) -> StatusConfig {
     status.status_config()
 }
 
+pub fn ci_status_config(status: CiStatus) -> StatusConfig {
+    status.status_config()
+}
+
+pub fn due_state_config(state: DueState) -> StatusConfig {
+    state.status_config()
+}
+
 /// Generate the status legend for help popup.
 pub fn generate_status_legend() -> Vec<&'static str> {
     vec![
@@ -237,6 +286,12 @@ pub fn generate_status_legend() -> Vec<&'static str> {
         "  󰜛  Merged       Successfully merged",
         "  󰅖  Closed       Closed without merging",
         "",
+        "  CI CHECKS",
+        "  ─────────",
+        "  󰄬  Passing      All checks succeeded",
+        "  󰔟  Pending      Checks still running",
+        "  󰅚  Failing      At least one check failed",
+        "",
         "  AGENT STATUS",
         "  ────────────",
         "  󰐊  Running      Agent actively working",
@@ -252,6 +307,12 @@ pub fn generate_status_legend() -> Vec<&'static str> {
         "  󰔟  Queued       Waiting to build",
         "  󰅚  Error        Deployment failed",
         "",
+        "  DUE DATE / SLA",
+        "  ──────────────",
+        "  󰀠  Overdue      Past due date or SLA breached",
+        "  󰔟  Due soon     Due within 2 days",
+        "  󰃭  Upcoming     Deadline further out",
+        "",
     ]
 }
 
@@ -294,8 +355,11 @@ pub fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
         Span::styled(text, Style::default().fg(Color::Yellow))
     } else {
         let text = if width >= 110 {
-            let sort_indicator = format!("[{}]", app.state.sort_mode.label());
-            format!(" j/k: nav | o/Enter: details | l: links | z: fold | /: search | f: filter | s: sort {} | ?: help ", sort_indicator)
+            let sort_indicator = match app.state.group_by {
+                GroupBy::None => format!("[{}]", app.state.sort_mode.label()),
+                group_by => format!("[{} / {}]", group_by.label(), app.state.sort_mode.label()),
+            };
+            format!(" j/k: nav | o/Enter: details | l: links | z: fold | /: search | f: filter | s: sort {} | i: inbox | ?: help ", sort_indicator)
         } else if width >= 90 {
             " j/k: nav | o: details | l: links | z: fold | /: search | f: filter | ?: help "
                 .to_string()
@@ -309,7 +373,20 @@ pub fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
         Span::styled(text, Style::default().fg(Color::DarkGray))
     };
 
-    let paragraph = Paragraph::new(Line::from(status));
+    // Unread Linear inbox count (i opens the inbox)
+    let unread = app.unread_notification_count();
+    let mut spans = Vec::new();
+    if unread > 0 {
+        spans.push(Span::styled(
+            format!(" {} {} ", icons::INBOX, unread),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans.push(status);
+
+    let paragraph = Paragraph::new(Line::from(spans));
     f.render_widget(paragraph, area);
 }
 
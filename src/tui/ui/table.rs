@@ -2,17 +2,17 @@
 
 use super::icons;
 use super::layout::{
-    compute_column_layout, display_width, pad_to_width, title_column_offset,
+    compute_column_layout, display_width, pad_to_width, short_age, title_column_offset,
     truncate_with_ellipsis, ColumnLayout, PREFIX, SEP,
 };
 use super::status::{
-    agent_status_config, linear_status_config, pr_status_config, priority_config,
-    vercel_status_config,
+    agent_status_config, ci_status_config, due_state_config, linear_status_config,
+    pr_status_config, priority_config, vercel_status_config,
 };
-use crate::data::{AgentStatus, SectionType, VisualItem};
+use crate::data::{AgentStatus, CycleProgress, DueState, ReviewRequest, SectionType, VisualItem};
 use crate::tui::app::{
-    COL_IDX_AGENT, COL_IDX_ID, COL_IDX_PR, COL_IDX_PRIORITY, COL_IDX_STATUS, COL_IDX_TIME,
-    COL_IDX_TITLE, COL_IDX_VERCEL,
+    COL_IDX_AGENT, COL_IDX_CI, COL_IDX_DUE, COL_IDX_ID, COL_IDX_OWNER, COL_IDX_PR,
+    COL_IDX_PRIORITY, COL_IDX_STATUS, COL_IDX_TIME, COL_IDX_TITLE, COL_IDX_VERCEL,
 };
 use crate::tui::App;
 use ratatui::{
@@ -93,7 +93,7 @@ pub fn draw_header(f: &mut Frame, app: &App, area: Rect) {
             Span::styled(progress_text, Style::default().fg(Color::Cyan)),
         ])
     } else {
-        Line::from(vec![
+        let mut spans = vec![
             Span::styled("󰣖 ", Style::default().fg(Color::Cyan)),
             Span::styled(
                 "Panopticon ",
@@ -105,13 +105,60 @@ pub fn draw_header(f: &mut Frame, app: &App, area: Rect) {
                 format!("[{} active]", active_count),
                 Style::default().fg(Color::Green),
             ),
-        ])
+        ];
+        if let Some(progress) = app.cycle_progress() {
+            spans.extend(cycle_progress_spans(&progress));
+        }
+        Line::from(spans)
     };
 
     let paragraph = Paragraph::new(text).alignment(Alignment::Center);
     f.render_widget(paragraph, inner);
 }
 
+/// Active cycle summary: days left and points done vs. remaining
+fn cycle_progress_spans(progress: &CycleProgress) -> Vec<Span<'static>> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let days_style = if progress.days_left <= 1 {
+        Style::default().fg(Color::Red)
+    } else if progress.days_left <= 3 {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let days = match progress.days_left {
+        0 => "ends today".to_string(),
+        1 => "1 day left".to_string(),
+        n => format!("{} days left", n),
+    };
+
+    let mut spans = vec![
+        Span::styled(" │ ", dim),
+        Span::styled(
+            format!("{} {} ", icons::ICON_CYCLE, progress.cycle.name),
+            dim,
+        ),
+        Span::styled(days, days_style),
+        Span::styled(" · ", dim),
+    ];
+    if progress.points_total > 0.0 {
+        spans.push(Span::styled(
+            format!("{}/{} pts", progress.points_done, progress.points_total),
+            Style::default().fg(Color::Green),
+        ));
+        spans.push(Span::styled(
+            format!(" ({} left)", progress.points_remaining()),
+            dim,
+        ));
+    } else {
+        spans.push(Span::styled(
+            format!("{}/{} issues", progress.issues_done, progress.issues_total),
+            Style::default().fg(Color::Green),
+        ));
+    }
+    spans
+}
+
 /// Draw the workstreams table.
 pub fn draw_workstreams(f: &mut Frame, app: &App, area: Rect) {
     let block = Block::default().borders(Borders::ALL);
@@ -186,6 +233,12 @@ pub fn draw_workstreams(f: &mut Frame, app: &App, area: Rect) {
         Alignment::Left,
         header_dim,
     );
+    push_header(
+        COL_IDX_CI,
+        header_label(icons::HEADER_CI, ""),
+        Alignment::Center,
+        header_style,
+    );
     push_header(
         COL_IDX_AGENT,
         header_label(icons::HEADER_AGENT, "Agent"),
@@ -204,6 +257,18 @@ pub fn draw_workstreams(f: &mut Frame, app: &App, area: Rect) {
         Alignment::Right,
         header_dim,
     );
+    push_header(
+        COL_IDX_DUE,
+        header_label(icons::HEADER_DUE, "Due"),
+        Alignment::Right,
+        header_dim,
+    );
+    push_header(
+        COL_IDX_OWNER,
+        header_label(icons::HEADER_OWNER, "Owner"),
+        Alignment::Left,
+        header_dim,
+    );
 
     items.push(ListItem::new(Line::from(header_spans)));
 
@@ -237,7 +302,12 @@ pub fn draw_workstreams(f: &mut Frame, app: &App, area: Rect) {
                     SectionType::AgentSessions => {
                         (icons::HEADER_AGENT, Style::default().fg(Color::Cyan))
                     }
-                    SectionType::Issues => (icons::HEADER_ID, Style::default().fg(Color::White)),
+                    SectionType::ReviewRequests => {
+                        (icons::HEADER_PR, Style::default().fg(Color::Magenta))
+                    }
+                    SectionType::Issues | SectionType::Group(_) => {
+                        (icons::HEADER_ID, Style::default().fg(Color::White))
+                    }
                 };
 
                 let header = format!(
@@ -316,6 +386,16 @@ pub fn draw_workstreams(f: &mut Frame, app: &App, area: Rect) {
                     }
                 }
             }
+            VisualItem::ReviewRequest(idx) => {
+                if let Some(request) = app.state.review_requests.get(*idx) {
+                    items.push(build_review_request_row(
+                        request,
+                        is_selected,
+                        &layout,
+                        app.frame_now,
+                    ));
+                }
+            }
         }
     }
 
@@ -434,6 +514,184 @@ fn build_workstream_row(
     WorkstreamRowBuilder::new(ws, session_override, layout, search_query, frame_now).build(selected)
 }
 
+/// PR status and number, with the unresolved review thread count when it fits
+fn pr_spans(pr: &crate::data::GitHubPR, width: usize) -> Vec<Span<'static>> {
+    pr_column_spans(
+        pr.status,
+        format!("PR#{:<5}", pr.number),
+        pr.unresolved_thread_count(),
+        width,
+    )
+}
+
+fn pr_column_spans(
+    status: crate::data::GitHubPRStatus,
+    label: String,
+    unresolved: usize,
+    width: usize,
+) -> Vec<Span<'static>> {
+    let cfg = pr_status_config(status);
+    if unresolved > 0 {
+        let text = format!("{} {}", cfg.icon, label.trim_end());
+        let counter = format!(" {}{}", icons::REVIEW_THREADS, unresolved);
+        let used = display_width(&text) + display_width(&counter);
+        if used <= width {
+            return vec![
+                Span::styled(text, cfg.style),
+                Span::styled(counter, Style::default().fg(Color::Yellow)),
+                Span::raw(pad_to_width("", width - used, Alignment::Left)),
+            ];
+        }
+    }
+    let text = format!("{} {}", cfg.icon, label);
+    vec![Span::styled(
+        pad_to_width(&text, width, Alignment::Left),
+        cfg.style,
+    )]
+}
+
+/// Row for a PR waiting on the user's review: the linked issue's status and
+/// identifier, the PR with its size, CI state, author and age
+fn build_review_request_row(
+    request: &ReviewRequest,
+    selected: bool,
+    layout: &ColumnLayout,
+    frame_now: chrono::DateTime<chrono::Utc>,
+) -> ListItem<'static> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let issue = request.linked_issue.as_ref();
+    let mut columns: Vec<(usize, Vec<Span<'static>>)> = Vec::new();
+
+    let width = layout.widths[COL_IDX_STATUS];
+    columns.push((
+        COL_IDX_STATUS,
+        vec![match issue {
+            Some(issue) => {
+                let cfg = linear_status_config(issue.status);
+                Span::styled(pad_to_width(cfg.icon, width, Alignment::Center), cfg.style)
+            }
+            None => Span::raw(pad_to_width("", width, Alignment::Center)),
+        }],
+    ));
+
+    let width = layout.widths[COL_IDX_PRIORITY];
+    columns.push((
+        COL_IDX_PRIORITY,
+        vec![match issue {
+            Some(issue) => {
+                let cfg = priority_config(issue.priority);
+                Span::styled(pad_to_width(cfg.icon, width, Alignment::Center), cfg.style)
+            }
+            None => Span::raw(pad_to_width("", width, Alignment::Center)),
+        }],
+    ));
+
+    let width = layout.widths[COL_IDX_ID];
+    let id_style = issue
+        .map(|issue| linear_status_config(issue.status).style)
+        .unwrap_or(dim);
+    columns.push((
+        COL_IDX_ID,
+        vec![Span::styled(
+            pad_to_width(
+                request.linked_identifier.as_deref().unwrap_or(""),
+                width,
+                Alignment::Left,
+            ),
+            id_style,
+        )],
+    ));
+
+    let width = layout.widths[COL_IDX_TITLE];
+    let mut suffix = format!(
+        " {} +{} -{} {}",
+        request.size_label(),
+        request.additions,
+        request.deletions,
+        request.pr.repo
+    );
+    if display_width(&suffix) * 2 > width {
+        suffix.clear();
+    }
+    let title_width = width.saturating_sub(display_width(&suffix));
+    let title = truncate_with_ellipsis(&request.pr.title, title_width);
+    let mut title_spans = vec![Span::raw(pad_to_width(
+        &title,
+        title_width,
+        Alignment::Left,
+    ))];
+    if !suffix.is_empty() {
+        title_spans.push(Span::styled(suffix, dim));
+    }
+    columns.push((COL_IDX_TITLE, title_spans));
+
+    columns.push((COL_IDX_PR, pr_spans(&request.pr, layout.widths[COL_IDX_PR])));
+
+    let width = layout.widths[COL_IDX_CI];
+    columns.push((
+        COL_IDX_CI,
+        vec![match request.pr.ci_status {
+            Some(status) => {
+                let cfg = ci_status_config(status);
+                Span::styled(pad_to_width(cfg.icon, width, Alignment::Center), cfg.style)
+            }
+            None => Span::styled(pad_to_width(icons::CI_NONE, width, Alignment::Center), dim),
+        }],
+    ));
+
+    let width = layout.widths[COL_IDX_AGENT];
+    let author = request
+        .author
+        .as_ref()
+        .map(|login| truncate_with_ellipsis(&format!("@{}", login), width))
+        .unwrap_or_default();
+    columns.push((
+        COL_IDX_AGENT,
+        vec![Span::styled(
+            pad_to_width(&author, width, Alignment::Left),
+            Style::default().fg(Color::Cyan),
+        )],
+    ));
+
+    for col in [COL_IDX_VERCEL, COL_IDX_TIME, COL_IDX_DUE, COL_IDX_OWNER] {
+        let text = if col == COL_IDX_TIME {
+            short_age(request.created_at, frame_now)
+        } else {
+            String::new()
+        };
+        columns.push((
+            col,
+            vec![Span::styled(
+                pad_to_width(&text, layout.widths[col], Alignment::Right),
+                dim,
+            )],
+        ));
+    }
+
+    let mut spans = vec![Span::raw(PREFIX)];
+    let mut first = true;
+    for (col, column_spans) in columns {
+        if !layout.is_visible(col) {
+            continue;
+        }
+        if !first {
+            spans.push(Span::styled(SEP, dim));
+        }
+        first = false;
+        spans.extend(column_spans);
+    }
+
+    if selected {
+        ListItem::new(Line::from(remap_dark_gray_to_gray(spans))).style(
+            Style::default()
+                .bg(Color::Rgb(30, 40, 60))
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ListItem::new(Line::from(spans))
+    }
+}
+
 /// Builder for workstream row UI elements.
 struct WorkstreamRowBuilder<'a> {
     ws: &'a crate::data::Workstream,
@@ -534,7 +792,14 @@ impl<'a> WorkstreamRowBuilder<'a> {
             self.push_column(
                 &mut spans,
                 &mut first,
-                vec![self.pr_span(self.layout.widths[COL_IDX_PR])],
+                self.pr_spans(self.layout.widths[COL_IDX_PR]),
+            );
+        }
+        if self.layout.is_visible(COL_IDX_CI) {
+            self.push_column(
+                &mut spans,
+                &mut first,
+                vec![self.ci_span(self.layout.widths[COL_IDX_CI])],
             );
         }
         if self.layout.is_visible(COL_IDX_AGENT) {
@@ -558,6 +823,20 @@ impl<'a> WorkstreamRowBuilder<'a> {
                 vec![self.elapsed_span(self.layout.widths[COL_IDX_TIME])],
             );
         }
+        if self.layout.is_visible(COL_IDX_DUE) {
+            self.push_column(
+                &mut spans,
+                &mut first,
+                vec![self.due_span(self.layout.widths[COL_IDX_DUE])],
+            );
+        }
+        if self.layout.is_visible(COL_IDX_OWNER) {
+            self.push_column(
+                &mut spans,
+                &mut first,
+                vec![self.owner_span(self.layout.widths[COL_IDX_OWNER])],
+            );
+        }
 
         spans
     }
@@ -580,11 +859,19 @@ impl<'a> WorkstreamRowBuilder<'a> {
     }
 
     fn sub_issue_indicators(&self) -> (String, String) {
-        if let Some(parent) = &self.ws.linear_issue.parent {
+        let issue = &self.ws.linear_issue;
+        let (prefix, mut suffix) = if let Some(parent) = &issue.parent {
             ("└ ".to_string(), format!(" ← {}", parent.identifier))
         } else {
             (String::new(), String::new())
+        };
+        // Parents show their estimate rolled up from sub-issues
+        if issue.children_estimate().is_some() {
+            if let Some(total) = issue.rolled_up_estimate() {
+                suffix.push_str(&format!(" Σ{}pt", total));
+            }
         }
+        (prefix, suffix)
     }
 
     fn status_span(&self, width: usize) -> Span<'static> {
@@ -627,6 +914,25 @@ impl<'a> WorkstreamRowBuilder<'a> {
             return Vec::new();
         }
         let issue = &self.ws.linear_issue;
+        // Workspace tag (only set when several Linear accounts are configured)
+        let mut tag = issue
+            .workspace
+            .as_ref()
+            .map(|ws| format!("{} ", ws))
+            .unwrap_or_default();
+        if display_width(&tag) * 2 > width {
+            tag.clear();
+        }
+        let width = width.saturating_sub(display_width(&tag));
+
+        // Lock for issues waiting on an open blocker
+        let blocked = if issue.is_blocked() && width > 4 {
+            format!("{} ", icons::BLOCKED)
+        } else {
+            String::new()
+        };
+        let width = width.saturating_sub(display_width(&blocked));
+
         let suffix_width = display_width(sub_suffix);
         let mut suffix = sub_suffix.to_string();
         let title_width = if suffix_width + 1 > width {
@@ -638,25 +944,43 @@ impl<'a> WorkstreamRowBuilder<'a> {
 
         let title = truncate_with_ellipsis(&issue.title, title_width);
         let title = pad_to_width(&title, title_width, Alignment::Left);
-        let mut spans = highlight_search_matches(&title, self.search_query, Style::default());
+        let mut spans = Vec::new();
+        if !tag.is_empty() {
+            spans.push(Span::styled(tag, Style::default().fg(Color::Magenta)));
+        }
+        if !blocked.is_empty() {
+            spans.push(Span::styled(blocked, Style::default().fg(Color::Red)));
+        }
+        spans.extend(highlight_search_matches(
+            &title,
+            self.search_query,
+            Style::default(),
+        ));
         if !suffix.is_empty() {
             spans.push(Span::styled(suffix, Style::default().fg(Color::DarkGray)));
         }
         spans
     }
 
-    fn pr_span(&self, width: usize) -> Span<'static> {
-        let (text, style) = if let Some(pr) = &self.ws.github_pr {
-            let cfg = pr_status_config(pr.status);
-            let text = format!("{} PR#{:<5}", cfg.icon, pr.number);
-            (pad_to_width(&text, width, Alignment::Left), cfg.style)
-        } else {
-            (
+    /// Several PRs show the worst status with a count
+    fn pr_spans(&self, width: usize) -> Vec<Span<'static>> {
+        let count = self.ws.pr_count();
+        match &self.ws.github_pr {
+            Some(pr) if count > 1 => {
+                let unresolved = self
+                    .ws
+                    .linked_prs
+                    .iter()
+                    .map(|linked| linked.pr.unresolved_thread_count())
+                    .sum();
+                pr_column_spans(pr.status, format!("{} PRs", count), unresolved, width)
+            }
+            Some(pr) => pr_spans(pr, width),
+            None => vec![Span::styled(
                 pad_to_width(&format!("{} --", icons::AGENT_NONE), width, Alignment::Left),
                 Style::default().fg(Color::DarkGray),
-            )
-        };
-        Span::styled(text, style)
+            )],
+        }
     }
 
     fn agent_span(&self, width: usize) -> Span<'static> {
@@ -722,6 +1046,20 @@ impl<'a> WorkstreamRowBuilder<'a> {
         Span::styled(text, style)
     }
 
+    fn ci_span(&self, width: usize) -> Span<'static> {
+        let ci_status = self.ws.worst_ci_status();
+        let (text, style) = if let Some(status) = ci_status {
+            let cfg = ci_status_config(status);
+            (pad_to_width(cfg.icon, width, Alignment::Center), cfg.style)
+        } else {
+            (
+                pad_to_width(icons::CI_NONE, width, Alignment::Center),
+                Style::default().fg(Color::DarkGray),
+            )
+        };
+        Span::styled(text, style)
+    }
+
     fn vercel_span(&self, width: usize) -> Span<'static> {
         let (text, style) = if let Some(deploy) = &self.ws.vercel_deployment {
             let cfg = vercel_status_config(deploy.status);
@@ -764,6 +1102,49 @@ impl<'a> WorkstreamRowBuilder<'a> {
         )
     }
 
+    /// Due date (or "SLA" when only an SLA is set) with an overdue / due soon badge
+    fn due_span(&self, width: usize) -> Span<'static> {
+        let issue = &self.ws.linear_issue;
+        let date = match (issue.due_date, issue.sla_breaches_at) {
+            (Some(due), _) => due.format("%b %-d").to_string(),
+            (None, Some(_)) => "SLA".to_string(),
+            (None, None) => {
+                return Span::raw(pad_to_width("", width, Alignment::Right));
+            }
+        };
+        let (text, style) = match issue.due_state(self.frame_now) {
+            Some(state) if state != DueState::Upcoming => {
+                let cfg = due_state_config(state);
+                (format!("{} {}", cfg.icon, date), cfg.style)
+            }
+            _ => (date, Style::default().fg(Color::DarkGray)),
+        };
+        Span::styled(pad_to_width(&text, width, Alignment::Right), style)
+    }
+
+    /// Teammate running the session ("me" for local sessions), or the
+    /// issue's assignee on issue rows
+    fn owner_span(&self, width: usize) -> Span<'static> {
+        let (text, style) = match self.session() {
+            Some(session) => match &session.owner {
+                Some(owner) => (owner.name.clone(), Style::default().fg(Color::Cyan)),
+                None => ("me".to_string(), Style::default().fg(Color::DarkGray)),
+            },
+            None => match &self.ws.linear_issue.assignee_name {
+                Some(name) => (name.clone(), Style::default().fg(Color::DarkGray)),
+                None => ("--".to_string(), Style::default().fg(Color::DarkGray)),
+            },
+        };
+        Span::styled(
+            pad_to_width(
+                &truncate_with_ellipsis(&text, width),
+                width,
+                Alignment::Left,
+            ),
+            style,
+        )
+    }
+
     /// Render expanded agent detail panel (multiple lines shown when row is selected)
     ///
     /// Layout mirrors the Issue Details modal for consistency:
@@ -21,6 +21,7 @@ mod helpers {
             started_at: Utc::now(),
             last_activity: Utc::now(),
             window_id: None,
+            owner: None,
             activity: Default::default(),
         }
     }
@@ -36,6 +37,7 @@ mod helpers {
             started_at: Utc::now(),
             last_activity: Utc::now(),
             window_id: None,
+            owner: None,
             activity: Default::default(),
         }
     }
@@ -161,6 +163,7 @@ fn test_cache_sessions_without_directory_are_ignored() {
         started_at: chrono::Utc::now(),
         last_activity: chrono::Utc::now(),
         window_id: None,
+        owner: None,
         activity: Default::default(),
     };
 
@@ -0,0 +1,146 @@
+//! Tests for configurable API base URLs and GitHub Enterprise routing
+//!
+//! Covers the defaults, deriving GraphQL endpoints from REST base URLs,
+//! parsing PR URLs on GitHub hosts only and picking the instance a PR is
+//! served by.
+
+use panopticon::config::{url_host, Config};
+use panopticon::integrations::github::{parse_pr_url, ApiEndpoint, PrUrl};
+
+fn config_with(extra: &str) -> Config {
+    toml::from_str(&format!(
+        r#"
+        [tokens]
+        linear = "lin_api_test"
+        github = "ghp_test"
+        {}
+        "#,
+        extra
+    ))
+    .unwrap()
+}
+
+// ============================================================================
+// Config Tests
+// ============================================================================
+
+#[test]
+fn test_defaults_point_at_public_apis() {
+    let config = config_with("");
+    assert_eq!(config.linear.api_url, "https://api.linear.app/graphql");
+    assert_eq!(config.github.api_url, "https://api.github.com");
+    assert_eq!(
+        config.github.graphql_url(),
+        "https://api.github.com/graphql"
+    );
+    assert_eq!(config.github.web_host(), "github.com");
+    assert_eq!(config.vercel.api_url, "https://api.vercel.com");
+}
+
+#[test]
+fn test_github_enterprise_endpoints() {
+    let config = config_with(
+        r#"
+        [github]
+        api_url = "https://git.acme.dev/api/v3/"
+        "#,
+    );
+    assert_eq!(config.github.rest_url(), "https://git.acme.dev/api/v3");
+    assert_eq!(
+        config.github.graphql_url(),
+        "https://git.acme.dev/api/graphql"
+    );
+    assert_eq!(config.github.web_host(), "git.acme.dev");
+
+    // A local mock server keeps GraphQL next to REST
+    let mock = config_with(
+        r#"
+        [github]
+        api_url = "http://localhost:4000"
+        "#,
+    );
+    assert_eq!(mock.github.graphql_url(), "http://localhost:4000/graphql");
+    assert_eq!(url_host("http://localhost:4000/graphql"), "localhost:4000");
+}
+
+#[test]
+fn test_linear_accounts_use_configured_endpoint() {
+    let config = config_with(
+        r#"
+        [linear]
+        api_url = "http://localhost:4001/graphql"
+
+        [[linear.accounts]]
+        name = "acme"
+        token = "lin_api_acme"
+        "#,
+    );
+    let accounts = config.linear_accounts();
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].api_url, "http://localhost:4001/graphql");
+
+    let single = config_with("").linear_accounts();
+    assert_eq!(single[0].api_url, "https://api.linear.app/graphql");
+}
+
+// ============================================================================
+// PR URL Routing Tests
+// ============================================================================
+
+#[test]
+fn test_parse_pr_url_on_github_hosts() {
+    let config = config_with("");
+    assert_eq!(
+        parse_pr_url(&config, "https://github.com/acme/web/pull/12"),
+        Some(PrUrl {
+            host: "github.com",
+            owner: "acme",
+            repo: "web",
+            number: 12,
+        })
+    );
+    assert!(parse_pr_url(&config, "https://github.com/acme/web/issues/12").is_none());
+    assert!(parse_pr_url(&config, "https://github.com/acme/web/pull/new").is_none());
+    assert!(parse_pr_url(&config, "https://gitlab.com/acme/web/-/merge_requests/3").is_none());
+
+    // The configured Enterprise host is a GitHub host too
+    let ghe = config_with(
+        r#"
+        [github]
+        api_url = "https://git.acme.dev/api/v3"
+        "#,
+    );
+    let pr = parse_pr_url(&ghe, "https://git.acme.dev/acme/web/pull/7/files#diff").unwrap();
+    assert_eq!((pr.host, pr.number), ("git.acme.dev", 7));
+}
+
+#[test]
+fn test_pr_url_on_foreign_host_is_rejected() {
+    // Anyone can attach a link to an issue; the GitHub token must not follow it
+    let config = config_with("");
+    assert!(parse_pr_url(&config, "https://git.acme.dev/acme/web/pull/7").is_none());
+    assert!(parse_pr_url(&config, "https://github.com.evil.example/acme/web/pull/7").is_none());
+    assert_eq!(ApiEndpoint::for_host(&config, "git.acme.dev"), None);
+    assert_eq!(ApiEndpoint::for_host(&config, ""), None);
+}
+
+#[test]
+fn test_pr_requests_route_to_their_instance() {
+    let config = config_with("");
+    assert_eq!(
+        ApiEndpoint::for_host(&config, "github.com"),
+        Some(ApiEndpoint::configured(&config))
+    );
+
+    // The configured Enterprise host uses the configured endpoint as-is
+    let ghe = config_with(
+        r#"
+        [github]
+        api_url = "http://git.acme.dev:8080/api/v3"
+        "#,
+    );
+    assert_eq!(
+        ApiEndpoint::for_host(&ghe, "git.acme.dev:8080").map(|e| e.rest),
+        Some("http://git.acme.dev:8080/api/v3".to_string())
+    );
+}
@@ -1,14 +1,15 @@
 //! Tests for build_visual_items and agent-first section grouping
 //!
 //! Verifies that workstreams are correctly grouped into Agent Sessions and Issues sections,
-//! with proper sorting within each section.
+//! with proper sorting within each section, and that group-by modes split the Issues
+//! section into one collapsible section per group value.
 
 #![allow(clippy::field_reassign_with_default)]
 
 use chrono::Utc;
 use panopticon::data::{
-    AgentSession, AgentStatus, AgentType, AppState, LinearIssue, LinearPriority, LinearStatus,
-    SectionType, VisualItem, Workstream,
+    AgentSession, AgentStatus, AgentType, AppState, GitHubPR, GitHubPRStatus, GroupBy, LinearCycle,
+    LinearIssue, LinearLabel, LinearPriority, LinearStatus, SectionType, VisualItem, Workstream,
 };
 
 fn make_workstream(id: &str, identifier: &str, status: LinearStatus) -> Workstream {
@@ -30,12 +31,17 @@ fn make_workstream(id: &str, identifier: &str, status: LinearStatus) -> Workstre
             assignee_id: None,
             assignee_name: None,
             estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
             attachments: vec![],
             parent: None,
             children: vec![],
+            relations: vec![],
+            workspace: None,
         },
         github_pr: None,
         vercel_deployment: None,
+        linked_prs: vec![],
         agent_sessions: vec![],
         agent_session: None,
         stale: false,
@@ -61,6 +67,7 @@ fn make_workstream_with_agent(
         started_at: Utc::now(),
         last_activity: Utc::now(),
         window_id: None,
+        owner: None,
         activity: Default::default(),
     };
     ws.agent_sessions = vec![session.clone()];
@@ -154,9 +161,12 @@ fn test_build_visual_items_groups_by_section() {
             VisualItem::SectionHeader(section) => match section {
                 SectionType::AgentSessions => agent_sessions_header_found = true,
                 SectionType::Issues => issues_header_found = true,
+                SectionType::Group(name) => panic!("Unexpected group section {}", name),
+                SectionType::ReviewRequests => panic!("Unexpected review requests section"),
             },
             VisualItem::AgentSession { .. } => agent_session_count += 1,
             VisualItem::Workstream(_) => workstream_count += 1,
+            VisualItem::ReviewRequest(_) => panic!("Unexpected review request row"),
         }
     }
 
@@ -240,6 +250,7 @@ fn test_build_visual_items_collapsed_sections() {
                     issues_count += 1;
                 }
             }
+            VisualItem::ReviewRequest(_) => panic!("Unexpected review request row"),
         }
     }
 
@@ -452,7 +463,9 @@ fn test_agent_sessions_appear_before_issues() {
             VisualItem::SectionHeader(SectionType::AgentSessions) => {
                 in_agent_section = true;
             }
-            VisualItem::SectionHeader(SectionType::Issues) => {
+            VisualItem::SectionHeader(
+                SectionType::Issues | SectionType::Group(_) | SectionType::ReviewRequests,
+            ) => {
                 in_agent_section = false;
             }
             VisualItem::AgentSession { ws_idx, .. } => {
@@ -469,6 +482,7 @@ fn test_agent_sessions_appear_before_issues() {
                     found_issue_after_agent = true;
                 }
             }
+            VisualItem::ReviewRequest(_) => panic!("Unexpected review request row"),
         }
     }
 
@@ -488,3 +502,212 @@ fn test_agent_sessions_appear_before_issues() {
         "Issue 2 should be in Issues section"
     );
 }
+
+// ============================================================================
+// Group-by Tests
+// ============================================================================
+
+fn section_layout(state: &AppState, items: &[VisualItem]) -> Vec<(String, Vec<String>)> {
+    let mut layout: Vec<(String, Vec<String>)> = Vec::new();
+    for item in items {
+        match item {
+            VisualItem::SectionHeader(section) => {
+                layout.push((section.display_name().to_string(), vec![]))
+            }
+            VisualItem::Workstream(idx) | VisualItem::AgentSession { ws_idx: idx, .. } => layout
+                .last_mut()
+                .unwrap()
+                .1
+                .push(state.workstreams[*idx].linear_issue.identifier.clone()),
+            VisualItem::ReviewRequest(idx) => layout
+                .last_mut()
+                .unwrap()
+                .1
+                .push(format!("#{}", state.review_requests[*idx].pr.number)),
+        }
+    }
+    layout
+}
+
+fn make_cycle(number: i32) -> LinearCycle {
+    LinearCycle {
+        id: format!("cycle-{}", number),
+        name: format!("Cycle {}", number),
+        number,
+        starts_at: Utc::now(),
+        ends_at: Utc::now(),
+    }
+}
+
+#[test]
+fn test_group_by_project_sorted_with_missing_group_last() {
+    let mut state = AppState::default();
+    state.group_by = GroupBy::Project;
+    state.workstreams = vec![
+        make_workstream("id-0", "TEST-0", LinearStatus::Todo),
+        make_workstream("id-1", "TEST-1", LinearStatus::Todo),
+        make_workstream("id-2", "TEST-2", LinearStatus::Todo),
+        make_workstream_with_agent(
+            "id-3",
+            "TEST-3",
+            LinearStatus::InProgress,
+            LinearPriority::High,
+            AgentStatus::Running,
+        ),
+    ];
+    state.workstreams[0].linear_issue.project = Some("Zeta".to_string());
+    state.workstreams[2].linear_issue.project = Some("Alpha".to_string());
+    state.workstreams[3].linear_issue.project = Some("Alpha".to_string());
+
+    let items = state.build_visual_items(&[0, 1, 2, 3], false);
+    let layout = section_layout(&state, &items);
+
+    assert_eq!(
+        layout,
+        vec![
+            ("Agent Sessions".to_string(), vec!["TEST-3".to_string()]),
+            ("Alpha".to_string(), vec!["TEST-2".to_string()]),
+            ("Zeta".to_string(), vec!["TEST-0".to_string()]),
+            ("No project".to_string(), vec!["TEST-1".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn test_group_by_combines_with_sort_mode() {
+    let mut state = AppState::default();
+    state.group_by = GroupBy::Team;
+    state.sort_mode = panopticon::data::SortMode::ByPriority;
+    state.workstreams = vec![
+        make_workstream_with_priority("id-0", "TEST-0", LinearStatus::Todo, LinearPriority::Low),
+        make_workstream_with_priority("id-1", "TEST-1", LinearStatus::Todo, LinearPriority::Urgent),
+        make_workstream_with_priority("id-2", "TEST-2", LinearStatus::Todo, LinearPriority::High),
+    ];
+
+    let items = state.build_visual_items(&[0, 1, 2], false);
+    assert_eq!(
+        section_layout(&state, &items),
+        vec![(
+            "Test".to_string(),
+            vec![
+                "TEST-1".to_string(),
+                "TEST-2".to_string(),
+                "TEST-0".to_string()
+            ]
+        )]
+    );
+
+    state.sort_mode = panopticon::data::SortMode::ByLinearStatus;
+    let items = state.build_visual_items(&[0, 1, 2], false);
+    assert_eq!(
+        section_layout(&state, &items)[0].1,
+        vec!["TEST-0", "TEST-1", "TEST-2"]
+    );
+}
+
+#[test]
+fn test_group_by_cycle_orders_by_cycle_number() {
+    let mut state = AppState::default();
+    state.group_by = GroupBy::Cycle;
+    state.workstreams = vec![
+        make_workstream("id-0", "TEST-0", LinearStatus::Todo),
+        make_workstream("id-1", "TEST-1", LinearStatus::Todo),
+        make_workstream("id-2", "TEST-2", LinearStatus::Todo),
+    ];
+    state.workstreams[0].linear_issue.cycle = Some(make_cycle(10));
+    state.workstreams[1].linear_issue.cycle = Some(make_cycle(9));
+
+    let items = state.build_visual_items(&[0, 1, 2], false);
+    let names: Vec<String> = section_layout(&state, &items)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(names, vec!["Cycle 9", "Cycle 10", "No cycle"]);
+}
+
+#[test]
+fn test_group_by_label_assignee_and_repository() {
+    let mut state = AppState::default();
+    state.workstreams = vec![
+        make_workstream("id-0", "TEST-0", LinearStatus::Todo),
+        make_workstream("id-1", "TEST-1", LinearStatus::Todo),
+    ];
+    let ws = &mut state.workstreams[0];
+    ws.linear_issue.labels = vec![
+        LinearLabel {
+            name: "bug".to_string(),
+            color: "#f00".to_string(),
+        },
+        LinearLabel {
+            name: "backend".to_string(),
+            color: "#00f".to_string(),
+        },
+    ];
+    ws.linear_issue.assignee_name = Some("Sam".to_string());
+    ws.github_pr = Some(GitHubPR {
+        number: 1,
+        title: "Fix".to_string(),
+        url: "https://github.com/acme/api/pull/1".to_string(),
+        status: GitHubPRStatus::Open,
+        branch: "fix".to_string(),
+        repo: "acme/api".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
+    });
+
+    let group_names = |state: &AppState| -> Vec<String> {
+        let items = state.build_visual_items(&[0, 1], false);
+        section_layout(state, &items)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    };
+
+    state.group_by = GroupBy::Label;
+    assert_eq!(group_names(&state), vec!["bug", "No label"]);
+    state.group_by = GroupBy::Assignee;
+    assert_eq!(group_names(&state), vec!["Sam", "Unassigned"]);
+    state.group_by = GroupBy::Repository;
+    assert_eq!(group_names(&state), vec!["acme/api", "No repository"]);
+}
+
+#[test]
+fn test_group_sections_collapse_independently() {
+    let mut state = AppState::default();
+    state.group_by = GroupBy::Project;
+    state.workstreams = vec![
+        make_workstream("id-0", "TEST-0", LinearStatus::Todo),
+        make_workstream("id-1", "TEST-1", LinearStatus::Todo),
+    ];
+    state.workstreams[0].linear_issue.project = Some("Alpha".to_string());
+    state
+        .collapsed_sections
+        .insert(SectionType::Group("Alpha".to_string()));
+
+    let items = state.build_visual_items(&[0, 1], false);
+    assert_eq!(
+        section_layout(&state, &items),
+        vec![
+            ("Alpha".to_string(), vec![]),
+            ("No project".to_string(), vec!["TEST-1".to_string()]),
+        ]
+    );
+    assert_eq!(
+        state.section_for(&state.workstreams[1]),
+        SectionType::Group("No project".to_string())
+    );
+}
+
+#[test]
+fn test_group_by_from_config_str() {
+    assert_eq!(GroupBy::from_config_str("repo"), Some(GroupBy::Repository));
+    assert_eq!(
+        GroupBy::from_config_str(" Project "),
+        Some(GroupBy::Project)
+    );
+    assert_eq!(GroupBy::from_config_str("none"), Some(GroupBy::None));
+    assert_eq!(GroupBy::from_config_str("bogus"), None);
+}
@@ -0,0 +1,211 @@
+//! Tests for CI check status on linked PRs
+//!
+//! Covers mapping GitHub's rollup and check states, parsing the checks out of
+//! a GraphQL PR node and the failing-first sort mode.
+
+#![allow(clippy::field_reassign_with_default)]
+
+use chrono::Utc;
+use panopticon::data::{
+    AppState, CiStatus, GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority, LinearStatus,
+    SortMode, Workstream,
+};
+use panopticon::integrations::github::parse_authored_prs;
+use serde_json::json;
+
+fn make_workstream(identifier: &str, ci_status: Option<CiStatus>) -> Workstream {
+    Workstream {
+        linear_issue: LinearIssue {
+            id: format!("id-{}", identifier),
+            identifier: identifier.to_string(),
+            title: format!("Issue {}", identifier),
+            description: None,
+            status: LinearStatus::InProgress,
+            priority: LinearPriority::Medium,
+            url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: Some(GitHubPR {
+            number: 1,
+            title: String::new(),
+            url: String::new(),
+            status: GitHubPRStatus::Open,
+            branch: String::new(),
+            repo: "acme/web".to_string(),
+            ci_status,
+            checks: vec![],
+            reviews: vec![],
+            review_threads: vec![],
+            head_sha: None,
+        }),
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    }
+}
+
+// ============================================================================
+// Status Mapping Tests
+// ============================================================================
+
+#[test]
+fn test_ci_status_from_state() {
+    assert_eq!(CiStatus::from_state("SUCCESS"), Some(CiStatus::Passing));
+    assert_eq!(CiStatus::from_state("FAILURE"), Some(CiStatus::Failing));
+    assert_eq!(CiStatus::from_state("ERROR"), Some(CiStatus::Failing));
+    assert_eq!(CiStatus::from_state("PENDING"), Some(CiStatus::Pending));
+    assert_eq!(CiStatus::from_state("EXPECTED"), Some(CiStatus::Pending));
+    assert_eq!(CiStatus::from_state("UNKNOWN"), None);
+}
+
+#[test]
+fn test_ci_status_from_check_run() {
+    let cases = [
+        ("COMPLETED", Some("SUCCESS"), CiStatus::Passing),
+        ("COMPLETED", Some("SKIPPED"), CiStatus::Passing),
+        ("COMPLETED", Some("NEUTRAL"), CiStatus::Passing),
+        ("COMPLETED", Some("FAILURE"), CiStatus::Failing),
+        ("COMPLETED", Some("TIMED_OUT"), CiStatus::Failing),
+        ("COMPLETED", Some("CANCELLED"), CiStatus::Failing),
+        ("IN_PROGRESS", None, CiStatus::Pending),
+        ("QUEUED", None, CiStatus::Pending),
+    ];
+    for (status, conclusion, expected) in cases {
+        assert_eq!(
+            CiStatus::from_check_run(status, conclusion),
+            expected,
+            "{} {:?}",
+            status,
+            conclusion
+        );
+    }
+}
+
+// ============================================================================
+// Parsing Tests
+// ============================================================================
+
+#[test]
+fn test_parse_pr_checks() {
+    let body = json!({
+        "data": { "search": { "nodes": [{
+            "number": 7,
+            "title": "Add caching",
+            "url": "https://github.com/acme/web/pull/7",
+            "state": "OPEN",
+            "isDraft": false,
+            "merged": false,
+            "headRefName": "caching",
+            "repository": { "nameWithOwner": "acme/web" },
+            "commits": { "nodes": [{ "commit": { "statusCheckRollup": {
+                "state": "FAILURE",
+                "contexts": { "nodes": [
+                    {
+                        "name": "test",
+                        "status": "COMPLETED",
+                        "conclusion": "FAILURE",
+                        "detailsUrl": "https://github.com/acme/web/actions/runs/1/job/2"
+                    },
+                    {
+                        "name": "lint",
+                        "status": "COMPLETED",
+                        "conclusion": "SUCCESS",
+                        "detailsUrl": "https://github.com/acme/web/actions/runs/1/job/3"
+                    },
+                    { "name": "e2e", "status": "IN_PROGRESS", "conclusion": null, "detailsUrl": "" },
+                    {
+                        "context": "ci/circleci",
+                        "state": "ERROR",
+                        "targetUrl": "https://circleci.com/gh/acme/web/9"
+                    }
+                ] }
+            } } }] }
+        }] } }
+    });
+
+    let prs = parse_authored_prs(&body);
+    let pr = &prs[0];
+    assert_eq!(pr.ci_status, Some(CiStatus::Failing));
+    assert_eq!(pr.checks.len(), 4);
+    assert_eq!(pr.checks[2].status, CiStatus::Pending);
+    assert_eq!(pr.checks[2].url, None);
+
+    let failing: Vec<(&str, Option<&str>)> = pr
+        .failing_checks()
+        .map(|c| (c.name.as_str(), c.url.as_deref()))
+        .collect();
+    assert_eq!(
+        failing,
+        vec![
+            (
+                "test",
+                Some("https://github.com/acme/web/actions/runs/1/job/2")
+            ),
+            ("ci/circleci", Some("https://circleci.com/gh/acme/web/9")),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_pr_without_checks() {
+    let body = json!({
+        "data": { "search": { "nodes": [{
+            "number": 8,
+            "url": "https://github.com/acme/web/pull/8",
+            "state": "OPEN",
+            "repository": { "nameWithOwner": "acme/web" },
+            "commits": { "nodes": [{ "commit": { "statusCheckRollup": null } }] }
+        }] } }
+    });
+
+    let prs = parse_authored_prs(&body);
+    assert_eq!(prs[0].ci_status, None);
+    assert!(prs[0].checks.is_empty());
+}
+
+// ============================================================================
+// Sorting Tests
+// ============================================================================
+
+#[test]
+fn test_ci_sort_puts_failing_first() {
+    let mut no_pr = make_workstream("TEST-5", None);
+    no_pr.github_pr = None;
+
+    let mut state = AppState::default();
+    state.workstreams = vec![
+        make_workstream("TEST-1", Some(CiStatus::Passing)),
+        make_workstream("TEST-2", None),
+        make_workstream("TEST-3", Some(CiStatus::Failing)),
+        make_workstream("TEST-4", Some(CiStatus::Pending)),
+        no_pr,
+    ];
+    state.sort_mode = SortMode::ByCIStatus;
+
+    let grouped = state.grouped_workstreams();
+    let identifiers: Vec<&str> = grouped[0]
+        .1
+        .iter()
+        .map(|ws| ws.linear_issue.identifier.as_str())
+        .collect();
+    assert_eq!(identifiers[..3], ["TEST-3", "TEST-4", "TEST-1"]);
+    assert_eq!(SortMode::from_config_str("ci"), Some(SortMode::ByCIStatus));
+    assert_eq!(SortMode::from_index(8), Some(SortMode::ByCIStatus));
+}
@@ -0,0 +1,340 @@
+//! Tests for the failed GitHub Actions job log viewer
+//!
+//! Covers finding Actions jobs among failing checks, cleaning up raw logs,
+//! picking the failure excerpt handed to agents, and the modal's key handling.
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    CiCheck, CiStatus, GitHubPR, GitHubPRStatus, JobLog, LinearIssue, LinearPriority, LinearStatus,
+    LinkedPr, Workstream, JOB_LOG_TAIL_LINES,
+};
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+
+fn check(name: &str, status: CiStatus, url: Option<&str>) -> CiCheck {
+    CiCheck {
+        name: name.to_string(),
+        status,
+        url: url.map(str::to_string),
+    }
+}
+
+fn make_pr(number: u64, checks: Vec<CiCheck>) -> GitHubPR {
+    GitHubPR {
+        number,
+        title: format!("PR {}", number),
+        url: format!("https://github.com/acme/web/pull/{}", number),
+        status: GitHubPRStatus::Open,
+        branch: "eng-1-feature".to_string(),
+        repo: "acme/web".to_string(),
+        ci_status: Some(CiStatus::Failing),
+        checks,
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
+    }
+}
+
+fn make_app(prs: Vec<GitHubPR>) -> App {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let mut app = App::new(config);
+
+    let mut ws = Workstream {
+        linear_issue: LinearIssue {
+            id: "id-1".to_string(),
+            identifier: "ENG-1".to_string(),
+            title: "Feature".to_string(),
+            description: None,
+            status: LinearStatus::InReview,
+            priority: LinearPriority::Medium,
+            url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    };
+    ws.set_linked_prs(
+        prs.into_iter()
+            .map(|pr| LinkedPr {
+                pr,
+                vercel_deployment: None,
+            })
+            .collect(),
+    );
+    app.state.workstreams = vec![ws];
+    app.modal_issue_id = Some("id-1".to_string());
+    app.modal = ModalState::LinkMenu {
+        show_links_popup: false,
+    };
+    app
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    }
+}
+
+const JOB_URL: &str = "https://github.com/acme/web/actions/runs/123/job/456";
+
+// ============================================================================
+// Job Discovery Tests
+// ============================================================================
+
+#[test]
+fn test_actions_job_id() {
+    let job = check("test", CiStatus::Failing, Some(JOB_URL));
+    assert_eq!(job.actions_job_id(), Some(456));
+
+    let with_query = check(
+        "test",
+        CiStatus::Failing,
+        Some("https://github.com/acme/web/actions/runs/123/job/789?pr=5#step:4:12"),
+    );
+    assert_eq!(with_query.actions_job_id(), Some(789));
+
+    let external = check(
+        "ci/circleci",
+        CiStatus::Failing,
+        Some("https://circleci.com/gh/acme/web/42"),
+    );
+    assert_eq!(external.actions_job_id(), None);
+    assert_eq!(
+        check("lint", CiStatus::Failing, None).actions_job_id(),
+        None
+    );
+}
+
+#[test]
+fn test_failed_actions_jobs_skip_passing_and_external_checks() {
+    let app = make_app(vec![make_pr(
+        5,
+        vec![
+            check("build", CiStatus::Passing, Some(JOB_URL)),
+            check("test", CiStatus::Failing, Some(JOB_URL)),
+            check(
+                "ci/circleci",
+                CiStatus::Failing,
+                Some("https://circleci.com/gh/acme/web/42"),
+            ),
+        ],
+    )]);
+    let jobs = app.modal_issue().unwrap().failed_actions_jobs();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].0.number, 5);
+    assert_eq!(jobs[0].1.name, "test");
+}
+
+// ============================================================================
+// Log Parsing Tests
+// ============================================================================
+
+#[test]
+fn test_from_raw_strips_timestamps_and_colors() {
+    let pr = make_pr(5, vec![]);
+    let job = check("test", CiStatus::Failing, Some(JOB_URL));
+    let raw = "\u{feff}2024-05-01T12:34:56.1234567Z ##[group]Run cargo test\n\
+               2024-05-01T12:34:57.0000000Z \u{1b}[31merror\u{1b}[0m: could not compile\n\
+               plain line  \n";
+    let log = JobLog::from_raw(&pr, &job, raw);
+    assert_eq!(
+        log.lines,
+        vec![
+            "##[group]Run cargo test",
+            "error: could not compile",
+            "plain line"
+        ]
+    );
+    assert_eq!(log.skipped, 0);
+    assert_eq!(log.name, "test");
+    assert_eq!(log.pr_number, 5);
+}
+
+#[test]
+fn test_from_raw_keeps_tail() {
+    let pr = make_pr(5, vec![]);
+    let job = check("test", CiStatus::Failing, Some(JOB_URL));
+    let raw: String = (0..JOB_LOG_TAIL_LINES + 10)
+        .map(|i| format!("line {}\n", i))
+        .collect();
+    let log = JobLog::from_raw(&pr, &job, &raw);
+    assert_eq!(log.lines.len(), JOB_LOG_TAIL_LINES);
+    assert_eq!(log.skipped, 10);
+    assert_eq!(log.lines[0], "line 10");
+}
+
+#[test]
+fn test_is_error_line() {
+    assert!(JobLog::is_error_line(
+        "##[error]Process completed with exit code 101."
+    ));
+    assert!(JobLog::is_error_line("error[E0308]: mismatched types"));
+    assert!(JobLog::is_error_line(
+        "thread 'main' panicked at src/lib.rs:3:5"
+    ));
+    assert!(JobLog::is_error_line(
+        "FAILED tests/test_api.py::test_login"
+    ));
+    assert!(!JobLog::is_error_line("   Compiling panopticon v0.1.0"));
+    assert!(!JobLog::is_error_line("test result: ok. 12 passed"));
+    assert!(JobLog::is_error_line("Process exited with code 2"));
+    assert!(!JobLog::is_error_line("errors: 0"));
+    assert!(!JobLog::is_error_line("failures: 0"));
+    assert!(!JobLog::is_error_line("Process exited with code 0"));
+}
+
+#[test]
+fn test_failure_excerpt_keeps_errors_with_context() {
+    let pr = make_pr(5, vec![]);
+    let job = check("test", CiStatus::Failing, Some(JOB_URL));
+    let mut raw: Vec<String> = (0..100).map(|i| format!("step {}", i)).collect();
+    raw[20] = "error: could not compile `web`".to_string();
+    raw.push("##[error]Process completed with exit code 101.".to_string());
+    let log = JobLog::from_raw(&pr, &job, &raw.join("\n"));
+
+    let excerpt = log.failure_excerpt();
+    assert_eq!(excerpt[0], "step 17");
+    assert!(excerpt.contains(&"error: could not compile `web`"));
+    assert!(excerpt.contains(&"…"));
+    assert_eq!(
+        excerpt.last(),
+        Some(&"##[error]Process completed with exit code 101.")
+    );
+    assert!(!excerpt.contains(&"step 50"));
+
+    let prompt = log.agent_prompt();
+    assert!(prompt.contains("CI job \"test\" failed on PR #5 (acme/web)"));
+    assert!(prompt.contains("error: could not compile `web`"));
+}
+
+#[test]
+fn test_failure_excerpt_falls_back_to_tail() {
+    let pr = make_pr(5, vec![]);
+    let job = check("test", CiStatus::Failing, Some(JOB_URL));
+    let raw: String = (0..200).map(|i| format!("step {}\n", i)).collect();
+    let log = JobLog::from_raw(&pr, &job, &raw);
+
+    let excerpt = log.failure_excerpt();
+    assert_eq!(excerpt.len(), 60);
+    assert_eq!(excerpt[0], "step 140");
+    assert_eq!(excerpt.last(), Some(&"step 199"));
+}
+
+// ============================================================================
+// Modal Tests
+// ============================================================================
+
+#[test]
+fn test_open_job_log_from_issue_details() {
+    let mut app = make_app(vec![make_pr(
+        5,
+        vec![check("test", CiStatus::Failing, Some(JOB_URL))],
+    )]);
+    let mut input = InputState::new();
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('c'))),
+        Message::OpenJobLog
+    );
+    // Outside a runtime nothing is fetched, but the modal shows as loading
+    app.open_job_log(0);
+    assert!(app.show_job_log());
+    assert!(app.job_log.is_none());
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('j'))),
+        Message::ScrollJobLog(1)
+    );
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('a'))),
+        Message::SendJobLogToAgent
+    );
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Tab)),
+        Message::NextJobLog
+    );
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Esc)),
+        Message::CloseJobLog
+    );
+}
+
+#[test]
+fn test_open_job_log_without_actions_jobs() {
+    let mut app = make_app(vec![make_pr(
+        5,
+        vec![check(
+            "ci/circleci",
+            CiStatus::Failing,
+            Some("https://circleci.com/gh/acme/web/42"),
+        )],
+    )]);
+    app.open_job_log(0);
+    assert!(!app.show_job_log());
+    assert!(app.error_message.is_some());
+}
+
+#[test]
+fn test_scroll_job_log_counts_up_from_tail() {
+    let pr = make_pr(5, vec![check("test", CiStatus::Failing, Some(JOB_URL))]);
+    let job = pr.checks[0].clone();
+    let mut app = make_app(vec![pr.clone()]);
+    app.open_job_log(0);
+    let raw: String = (0..50).map(|i| format!("step {}\n", i)).collect();
+    app.job_log = Some(Ok(JobLog::from_raw(&pr, &job, &raw)));
+
+    app.scroll_job_log(-10);
+    assert_eq!(app.job_log_scroll, 10);
+    app.scroll_job_log(4);
+    assert_eq!(app.job_log_scroll, 6);
+    app.scroll_job_log(-i32::MAX);
+    assert_eq!(app.job_log_scroll, 49);
+    app.scroll_job_log(i32::MAX);
+    assert_eq!(app.job_log_scroll, 0);
+}
@@ -0,0 +1,144 @@
+//! Tests for estimate roll-ups and the header's cycle progress summary.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use panopticon::data::{
+    CycleProgress, LinearChildRef, LinearCycle, LinearIssue, LinearPriority, LinearStatus,
+};
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, 6, 12, 0, 0).unwrap()
+}
+
+fn cycle(id: &str, ends_in_hours: i64) -> LinearCycle {
+    LinearCycle {
+        id: id.to_string(),
+        name: format!("Cycle {}", id),
+        number: 1,
+        starts_at: now() - Duration::days(7),
+        ends_at: now() + Duration::hours(ends_in_hours),
+    }
+}
+
+fn make_issue(identifier: &str, status: LinearStatus, estimate: Option<f32>) -> LinearIssue {
+    LinearIssue {
+        id: format!("id-{}", identifier),
+        identifier: identifier.to_string(),
+        title: format!("Issue {}", identifier),
+        description: None,
+        status,
+        priority: LinearPriority::Medium,
+        url: String::new(),
+        created_at: now(),
+        updated_at: now(),
+        cycle: Some(cycle("current", 50)),
+        labels: vec![],
+        project: None,
+        team: None,
+        assignee_id: None,
+        assignee_name: None,
+        estimate,
+        due_date: None,
+        sla_breaches_at: None,
+        attachments: vec![],
+        parent: None,
+        children: vec![],
+        relations: vec![],
+        workspace: None,
+    }
+}
+
+fn child(identifier: &str, estimate: Option<f32>) -> LinearChildRef {
+    LinearChildRef {
+        id: format!("id-{}", identifier),
+        identifier: identifier.to_string(),
+        title: String::new(),
+        url: String::new(),
+        status: LinearStatus::Todo,
+        priority: LinearPriority::Medium,
+        estimate,
+    }
+}
+
+// ============================================================================
+// Estimate Roll-up Tests
+// ============================================================================
+
+#[test]
+fn test_rolled_up_estimate_adds_sub_issues() {
+    let mut parent = make_issue("ENG-1", LinearStatus::InProgress, Some(2.0));
+    parent.children = vec![
+        child("ENG-2", Some(3.0)),
+        child("ENG-3", None),
+        child("ENG-4", Some(5.0)),
+    ];
+    assert_eq!(parent.children_estimate(), Some(8.0));
+    assert_eq!(parent.rolled_up_estimate(), Some(10.0));
+
+    parent.estimate = None;
+    assert_eq!(parent.rolled_up_estimate(), Some(8.0));
+}
+
+#[test]
+fn test_rolled_up_estimate_without_estimated_children() {
+    let mut issue = make_issue("ENG-1", LinearStatus::Todo, Some(2.0));
+    issue.children = vec![child("ENG-2", None)];
+    assert_eq!(issue.children_estimate(), None);
+    assert_eq!(issue.rolled_up_estimate(), Some(2.0));
+
+    issue.estimate = None;
+    assert_eq!(issue.rolled_up_estimate(), None);
+}
+
+// ============================================================================
+// Cycle Progress Tests
+// ============================================================================
+
+#[test]
+fn test_cycle_progress_counts_points_in_active_cycle() {
+    let mut past = make_issue("ENG-9", LinearStatus::Todo, Some(13.0));
+    past.cycle = Some(LinearCycle {
+        starts_at: now() - Duration::days(21),
+        ends_at: now() - Duration::days(14),
+        ..cycle("previous", 0)
+    });
+    let mut no_cycle = make_issue("ENG-10", LinearStatus::Todo, Some(1.0));
+    no_cycle.cycle = None;
+    let issues = [
+        make_issue("ENG-1", LinearStatus::Done, Some(3.0)),
+        make_issue("ENG-2", LinearStatus::InProgress, Some(5.0)),
+        make_issue("ENG-3", LinearStatus::Todo, None),
+        make_issue("ENG-4", LinearStatus::Canceled, Some(8.0)),
+        past,
+        no_cycle,
+    ];
+
+    let progress = CycleProgress::compute(&issues, now()).unwrap();
+    assert_eq!(progress.cycle.id, "current");
+    assert_eq!(progress.days_left, 3, "50 hours rounds up to 3 days");
+    assert_eq!(progress.points_done, 3.0);
+    assert_eq!(progress.points_total, 8.0);
+    assert_eq!(progress.points_remaining(), 5.0);
+    assert_eq!(progress.issues_done, 1);
+    assert_eq!(progress.issues_total, 3);
+}
+
+#[test]
+fn test_cycle_progress_picks_cycle_with_most_issues() {
+    let mut other = make_issue("WEB-1", LinearStatus::Todo, Some(1.0));
+    other.cycle = Some(cycle("web", 10));
+    let issues = [
+        other,
+        make_issue("ENG-1", LinearStatus::Todo, Some(1.0)),
+        make_issue("ENG-2", LinearStatus::Todo, Some(1.0)),
+    ];
+
+    let progress = CycleProgress::compute(&issues, now()).unwrap();
+    assert_eq!(progress.cycle.id, "current");
+}
+
+#[test]
+fn test_no_cycle_progress_without_active_cycle() {
+    let mut issue = make_issue("ENG-1", LinearStatus::Todo, Some(1.0));
+    issue.cycle = None;
+    assert!(CycleProgress::compute(&[issue], now()).is_none());
+}
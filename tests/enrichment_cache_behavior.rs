@@ -1,6 +1,7 @@
-use panopticon::config::{CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, Tokens, UiConfig, VercelConfig};
-use panopticon::data::{GitHubPR, GitHubPRStatus, VercelDeployment, VercelStatus};
+use panopticon::config::{CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig, Tokens, UiConfig, VercelConfig, WebhookConfig};
+use panopticon::data::{DeploymentSource, GitHubPR, GitHubPRStatus, VercelDeployment, VercelStatus};
 use panopticon::integrations::enrichment_cache;
+use std::time::Duration;
 
 fn test_config() -> Config {
     Config {
@@ -23,6 +24,8 @@ fn test_config() -> Config {
         },
         notifications: NotificationConfig::default(),
         ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
     }
 }
 
@@ -37,6 +40,11 @@ async fn github_cache_roundtrip_in_memory() {
         status: GitHubPRStatus::Open,
         branch: "feat".to_string(),
         repo: "o/r".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
     };
 
     let key = enrichment_cache::normalize_github_pr_url(&pr.url);
@@ -70,6 +78,12 @@ async fn vercel_cache_and_backoff() {
         url: "https://example.vercel.app".to_string(),
         status: VercelStatus::Ready,
         created_at: chrono::Utc::now(),
+        name: None,
+        project_id: None,
+        target: None,
+        commit_sha: None,
+        commit_message: None,
+        source: DeploymentSource::Vercel,
     };
 
     let key = enrichment_cache::vercel_key("o/r", "feat");
@@ -85,3 +99,101 @@ async fn vercel_cache_and_backoff() {
     enrichment_cache::mark_vercel_rate_limited(&config, Some(10)).await;
     assert!(enrichment_cache::vercel_should_backoff(&config).await);
 }
+
+#[tokio::test]
+async fn branch_pr_links_and_misses_expire() {
+    let config = test_config();
+
+    let found = enrichment_cache::branch_pr_key("Acme/Web", "eng-1-login");
+    let missing = enrichment_cache::branch_pr_key("acme/web", "eng-2-spike");
+    assert_eq!(found, "acme/web:eng-1-login");
+
+    enrichment_cache::set_cached_branch_pr(
+        &config,
+        &found,
+        Some("https://github.com/acme/web/pull/7".to_string()),
+    )
+    .await;
+    enrichment_cache::set_cached_branch_pr(&config, &missing, None).await;
+
+    // Both are stale after the TTL; the caller keeps links to open PRs
+    let (url, fresh) = enrichment_cache::get_cached_branch_pr(&config, &found, 0)
+        .await
+        .expect("expected cached link");
+    assert!(!fresh);
+    assert_eq!(url.as_deref(), Some("https://github.com/acme/web/pull/7"));
+    assert!(
+        enrichment_cache::get_cached_branch_pr(&config, &found, 60)
+            .await
+            .unwrap()
+            .1
+    );
+
+    let (url, fresh) = enrichment_cache::get_cached_branch_pr(&config, &missing, 0)
+        .await
+        .expect("expected cached miss");
+    assert!(!fresh);
+    assert!(url.is_none());
+    assert!(
+        enrichment_cache::get_cached_branch_pr(&config, &missing, 60)
+            .await
+            .unwrap()
+            .1
+    );
+}
+
+#[tokio::test]
+async fn ttl_cache_reports_fresh_entries() {
+    let cache: enrichment_cache::AsyncTtlCache<String, u32> = Default::default();
+    let key = "https://github.com/acme/web/pull/1".to_string();
+    assert!(!cache.contains_fresh(&key).await);
+
+    cache.insert(key.clone(), 1, Duration::from_secs(60)).await;
+    assert!(cache.contains_fresh(&key).await);
+
+    cache.insert(key.clone(), 2, Duration::ZERO).await;
+    assert!(!cache.contains_fresh(&key).await);
+}
+
+#[test]
+fn validators_round_trip_through_headers() {
+    let mut headers = reqwest::header::HeaderMap::new();
+    assert!(enrichment_cache::Validators::from_headers(&headers).is_none());
+
+    headers.insert(reqwest::header::ETAG, "W/\"abc\"".parse().unwrap());
+    let validators = enrichment_cache::Validators::from_headers(&headers).unwrap();
+    assert_eq!(validators.etag.as_deref(), Some("W/\"abc\""));
+    assert!(validators.last_modified.is_none());
+
+    let request = validators
+        .apply(reqwest::Client::new().get("https://api.github.com/repos/o/r/pulls/1"))
+        .build()
+        .unwrap();
+    assert_eq!(request.headers()[reqwest::header::IF_NONE_MATCH], "W/\"abc\"");
+    assert!(!request.headers().contains_key(reqwest::header::IF_MODIFIED_SINCE));
+}
+
+#[tokio::test]
+async fn conditional_results_are_kept_per_url() {
+    let config = test_config();
+    let url = "https://api.vercel.com/v6/deployments?limit=1&meta-githubCommitRef=feat";
+    let validators = enrichment_cache::Validators {
+        etag: None,
+        last_modified: Some("Wed, 21 Oct 2026 07:28:00 GMT".to_string()),
+    };
+
+    assert!(enrichment_cache::get_vercel_conditional(&config, url).await.is_none());
+    enrichment_cache::set_vercel_conditional(&config, url, validators.clone(), None).await;
+
+    let (cached_validators, deployment) = enrichment_cache::get_vercel_conditional(&config, url)
+        .await
+        .expect("expected conditional entry");
+    assert_eq!(cached_validators, validators);
+    assert!(deployment.is_none());
+
+    let (github_before, vercel_before) = enrichment_cache::not_modified_counts();
+    enrichment_cache::record_vercel_not_modified();
+    let (github_after, vercel_after) = enrichment_cache::not_modified_counts();
+    assert_eq!(vercel_after, vercel_before + 1);
+    assert!(github_after >= github_before);
+}
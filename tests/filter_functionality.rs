@@ -6,14 +6,22 @@
 //! 3. Filter application correctly filters workstreams by project
 //! 4. clear_all_filters clears all filter types
 //! 5. has_active_filters includes new filter types
+//! 6. Workspace filter keeps only issues from the selected Linear accounts
+//! 7. Blocked mode hides issues waiting on open blockers
+//! 8. Overdue filter keeps only open issues past their due date or SLA
+//! 9. Assignee filter applies to agent rows by session owner (team view)
 
-use chrono::{TimeZone, Utc};
+use chrono::{Duration, TimeZone, Utc};
 use panopticon::config::{
-    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, Tokens,
-    UiConfig, VercelConfig,
+    CacheConfig, Config, GithubConfig, LinearAccount, LinearConfig, NotificationConfig,
+    PollingConfig, TeamConfig, Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    AgentActivity, AgentSession, AgentStatus, AgentType, BlockedMode, DueState, LinearIssue,
+    LinearPriority, LinearRelationRef, LinearStatus, RelationKind, VisualItem, Workstream,
 };
-use panopticon::data::{LinearIssue, LinearPriority, LinearStatus, Workstream};
 use panopticon::integrations::linear::{ProjectInfo, TeamMemberInfo};
+use panopticon::integrations::team_share::TeamSnapshot;
 use panopticon::tui::App;
 
 /// Create a minimal config for testing
@@ -31,6 +39,8 @@ fn test_config() -> Config {
         cache: CacheConfig::default(),
         notifications: NotificationConfig::default(),
         ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
     }
 }
 
@@ -52,14 +62,19 @@ fn make_workstream_with_project(id: &str, identifier: &str, project: Option<&str
             assignee_id: None,
             assignee_name: None,
             estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
             created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             attachments: Vec::new(),
             parent: None,
             children: Vec::new(),
+            relations: vec![],
+            workspace: None,
         },
         github_pr: None,
         vercel_deployment: None,
+        linked_prs: vec![],
         agent_sessions: vec![],
         agent_session: None,
         stale: false,
@@ -85,10 +100,12 @@ fn test_toggle_project_filter_adds_project() {
         ProjectInfo {
             id: "proj-1".to_string(),
             name: "Project Alpha".to_string(),
+            workspace: None,
         },
         ProjectInfo {
             id: "proj-2".to_string(),
             name: "Project Beta".to_string(),
+            workspace: None,
         },
     ];
 
@@ -109,6 +126,7 @@ fn test_toggle_project_filter_removes_project() {
     app.available_projects = vec![ProjectInfo {
         id: "proj-1".to_string(),
         name: "Project Alpha".to_string(),
+        workspace: None,
     }];
 
     // Add filter
@@ -129,6 +147,7 @@ fn test_toggle_project_filter_invalid_index_ignored() {
     app.available_projects = vec![ProjectInfo {
         id: "proj-1".to_string(),
         name: "Project Alpha".to_string(),
+        workspace: None,
     }];
 
     // Invalid index should be ignored
@@ -234,10 +253,12 @@ fn test_apply_filters_by_project() {
         ProjectInfo {
             id: "proj-alpha".to_string(),
             name: "Project Alpha".to_string(),
+            workspace: None,
         },
         ProjectInfo {
             id: "proj-beta".to_string(),
             name: "Project Beta".to_string(),
+            workspace: None,
         },
     ];
 
@@ -272,14 +293,17 @@ fn test_apply_filters_multiple_projects() {
         ProjectInfo {
             id: "proj-alpha".to_string(),
             name: "Project Alpha".to_string(),
+            workspace: None,
         },
         ProjectInfo {
             id: "proj-beta".to_string(),
             name: "Project Beta".to_string(),
+            workspace: None,
         },
         ProjectInfo {
             id: "proj-gamma".to_string(),
             name: "Project Gamma".to_string(),
+            workspace: None,
         },
     ];
 
@@ -380,3 +404,269 @@ fn test_has_active_filters_all_types() {
     app.filter_assignees.clear();
     assert!(!app.has_active_filters());
 }
+
+// ============================================================================
+// Workspace Filter Tests
+// ============================================================================
+
+fn multi_workspace_config() -> Config {
+    let mut config = test_config();
+    config.linear.accounts = vec![
+        LinearAccount {
+            name: "acme".to_string(),
+            token: "lin_api_acme".to_string(),
+            api_url: String::new(),
+            webhook_secret: None,
+        },
+        LinearAccount {
+            name: "side".to_string(),
+            token: "lin_api_side".to_string(),
+            api_url: String::new(),
+            webhook_secret: None,
+        },
+    ];
+    config
+}
+
+fn make_workspace_workstream(id: &str, workspace: &str) -> Workstream {
+    let mut ws = make_simple_workstream(id, &format!("TEST-{}", id));
+    ws.linear_issue.workspace = Some(workspace.to_string());
+    ws
+}
+
+#[test]
+fn test_available_workspaces_only_with_multiple_accounts() {
+    assert!(App::new(test_config()).available_workspaces.is_empty());
+
+    let app = App::new(multi_workspace_config());
+    assert_eq!(app.available_workspaces, vec!["acme", "side"]);
+}
+
+#[test]
+fn test_apply_filters_by_workspace() {
+    let mut app = App::new(multi_workspace_config());
+    app.state.workstreams = vec![
+        make_workspace_workstream("1", "acme"),
+        make_workspace_workstream("2", "side"),
+        make_workspace_workstream("3", "acme"),
+    ];
+
+    app.toggle_workspace_filter(1);
+    assert!(app.has_active_filters());
+    assert_eq!(app.filtered_indices, vec![1]);
+
+    app.toggle_workspace_filter(0);
+    assert_eq!(app.filtered_indices.len(), 3);
+
+    app.clear_workspace_filters();
+    assert!(!app.has_active_filters());
+    assert_eq!(app.filtered_indices.len(), 3);
+}
+
+#[test]
+fn test_linear_token_routed_by_workspace() {
+    let config = multi_workspace_config();
+    assert_eq!(config.linear_token_for(Some("side")), "lin_api_side");
+    // Unknown/untagged issues fall back to the first account
+    assert_eq!(config.linear_token_for(None), "lin_api_acme");
+
+    let mut single = test_config();
+    single.tokens.linear = "lin_api_default".to_string();
+    assert_eq!(single.linear_token_for(None), "lin_api_default");
+    assert_eq!(single.linear_accounts().len(), 1);
+}
+
+// ============================================================================
+// Blocked Filter Tests
+// ============================================================================
+
+fn blocked_by(identifier: &str, status: LinearStatus) -> LinearRelationRef {
+    LinearRelationRef {
+        kind: RelationKind::BlockedBy,
+        id: format!("id-{}", identifier),
+        identifier: identifier.to_string(),
+        title: format!("Issue {}", identifier),
+        url: String::new(),
+        status,
+    }
+}
+
+#[test]
+fn test_blocked_mode_hides_only_open_blockers() {
+    let mut app = App::new(test_config());
+    let mut blocked = make_simple_workstream("1", "TEST-1");
+    blocked.linear_issue.relations = vec![blocked_by("TEST-9", LinearStatus::InProgress)];
+    let mut unblocked = make_simple_workstream("2", "TEST-2");
+    unblocked.linear_issue.relations = vec![blocked_by("TEST-8", LinearStatus::Done)];
+    let mut blocking = make_simple_workstream("3", "TEST-3");
+    blocking.linear_issue.relations = vec![LinearRelationRef {
+        kind: RelationKind::Blocks,
+        ..blocked_by("TEST-7", LinearStatus::Todo)
+    }];
+    app.state.workstreams = vec![blocked, unblocked, blocking];
+
+    app.apply_filters();
+    assert_eq!(app.filtered_indices, vec![0, 1, 2]);
+
+    // Show -> Demote keeps everything, Demote -> Hide drops the blocked issue
+    app.cycle_blocked_mode();
+    assert_eq!(app.state.blocked_mode, BlockedMode::Demote);
+    assert_eq!(app.filtered_indices, vec![0, 1, 2]);
+
+    app.cycle_blocked_mode();
+    assert_eq!(app.state.blocked_mode, BlockedMode::Hide);
+    assert_eq!(app.filtered_indices, vec![1, 2]);
+
+    app.cycle_blocked_mode();
+    assert_eq!(app.state.blocked_mode, BlockedMode::Show);
+    assert_eq!(app.filtered_indices, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_blocked_mode_from_config() {
+    let mut config = test_config();
+    config.ui.blocked_issues = "hide".to_string();
+    let app = App::new(config);
+    assert_eq!(app.state.blocked_mode, BlockedMode::Hide);
+}
+
+#[test]
+fn test_overdue_filter_keeps_open_issues_past_deadline() {
+    let now = Utc::now();
+    let mut app = App::new(test_config());
+    let mut overdue = make_simple_workstream("1", "TEST-1");
+    overdue.linear_issue.due_date = Some((now - Duration::days(3)).date_naive());
+    let mut breached = make_simple_workstream("2", "TEST-2");
+    breached.linear_issue.sla_breaches_at = Some(now - Duration::hours(1));
+    let mut due_soon = make_simple_workstream("3", "TEST-3");
+    due_soon.linear_issue.sla_breaches_at = Some(now + Duration::hours(5));
+    let mut finished = make_simple_workstream("4", "TEST-4");
+    finished.linear_issue.due_date = overdue.linear_issue.due_date;
+    finished.linear_issue.status = LinearStatus::Done;
+    let no_deadline = make_simple_workstream("5", "TEST-5");
+    app.show_completed = true;
+    app.state.workstreams = vec![overdue, breached, due_soon, finished, no_deadline];
+
+    assert_eq!(
+        app.state.workstreams[2].linear_issue.due_state(now),
+        Some(DueState::DueSoon)
+    );
+    assert_eq!(app.state.workstreams[3].linear_issue.due_state(now), None);
+
+    app.toggle_overdue_filter();
+    assert!(app.has_active_filters());
+    assert_eq!(app.filtered_indices, vec![0, 1]);
+
+    app.clear_all_filters();
+    assert!(!app.filter_overdue);
+    assert_eq!(app.filtered_indices, vec![0, 1, 2, 3, 4]);
+}
+
+// ============================================================================
+// Team View Tests
+// ============================================================================
+
+fn make_agent_session(id: &str) -> AgentSession {
+    AgentSession {
+        id: id.to_string(),
+        agent_type: AgentType::ClaudeCode,
+        status: AgentStatus::Running,
+        working_directory: None,
+        git_branch: Some(format!("{}-branch", id)),
+        last_output: None,
+        started_at: Utc::now(),
+        last_activity: Utc::now(),
+        window_id: None,
+        activity: AgentActivity::default(),
+        owner: None,
+    }
+}
+
+fn session_rows(app: &App) -> Vec<String> {
+    app.visual_items
+        .iter()
+        .filter_map(|item| match item {
+            VisualItem::AgentSession {
+                ws_idx,
+                session_idx,
+            } => Some(
+                app.state.workstreams[*ws_idx].agent_sessions[*session_idx]
+                    .id
+                    .clone(),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_assignee_filter_applies_to_agent_rows_by_owner() {
+    let mut config = test_config();
+    config.team.enabled = true;
+    let mut app = App::new(config);
+    app.current_user_ids = vec!["user-me".to_string()];
+    app.available_team_members = vec![TeamMemberInfo {
+        id: "user-bob".to_string(),
+        name: "Bob".to_string(),
+        display_name: None,
+        email: None,
+    }];
+
+    // My issue has my session; Bob runs one on it too and one elsewhere
+    let mut mine = make_simple_workstream("1", "TEST-1");
+    mine.linear_issue.assignee_id = Some("user-me".to_string());
+    mine.agent_sessions = vec![make_agent_session("local")];
+    mine.agent_session = mine.agent_sessions.first().cloned();
+    let mut bob_ws = make_simple_workstream("2", "TEST-2");
+    bob_ws.agent_sessions = vec![make_agent_session("one"), make_agent_session("two")];
+    let bob =
+        TeamSnapshot::from_workstreams("bob", Some("user-bob".to_string()), &[bob_ws], Utc::now());
+    let mut bob_on_mine = bob.clone();
+    bob_on_mine.sessions[0].issue_identifier = Some("TEST-1".to_string());
+    app.state.workstreams = vec![mine];
+    app.team_snapshots.insert("bob".to_string(), bob_on_mine);
+    panopticon::integrations::team_share::merge(
+        &mut app.state.workstreams,
+        app.team_snapshots.values(),
+    );
+    app.apply_filters();
+    app.rebuild_visual_items();
+    assert_eq!(session_rows(&app).len(), 3);
+
+    // "Me" keeps only the session running here, even on my own issue
+    app.toggle_assignee_filter(0);
+    assert_eq!(session_rows(&app), vec!["local"]);
+
+    // Bob's rows follow him, including the one on my issue
+    app.toggle_assignee_filter(0);
+    app.toggle_assignee_filter(2);
+    let mut rows = session_rows(&app);
+    rows.sort();
+    assert_eq!(rows, vec!["bob:one", "bob:two"]);
+}
+
+#[test]
+fn test_assignee_filter_uses_issue_assignee_without_team_mode() {
+    let mut app = App::new(test_config());
+    app.current_user_ids = vec!["user-me".to_string()];
+    app.available_team_members = vec![TeamMemberInfo {
+        id: "user-bob".to_string(),
+        name: "Bob".to_string(),
+        display_name: None,
+        email: None,
+    }];
+
+    // Bob's issue has a session running on this machine
+    let mut bobs = make_simple_workstream("1", "TEST-1");
+    bobs.linear_issue.assignee_id = Some("user-bob".to_string());
+    bobs.agent_sessions = vec![make_agent_session("local")];
+    bobs.agent_session = bobs.agent_sessions.first().cloned();
+    let mut mine = make_simple_workstream("2", "TEST-2");
+    mine.linear_issue.assignee_id = Some("user-me".to_string());
+    app.state.workstreams = vec![bobs, mine];
+
+    // Selecting Bob keeps his issue, session and all
+    app.toggle_assignee_filter(2);
+    assert_eq!(app.filtered_indices, vec![0]);
+    assert_eq!(session_rows(&app), vec!["local"]);
+}
@@ -6,9 +6,10 @@
 
 #![allow(clippy::field_reassign_with_default)]
 
-use chrono::{TimeZone, Utc};
+use chrono::{NaiveDate, TimeZone, Utc};
 use panopticon::data::{
-    AppState, LinearIssue, LinearParentRef, LinearPriority, LinearStatus, SortMode, Workstream,
+    AppState, BlockedMode, LinearIssue, LinearParentRef, LinearPriority, LinearRelationRef,
+    LinearStatus, RelationKind, SortMode, Workstream,
 };
 
 /// Create a minimal workstream for testing
@@ -35,6 +36,8 @@ fn make_workstream(
             assignee_id: None,
             assignee_name: None,
             estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
             created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             attachments: Vec::new(),
@@ -45,9 +48,12 @@ fn make_workstream(
                 url: "https://linear.app/test".to_string(),
             }),
             children: Vec::new(),
+            relations: vec![],
+            workspace: None,
         },
         github_pr: None,
         vercel_deployment: None,
+        linked_prs: vec![],
         agent_sessions: vec![],
         agent_session: None,
         stale: false,
@@ -408,6 +414,67 @@ fn test_hierarchical_sort_by_identifier() {
     assert_eq!(ids, vec!["ZZZ-1", "AAA-2", "MMM-3"]);
 }
 
+#[test]
+fn test_demoted_blocked_issues_sort_last() {
+    let mut blocked = make_workstream("1", "TEST-1", LinearPriority::Urgent, None, None);
+    blocked.linear_issue.relations = vec![LinearRelationRef {
+        kind: RelationKind::BlockedBy,
+        id: "9".to_string(),
+        identifier: "TEST-9".to_string(),
+        title: "Blocker".to_string(),
+        url: String::new(),
+        status: LinearStatus::Todo,
+    }];
+    let ws2 = make_workstream("2", "TEST-2", LinearPriority::Low, None, None);
+    let ws3 = make_workstream("3", "TEST-3", LinearPriority::High, None, None);
+
+    let mut state = AppState::default();
+    state.workstreams = vec![blocked, ws2, ws3];
+    state.sort_mode = SortMode::ByPriority;
+
+    let grouped = state.grouped_workstreams();
+    assert_eq!(
+        get_identifiers(&grouped[0].1),
+        vec!["TEST-1", "TEST-3", "TEST-2"]
+    );
+
+    state.blocked_mode = BlockedMode::Demote;
+    let grouped = state.grouped_workstreams();
+    assert_eq!(
+        get_identifiers(&grouped[0].1),
+        vec!["TEST-3", "TEST-2", "TEST-1"]
+    );
+
+    // A finished blocker no longer blocks
+    state.workstreams[0].linear_issue.relations[0].status = LinearStatus::Done;
+    let grouped = state.grouped_workstreams();
+    assert_eq!(
+        get_identifiers(&grouped[0].1),
+        vec!["TEST-1", "TEST-3", "TEST-2"]
+    );
+}
+
+#[test]
+fn test_due_date_sort_puts_soonest_deadline_first() {
+    let mut later = make_workstream("1", "TEST-1", LinearPriority::Urgent, None, None);
+    later.linear_issue.due_date = NaiveDate::from_ymd_opt(2024, 3, 20);
+    let no_deadline = make_workstream("2", "TEST-2", LinearPriority::Urgent, None, None);
+    let mut sla = make_workstream("3", "TEST-3", LinearPriority::Low, None, None);
+    sla.linear_issue.sla_breaches_at = Some(Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap());
+    let mut sooner = make_workstream("4", "TEST-4", LinearPriority::Low, None, None);
+    sooner.linear_issue.due_date = NaiveDate::from_ymd_opt(2024, 3, 15);
+
+    let mut state = AppState::default();
+    state.workstreams = vec![later, no_deadline, sla, sooner];
+    state.sort_mode = SortMode::ByDueDate;
+
+    let grouped = state.grouped_workstreams();
+    assert_eq!(
+        get_identifiers(&grouped[0].1),
+        vec!["TEST-3", "TEST-4", "TEST-1", "TEST-2"]
+    );
+}
+
 // ============================================================================
 // Regression Tests
 // ============================================================================
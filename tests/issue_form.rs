@@ -0,0 +1,425 @@
+//! Tests for the quick-capture create-issue form
+//!
+//! Covers prefilling from unlinked sessions, field editing, validation into
+//! an `issueCreate` input, the created issue taking over the session row, and
+//! dropping links to sessions that are gone.
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    AgentActivity, AgentSession, AgentStatus, AgentType, LinearIssue, LinearPriority, LinearStatus,
+    VisualItem, Workstream,
+};
+use panopticon::integrations::linear::{LabelInfo, ProjectInfo, TeamInfo};
+use panopticon::integrations::session_links::{self, SessionLink, SessionLinks};
+use panopticon::tui::input::InputState;
+use panopticon::tui::issue_form::{IssueForm, IssueFormField};
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn test_config() -> Config {
+    Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    }
+}
+
+fn make_session(prompt: Option<&str>, branch: Option<&str>) -> AgentSession {
+    AgentSession {
+        id: "session-1".to_string(),
+        agent_type: AgentType::ClaudeCode,
+        status: AgentStatus::Running,
+        working_directory: Some("/home/me/Projects/sandbox".to_string()),
+        git_branch: branch.map(String::from),
+        last_output: None,
+        started_at: Utc::now(),
+        last_activity: Utc::now(),
+        window_id: None,
+        owner: None,
+        activity: AgentActivity {
+            last_prompt: prompt.map(String::from),
+            ..Default::default()
+        },
+    }
+}
+
+fn make_issue(id: &str, identifier: &str) -> LinearIssue {
+    LinearIssue {
+        id: id.to_string(),
+        identifier: identifier.to_string(),
+        title: format!("Issue {}", identifier),
+        description: None,
+        status: LinearStatus::Todo,
+        priority: LinearPriority::Medium,
+        url: format!("https://linear.app/test/issue/{}", identifier),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        cycle: None,
+        labels: vec![],
+        project: None,
+        team: Some("Platform".to_string()),
+        assignee_id: None,
+        assignee_name: None,
+        estimate: None,
+        due_date: None,
+        sla_breaches_at: None,
+        attachments: vec![],
+        parent: None,
+        children: vec![],
+        relations: vec![],
+        workspace: None,
+    }
+}
+
+fn make_workstream(issue: LinearIssue, session: Option<AgentSession>) -> Workstream {
+    Workstream {
+        linear_issue: issue,
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: session.iter().cloned().collect(),
+        agent_session: session,
+        stale: false,
+    }
+}
+
+/// Placeholder row the refresh creates for a session without an issue
+fn make_unlinked_workstream(session: AgentSession) -> Workstream {
+    let mut issue = make_issue(&format!("unlinked-{}", session.id), "");
+    issue.title = "~/Projects/sandbox (main)".to_string();
+    issue.team = None;
+    make_workstream(issue, Some(session))
+}
+
+fn teams() -> Vec<TeamInfo> {
+    vec![
+        TeamInfo {
+            id: "team-eng".to_string(),
+            key: "ENG".to_string(),
+            name: "Engineering".to_string(),
+            labels: vec![
+                LabelInfo {
+                    id: "label-bug".to_string(),
+                    name: "bug".to_string(),
+                },
+                LabelInfo {
+                    id: "label-feature".to_string(),
+                    name: "feature".to_string(),
+                },
+            ],
+            workspace: None,
+        },
+        TeamInfo {
+            id: "team-plat".to_string(),
+            key: "PLAT".to_string(),
+            name: "Platform".to_string(),
+            labels: vec![],
+            workspace: Some("side".to_string()),
+        },
+    ]
+}
+
+fn projects() -> Vec<ProjectInfo> {
+    vec![
+        ProjectInfo {
+            id: "project-1".to_string(),
+            name: "Launch".to_string(),
+            workspace: None,
+        },
+        ProjectInfo {
+            id: "project-side".to_string(),
+            name: "Side launch".to_string(),
+            workspace: Some("side".to_string()),
+        },
+    ]
+}
+
+fn key_event(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    }
+}
+
+// ============================================================================
+// Prefill Tests
+// ============================================================================
+
+#[test]
+fn test_prefill_from_session_prompt_and_branch() {
+    let session = make_session(
+        Some("Add retry logic to the webhook sender\nand log failures"),
+        Some("feat/webhook-retries"),
+    );
+    let form = IssueForm::for_session(&session);
+
+    assert_eq!(form.title, "Add retry logic to the webhook sender");
+    let description = form.description.unwrap();
+    assert!(description.contains("and log failures"));
+    assert!(description.contains("Branch: `feat/webhook-retries`"));
+    assert_eq!(form.session.unwrap().id, "session-1");
+}
+
+#[test]
+fn test_prefill_title_from_branch_without_prompt() {
+    let form = IssueForm::for_session(&make_session(None, Some("fix/flaky_login-test")));
+    assert_eq!(form.title, "Flaky login test");
+
+    let form = IssueForm::for_session(&make_session(None, Some("main")));
+    assert!(form.title.is_empty());
+}
+
+#[test]
+fn test_long_prompt_is_shortened_at_word_boundary() {
+    let prompt = "Investigate why the nightly export job sometimes produces duplicated rows \
+                  for customers with more than one billing account";
+    let form = IssueForm::for_session(&make_session(Some(prompt), None));
+
+    assert!(form.title.ends_with('…'));
+    assert!(form.title.chars().count() <= 81);
+    assert!(prompt.starts_with(form.title.trim_end_matches('…')));
+}
+
+// ============================================================================
+// Editing and Validation Tests
+// ============================================================================
+
+#[test]
+fn test_pickers_cycle_and_build_input() {
+    let teams = teams();
+    let projects = projects();
+    let workstreams = vec![make_workstream(make_issue("issue-100", "ENG-100"), None)];
+
+    let mut form = IssueForm::new();
+    for c in "Fix login".chars() {
+        form.input_char(c, &teams);
+    }
+
+    form.focus = IssueFormField::Priority;
+    form.cycle(1, &teams, &projects);
+    form.cycle(1, &teams, &projects);
+    assert_eq!(form.priority, LinearPriority::High);
+
+    form.focus = IssueFormField::Project;
+    form.cycle(1, &teams, &projects);
+    assert_eq!(form.project_idx, Some(0));
+
+    form.focus = IssueFormField::Labels;
+    form.cycle(1, &teams, &projects);
+    form.input_char(' ', &teams);
+    assert_eq!(form.label_ids, vec!["label-feature"]);
+
+    form.focus = IssueFormField::Parent;
+    for c in "eng-100".chars() {
+        form.input_char(c, &teams);
+    }
+
+    let (workspace, input) = form.build(&teams, &projects, &workstreams).unwrap();
+    assert_eq!(workspace, None);
+    assert_eq!(input.team_id, "team-eng");
+    assert_eq!(input.title, "Fix login");
+    assert_eq!(input.priority, LinearPriority::High);
+    assert_eq!(input.project_id.as_deref(), Some("project-1"));
+    assert_eq!(input.label_ids, vec!["label-feature"]);
+    assert_eq!(input.parent_id.as_deref(), Some("issue-100"));
+}
+
+#[test]
+fn test_changing_team_clears_labels_and_routes_workspace() {
+    let teams = teams();
+    let mut form = IssueForm::new();
+    form.title = "Something".to_string();
+    form.focus = IssueFormField::Labels;
+    form.input_char(' ', &teams);
+    assert_eq!(form.label_ids.len(), 1);
+
+    form.focus = IssueFormField::Team;
+    form.cycle(1, &teams, &[]);
+    assert!(form.label_ids.is_empty());
+
+    let (workspace, input) = form.build(&teams, &[], &[]).unwrap();
+    assert_eq!(workspace.as_deref(), Some("side"));
+    assert_eq!(input.team_id, "team-plat");
+}
+
+#[test]
+fn test_project_picker_follows_team_workspace() {
+    let teams = teams();
+    let projects = projects();
+    let mut form = IssueForm::new();
+    form.title = "Something".to_string();
+
+    // Engineering only sees its own workspace's project
+    form.focus = IssueFormField::Project;
+    form.cycle(1, &teams, &projects);
+    form.cycle(1, &teams, &projects);
+    assert_eq!(form.project_idx, None);
+    form.cycle(1, &teams, &projects);
+    assert_eq!(form.project(&teams, &projects).unwrap().id, "project-1");
+
+    // Switching to a team in another workspace drops the project
+    form.focus = IssueFormField::Team;
+    form.cycle(1, &teams, &projects);
+    assert_eq!(form.project_idx, None);
+
+    form.focus = IssueFormField::Project;
+    form.cycle(1, &teams, &projects);
+    let (workspace, input) = form.build(&teams, &projects, &[]).unwrap();
+    assert_eq!(workspace.as_deref(), Some("side"));
+    assert_eq!(input.project_id.as_deref(), Some("project-side"));
+}
+
+#[test]
+fn test_build_rejects_missing_title_and_unknown_parent() {
+    let teams = teams();
+    let mut form = IssueForm::new();
+    assert!(form.build(&teams, &[], &[]).is_err());
+
+    form.title = "Title".to_string();
+    assert!(form.build(&[], &[], &[]).is_err(), "no teams loaded");
+
+    form.parent = "NOPE-1".to_string();
+    let err = form.build(&teams, &[], &[]).unwrap_err();
+    assert!(err.contains("NOPE-1"));
+}
+
+// ============================================================================
+// App Integration Tests
+// ============================================================================
+
+#[test]
+fn test_open_form_on_unlinked_session_row() {
+    let mut app = App::new(test_config());
+    app.available_teams = teams();
+    app.state.workstreams = vec![make_unlinked_workstream(make_session(
+        Some("Write docs"),
+        Some("main"),
+    ))];
+    app.apply_filters();
+    app.rebuild_visual_items();
+
+    app.open_issue_form();
+    assert!(app.show_issue_form());
+    let form = app.issue_form.as_ref().unwrap();
+    assert_eq!(form.title, "Write docs");
+    assert!(form.session.is_some());
+
+    // Typing goes to the form, not to normal-mode bindings
+    let mut input = InputState::new();
+    let msg = dispatch(&app, &mut input, key_event(KeyCode::Char('q')));
+    assert_eq!(msg, Message::IssueFormInput('q'));
+    let msg = dispatch(&app, &mut input, key_event(KeyCode::Esc));
+    assert_eq!(msg, Message::CloseModal);
+}
+
+#[test]
+fn test_open_form_preselects_team_of_selected_issue() {
+    let mut app = App::new(test_config());
+    app.available_teams = teams();
+    app.state.workstreams = vec![make_workstream(make_issue("issue-1", "PLAT-1"), None)];
+    app.apply_filters();
+    app.rebuild_visual_items();
+
+    app.open_issue_form();
+    let form = app.issue_form.as_ref().unwrap();
+    assert_eq!(form.team_idx, 1);
+    assert!(form.session.is_none());
+}
+
+#[test]
+fn test_created_issue_takes_over_session_row() {
+    let session = make_session(Some("Write docs"), Some("main"));
+    let mut app = App::new(test_config());
+    app.state.workstreams = vec![
+        make_workstream(make_issue("issue-1", "ENG-1"), None),
+        make_unlinked_workstream(session.clone()),
+    ];
+    app.apply_filters();
+    app.rebuild_visual_items();
+    app.issue_form = Some(IssueForm::for_session(&session));
+    app.modal = ModalState::IssueForm;
+
+    app.finish_issue_create(Ok(make_issue("issue-2", "ENG-2")), Some(session));
+
+    assert!(app.modal.is_none());
+    assert!(app.issue_form.is_none());
+    assert_eq!(app.state.workstreams.len(), 2);
+    let ws = &app.state.workstreams[1];
+    assert_eq!(ws.linear_issue.identifier, "ENG-2");
+    assert_eq!(ws.agent_sessions[0].id, "session-1");
+
+    // Selection follows the new issue
+    match app.visual_items[app.visual_selected] {
+        VisualItem::AgentSession { ws_idx, .. } => assert_eq!(ws_idx, 1),
+        ref other => panic!("expected session row, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_failed_create_keeps_form_open_with_error() {
+    let mut app = App::new(test_config());
+    let mut form = IssueForm::new();
+    form.submitting = true;
+    app.issue_form = Some(form);
+    app.modal = ModalState::IssueForm;
+
+    app.finish_issue_create(Err("Linear rejected issue: bad team".to_string()), None);
+
+    assert!(app.show_issue_form());
+    let form = app.issue_form.as_ref().unwrap();
+    assert!(!form.submitting);
+    assert_eq!(
+        form.error.as_deref(),
+        Some("Linear rejected issue: bad team")
+    );
+    assert!(app.state.workstreams.is_empty());
+}
+
+#[test]
+fn test_links_to_ended_sessions_are_pruned_on_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session_links.json");
+    let alive = make_session(None, Some("main"));
+    let mut ended = make_session(None, Some("main"));
+    ended.id = "session-ended".to_string();
+
+    let mut links = SessionLinks::default();
+    links.add(SessionLink::for_session(&alive, "ENG-1"));
+    links.add(SessionLink::for_session(&ended, "ENG-2"));
+    session_links::save_to_path(&path, &links).unwrap();
+
+    let loaded = session_links::load_pruned_from_path(&path, std::slice::from_ref(&alive)).unwrap();
+    assert_eq!(
+        loaded.links,
+        vec![SessionLink::for_session(&alive, "ENG-1")]
+    );
+
+    // The pruned list is saved back
+    let saved = session_links::load_from_path(&path).unwrap();
+    assert_eq!(saved.links, loaded.links);
+}
@@ -5,8 +5,8 @@
 
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 use panopticon::config::{
-    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, Tokens,
-    UiConfig, VercelConfig,
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
 };
 use panopticon::tui::input::InputState;
 use panopticon::tui::keybindings::dispatch;
@@ -30,6 +30,8 @@ fn test_config() -> Config {
         cache: CacheConfig::default(),
         notifications: NotificationConfig::default(),
         ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
     }
 }
 
@@ -179,3 +181,24 @@ fn test_lowercase_r_refreshes() {
 
     assert_eq!(msg, Message::Refresh);
 }
+
+// ============================================================================
+// Sort Menu Tests
+// ============================================================================
+
+#[test]
+fn test_sort_menu_group_keys_set_grouping() {
+    use panopticon::data::GroupBy;
+
+    let mut app = App::new(test_config());
+    app.modal = ModalState::SortMenu;
+    let mut input = InputState::new();
+
+    for group_by in GroupBy::all() {
+        let msg = dispatch(&app, &mut input, key_event(KeyCode::Char(group_by.key())));
+        assert_eq!(msg, Message::SetGroupBy(group_by));
+    }
+
+    let msg = dispatch(&app, &mut input, key_event(KeyCode::Char('x')));
+    assert_eq!(msg, Message::None);
+}
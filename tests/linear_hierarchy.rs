@@ -124,3 +124,26 @@ fn test_child_with_missing_required_fields() {
     assert_eq!(linked.issue.children.len(), 1);
     assert_eq!(linked.issue.children[0].identifier, "TEST-3");
 }
+
+#[test]
+fn test_parse_child_estimates() {
+    let mut node = minimal_issue_json();
+    node["children"]["nodes"] = json!([
+        {
+            "id": "child-1",
+            "identifier": "TEST-2",
+            "estimate": 3.0,
+            "state": { "name": "Todo", "type": "unstarted" }
+        },
+        {
+            "id": "child-2",
+            "identifier": "TEST-3",
+            "estimate": null,
+            "state": { "name": "Todo", "type": "unstarted" }
+        }
+    ]);
+
+    let linked = parse_issue(&node).unwrap();
+    assert_eq!(linked.issue.children[0].estimate, Some(3.0));
+    assert_eq!(linked.issue.children[1].estimate, None);
+}
@@ -0,0 +1,326 @@
+//! Tests for the Linear inbox
+//!
+//! Covers mapping Linear's notification types, marking notifications read
+//! (including refreshes racing the mutation) and jumping from a notification
+//! to its workstream row.
+
+use chrono::{Duration, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    LinearIssue, LinearNotification, LinearPriority, LinearStatus, NotificationKind, VisualItem,
+    Workstream,
+};
+use panopticon::integrations::linear::mark_notifications_read;
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn test_config() -> Config {
+    Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    }
+}
+
+fn make_workstream(id: &str, identifier: &str, priority: LinearPriority) -> Workstream {
+    Workstream {
+        linear_issue: LinearIssue {
+            id: id.to_string(),
+            identifier: identifier.to_string(),
+            title: format!("Issue {}", identifier),
+            description: None,
+            status: LinearStatus::InProgress,
+            priority,
+            url: format!("https://linear.app/test/issue/{}", identifier),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    }
+}
+
+fn make_notification(id: &str, issue_id: &str, kind: NotificationKind) -> LinearNotification {
+    LinearNotification {
+        id: id.to_string(),
+        kind,
+        issue_id: Some(issue_id.to_string()),
+        issue_identifier: None,
+        issue_title: None,
+        issue_url: None,
+        actor: Some("Alice".to_string()),
+        excerpt: None,
+        created_at: Utc::now() - Duration::minutes(5),
+        read_at: None,
+        workspace: None,
+    }
+}
+
+fn app_with_inbox() -> App {
+    let mut app = App::new(test_config());
+    app.state.workstreams = vec![
+        make_workstream("issue-1", "ENG-1", LinearPriority::High),
+        make_workstream("issue-2", "ENG-2", LinearPriority::Medium),
+    ];
+    app.apply_filters();
+    app.rebuild_visual_items();
+    app.set_notifications(vec![
+        make_notification("n1", "issue-2", NotificationKind::Mention),
+        make_notification("n2", "issue-1", NotificationKind::Comment),
+        make_notification("n3", "issue-9", NotificationKind::Assigned),
+    ]);
+    app
+}
+
+fn key_event(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    }
+}
+
+fn selected_ws_idx(app: &App) -> Option<usize> {
+    match app.visual_items.get(app.visual_selected)? {
+        VisualItem::Workstream(idx) | VisualItem::AgentSession { ws_idx: idx, .. } => Some(*idx),
+        VisualItem::SectionHeader(_) | VisualItem::ReviewRequest(_) => None,
+    }
+}
+
+// ============================================================================
+// Notification Kind Tests
+// ============================================================================
+
+#[test]
+fn test_notification_kind_from_linear_type() {
+    let cases = [
+        ("issueMention", Some(NotificationKind::Mention)),
+        ("issueCommentMention", Some(NotificationKind::Mention)),
+        ("issueAssignedToYou", Some(NotificationKind::Assigned)),
+        ("issueNewComment", Some(NotificationKind::Comment)),
+        ("issueStatusChanged", Some(NotificationKind::StatusChange)),
+        (
+            "issueStatusChangedAll",
+            Some(NotificationKind::StatusChange),
+        ),
+        ("issueEmojiReaction", None),
+        ("issueSubscribed", None),
+    ];
+    for (linear_type, expected) in cases {
+        assert_eq!(
+            NotificationKind::from_linear_type(linear_type),
+            expected,
+            "{}",
+            linear_type
+        );
+    }
+}
+
+// ============================================================================
+// Mark Read Tests
+// ============================================================================
+
+#[test]
+fn test_mark_read_updates_unread_count() {
+    let mut app = app_with_inbox();
+    assert_eq!(app.unread_notification_count(), 3);
+
+    app.inbox_selected = 1;
+    app.mark_selected_notification_read();
+    assert_eq!(app.unread_notification_count(), 2);
+    assert!(!app.notifications[1].is_unread());
+
+    app.mark_all_notifications_read();
+    assert_eq!(app.unread_notification_count(), 0);
+}
+
+#[test]
+fn test_refresh_keeps_unconfirmed_reads() {
+    let mut app = app_with_inbox();
+    app.mark_selected_notification_read();
+    let read_at = app.notifications[0].read_at;
+
+    // A refresh that raced the mutation still reports n1 unread
+    app.set_notifications(vec![
+        make_notification("n1", "issue-2", NotificationKind::Mention),
+        make_notification("n2", "issue-1", NotificationKind::Comment),
+    ]);
+    assert_eq!(app.notifications[0].read_at, read_at);
+    assert!(app.notifications[1].is_unread());
+    assert_eq!(app.unread_notification_count(), 1);
+}
+
+/// Linear stand-in that refuses to mark `n2` read
+async fn start_linear() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                let body = loop {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                        continue;
+                    };
+                    let length = head
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if n == 0 || body.len() >= length {
+                        break body.to_string();
+                    }
+                };
+                let success = !body.contains("\"n2\"");
+                let reply = format!(
+                    r#"{{"data":{{"notificationUpdate":{{"success":{}}}}}}}"#,
+                    success
+                );
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    reply.len(),
+                    reply
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    url
+}
+
+#[tokio::test]
+async fn test_mark_read_reports_failed_ids() {
+    let mut config = test_config();
+    config.linear.api_url = start_linear().await;
+    let ids: Vec<String> = ["n1", "n2", "n3"].iter().map(|s| s.to_string()).collect();
+
+    let failed = mark_notifications_read(&config, None, &ids, Utc::now()).await;
+
+    let failed_ids: Vec<&str> = failed.iter().map(|(id, _)| id.as_str()).collect();
+    assert_eq!(failed_ids, vec!["n2"]);
+}
+
+// ============================================================================
+// Linking Tests
+// ============================================================================
+
+#[test]
+fn test_open_notification_jumps_to_row() {
+    let mut app = app_with_inbox();
+    app.toggle_inbox();
+    assert!(app.show_inbox());
+
+    app.open_selected_notification();
+
+    assert!(app.modal.is_none());
+    assert!(!app.notifications[0].is_unread());
+    assert_eq!(selected_ws_idx(&app), Some(1));
+}
+
+#[test]
+fn test_open_notification_for_filtered_row_opens_details() {
+    let mut app = app_with_inbox();
+    app.filter_priorities.insert(LinearPriority::High);
+    app.apply_filters();
+    app.rebuild_visual_items();
+    app.toggle_inbox();
+
+    // ENG-2 is filtered out of the list: its details open instead
+    app.open_selected_notification();
+
+    assert!(app.show_link_menu());
+    assert_eq!(app.modal_issue_id.as_deref(), Some("issue-2"));
+}
+
+#[test]
+fn test_notification_links_by_identifier() {
+    let app = app_with_inbox();
+    let mut notification = make_notification("n4", "other-id", NotificationKind::Mention);
+    assert_eq!(app.notification_workstream(&notification), None);
+
+    notification.issue_identifier = Some("ENG-1".to_string());
+    assert_eq!(app.notification_workstream(&notification), Some(0));
+}
+
+// ============================================================================
+// Dispatch Tests
+// ============================================================================
+
+#[test]
+fn test_inbox_keys() {
+    let mut app = app_with_inbox();
+    let mut input = InputState::new();
+
+    let msg = dispatch(&app, &mut input, key_event(KeyCode::Char('i')));
+    assert_eq!(msg, Message::ToggleInbox);
+
+    app.modal = ModalState::Inbox;
+    let cases = [
+        (KeyCode::Char('j'), Message::MoveInboxSelection(1)),
+        (KeyCode::Up, Message::MoveInboxSelection(-1)),
+        (KeyCode::Enter, Message::OpenNotification),
+        (KeyCode::Char('m'), Message::MarkNotificationRead),
+        (KeyCode::Char('a'), Message::MarkAllNotificationsRead),
+        (KeyCode::Esc, Message::CloseModal),
+    ];
+    for (code, expected) in cases {
+        assert_eq!(dispatch(&app, &mut input, key_event(code)), expected);
+    }
+
+    app.move_inbox_selection(10);
+    assert_eq!(app.inbox_selected, 2);
+    app.move_inbox_selection(-10);
+    assert_eq!(app.inbox_selected, 0);
+}
@@ -0,0 +1,480 @@
+//! Tests for the Linear webhook receiver
+//!
+//! Recorded payloads are POSTed to a listener on localhost and the resulting
+//! updates are applied to an in-memory workstream list.
+
+use chrono::{TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use panopticon::config::Config;
+use panopticon::data::{
+    GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority, LinearStatus, LinkedPr, Workstream,
+};
+use panopticon::integrations;
+use panopticon::integrations::linear_webhook::{self, WebhookUpdate};
+use panopticon::tui::{App, RefreshResult};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+const SECRET: &str = "lin_wh_test_secret";
+
+fn test_config() -> Arc<Config> {
+    let config: Config = toml::from_str(&format!(
+        r#"
+        [tokens]
+        linear = "lin_api_test"
+        github = "ghp_test"
+
+        [webhook]
+        enabled = true
+        secret = "{}"
+        "#,
+        SECRET
+    ))
+    .unwrap();
+    Arc::new(config)
+}
+
+fn unsigned_config() -> Arc<Config> {
+    let mut config = (*test_config()).clone();
+    config.webhook.secret = None;
+    config.webhook.listen_addr = "127.0.0.1:0".to_string();
+    Arc::new(config)
+}
+
+fn multi_workspace_config() -> Arc<Config> {
+    let config: Config = toml::from_str(
+        r#"
+        [tokens]
+        github = "ghp_test"
+
+        [[linear.accounts]]
+        name = "acme"
+        token = "lin_api_acme"
+        webhook_secret = "lin_wh_acme"
+
+        [[linear.accounts]]
+        name = "side"
+        token = "lin_api_side"
+        webhook_secret = "lin_wh_side"
+
+        [webhook]
+        enabled = true
+        listen_addr = "127.0.0.1:0"
+        "#,
+    )
+    .unwrap();
+    Arc::new(config)
+}
+
+fn sign(body: &[u8]) -> String {
+    sign_with(SECRET, body)
+}
+
+fn sign_with(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+fn me() -> Vec<String> {
+    vec!["user-1".to_string()]
+}
+
+fn make_workstream(id: &str) -> Workstream {
+    Workstream {
+        linear_issue: LinearIssue {
+            id: id.to_string(),
+            identifier: "ENG-42".to_string(),
+            title: "Old title".to_string(),
+            description: None,
+            status: LinearStatus::Todo,
+            priority: LinearPriority::Low,
+            url: "https://linear.app/acme/issue/ENG-42".to_string(),
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: Some("Engineering".to_string()),
+            assignee_id: Some("user-1".to_string()),
+            assignee_name: Some("Me".to_string()),
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![panopticon::data::LinearAttachment {
+                id: "att-1".to_string(),
+                url: "https://www.figma.com/file/abc".to_string(),
+                title: "Design".to_string(),
+                subtitle: None,
+                source_type: Some("figma".to_string()),
+            }],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: true,
+    }
+}
+
+/// Recorded `Issue` update delivery (trimmed to the fields we read)
+fn issue_update_payload() -> Value {
+    json!({
+        "action": "update",
+        "type": "Issue",
+        "createdAt": "2024-03-01T12:00:00.000Z",
+        "url": "https://linear.app/acme/issue/ENG-42",
+        "webhookTimestamp": 1709294400000u64,
+        "data": {
+            "id": "issue-42",
+            "identifier": "ENG-42",
+            "number": 42,
+            "title": "Realtime updates",
+            "description": "Push, don't poll",
+            "url": "https://linear.app/acme/issue/ENG-42",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "updatedAt": "2024-03-01T12:00:00.000Z",
+            "priority": 1,
+            "priorityLabel": "Urgent",
+            "estimate": 3,
+            "state": { "id": "state-1", "name": "In Review", "type": "started", "color": "#0f0" },
+            "team": { "id": "team-1", "key": "ENG", "name": "Engineering" },
+            "assignee": { "id": "user-1", "name": "Me" },
+            "labels": [{ "id": "label-1", "name": "backend", "color": "#ff0000" }],
+            "project": null,
+            "cycle": null
+        }
+    })
+}
+
+async fn start_listener() -> (String, mpsc::Receiver<RefreshResult>) {
+    start_listener_with(test_config()).await
+}
+
+async fn start_listener_with(config: Arc<Config>) -> (String, mpsc::Receiver<RefreshResult>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = format!("http://{}/", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(linear_webhook::serve(listener, config, tx));
+    (addr, rx)
+}
+
+async fn post(addr: &str, body: &Value, signature: Option<String>) -> u16 {
+    let body = serde_json::to_vec(body).unwrap();
+    let signature = signature.unwrap_or_else(|| sign(&body));
+    reqwest::Client::new()
+        .post(addr)
+        .header("Content-Type", "application/json")
+        .header("Linear-Signature", signature)
+        .body(body)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .as_u16()
+}
+
+async fn recv_update(rx: &mut mpsc::Receiver<RefreshResult>) -> WebhookUpdate {
+    match tokio::time::timeout(Duration::from_secs(5), rx.recv()).await {
+        Ok(Some(RefreshResult::Live(update))) => update,
+        Ok(Some(_)) => panic!("expected a live update"),
+        _ => panic!("no update received"),
+    }
+}
+
+#[tokio::test]
+async fn test_signed_issue_update_is_applied() {
+    let (addr, mut rx) = start_listener().await;
+    assert_eq!(post(&addr, &issue_update_payload(), None).await, 200);
+
+    let mut workstreams = vec![make_workstream("issue-42")];
+    let update = recv_update(&mut rx).await;
+    assert!(update.apply(&mut workstreams, &me()));
+
+    let issue = &workstreams[0].linear_issue;
+    assert_eq!(issue.title, "Realtime updates");
+    assert_eq!(issue.status, LinearStatus::InReview);
+    assert_eq!(issue.priority, LinearPriority::Urgent);
+    assert_eq!(issue.labels.len(), 1);
+    // Attachments aren't part of issue payloads and must survive the update
+    assert_eq!(issue.attachments.len(), 1);
+    assert!(!workstreams[0].stale);
+}
+
+#[tokio::test]
+async fn test_bad_signature_is_rejected() {
+    let (addr, mut rx) = start_listener().await;
+    let status = post(&addr, &issue_update_payload(), Some("deadbeef".to_string())).await;
+    assert_eq!(status, 401);
+
+    let received = tokio::time::timeout(Duration::from_millis(200), rx.recv()).await;
+    assert!(
+        received.is_err(),
+        "rejected delivery must not produce an update"
+    );
+}
+
+#[tokio::test]
+async fn test_listener_requires_secret() {
+    let (tx, _rx) = mpsc::channel(16);
+    let err = linear_webhook::spawn(unsigned_config(), tx)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("webhook.secret"));
+
+    // Even when served directly, nothing is accepted without a secret
+    let (addr, mut rx) = start_listener_with(unsigned_config()).await;
+    assert_eq!(post(&addr, &issue_update_payload(), None).await, 401);
+    let received = tokio::time::timeout(Duration::from_millis(200), rx.recv()).await;
+    assert!(received.is_err());
+}
+
+#[tokio::test]
+async fn test_delivery_tagged_with_signing_workspace() {
+    let (addr, mut rx) = start_listener_with(multi_workspace_config()).await;
+    let payload = issue_update_payload();
+    let signature = sign_with("lin_wh_side", &serde_json::to_vec(&payload).unwrap());
+    assert_eq!(post(&addr, &payload, Some(signature)).await, 200);
+
+    // New issue is inserted under the workspace whose secret signed it
+    let mut workstreams = vec![];
+    assert!(recv_update(&mut rx).await.apply(&mut workstreams, &me()));
+    assert_eq!(
+        workstreams[0].linear_issue.workspace.as_deref(),
+        Some("side")
+    );
+}
+
+#[tokio::test]
+async fn test_shared_secret_rejected_with_multiple_workspaces() {
+    let mut config = (*multi_workspace_config()).clone();
+    config.webhook.secret = Some(SECRET.to_string());
+    let (tx, _rx) = mpsc::channel(16);
+    let err = linear_webhook::spawn(Arc::new(config), tx)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("webhook_secret"));
+}
+
+#[tokio::test]
+async fn test_malformed_payload_is_rejected() {
+    let (addr, _rx) = start_listener().await;
+    assert_eq!(post(&addr, &json!({ "hello": "world" }), None).await, 400);
+}
+
+#[tokio::test]
+async fn test_comment_bumps_activity() {
+    let (addr, mut rx) = start_listener().await;
+    let payload = json!({
+        "action": "create",
+        "type": "Comment",
+        "data": {
+            "id": "comment-1",
+            "body": "LGTM",
+            "issueId": "issue-42",
+            "createdAt": "2024-03-02T09:30:00.000Z",
+            "user": { "id": "user-2", "name": "Reviewer" }
+        }
+    });
+    assert_eq!(post(&addr, &payload, None).await, 200);
+
+    let mut workstreams = vec![make_workstream("issue-42")];
+    assert!(recv_update(&mut rx).await.apply(&mut workstreams, &me()));
+    assert_eq!(
+        workstreams[0].linear_issue.updated_at,
+        Utc.with_ymd_and_hms(2024, 3, 2, 9, 30, 0).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_attachment_is_added() {
+    let (addr, mut rx) = start_listener().await;
+    let payload = json!({
+        "action": "create",
+        "type": "Attachment",
+        "data": {
+            "id": "att-2",
+            "issueId": "issue-42",
+            "url": "https://www.notion.so/spec",
+            "title": "Spec",
+            "sourceType": "notion"
+        }
+    });
+    assert_eq!(post(&addr, &payload, None).await, 200);
+
+    let mut workstreams = vec![make_workstream("issue-42")];
+    assert!(recv_update(&mut rx).await.apply(&mut workstreams, &me()));
+    let titles: Vec<_> = workstreams[0]
+        .linear_issue
+        .attachments
+        .iter()
+        .map(|a| a.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Design", "Spec"]);
+}
+
+#[test]
+fn test_pr_link_on_foreign_host_stays_an_attachment() {
+    // Only github.com and the configured instance's PRs are fetched with the
+    // GitHub token
+    let payload = json!({
+        "action": "create",
+        "type": "Attachment",
+        "data": {
+            "id": "att-3",
+            "issueId": "issue-42",
+            "url": "https://evil.example/acme/web/pull/7",
+            "title": "Totally a PR"
+        }
+    });
+    let body = serde_json::to_vec(&payload).unwrap();
+    let update = linear_webhook::parse_payload(&test_config(), &body)
+        .unwrap()
+        .unwrap();
+    assert!(matches!(update, WebhookUpdate::Attachment { .. }));
+}
+
+#[tokio::test]
+async fn test_issue_remove_drops_workstream() {
+    let (addr, mut rx) = start_listener().await;
+    let payload = json!({
+        "action": "remove",
+        "type": "Issue",
+        "data": { "id": "issue-42" }
+    });
+    assert_eq!(post(&addr, &payload, None).await, 200);
+
+    let mut workstreams = vec![make_workstream("issue-42"), make_workstream("issue-7")];
+    assert!(recv_update(&mut rx).await.apply(&mut workstreams, &me()));
+    assert_eq!(workstreams.len(), 1);
+    assert_eq!(workstreams[0].linear_issue.id, "issue-7");
+}
+
+#[test]
+fn test_reassigned_issue_is_dropped_and_foreign_issue_ignored() {
+    let body = serde_json::to_vec(&issue_update_payload()).unwrap();
+    let update = linear_webhook::parse_payload(&test_config(), &body)
+        .unwrap()
+        .unwrap();
+
+    // Issue now belongs to someone else -> removed from my list
+    let mut workstreams = vec![make_workstream("issue-42")];
+    assert!(update
+        .clone()
+        .apply(&mut workstreams, &["user-2".to_string()]));
+    assert!(workstreams.is_empty());
+
+    // Unknown issue not assigned to me -> not inserted
+    assert!(!update.apply(&mut workstreams, &["user-2".to_string()]));
+    assert!(workstreams.is_empty());
+}
+
+#[test]
+fn test_verify_signature() {
+    let body = br#"{"action":"update"}"#;
+    assert!(linear_webhook::verify_signature(SECRET, body, &sign(body)));
+    assert!(!linear_webhook::verify_signature(
+        "other",
+        body,
+        &sign(body)
+    ));
+    assert!(!linear_webhook::verify_signature(SECRET, body, "not-hex"));
+}
+
+#[test]
+fn test_webhook_update_survives_in_flight_refresh() {
+    let mut config = (*test_config()).clone();
+    config.cache.enabled = false;
+    let mut app = App::new(config);
+    app.state.workstreams = vec![make_workstream("issue-42")];
+
+    // A refresh is running; its fetch of issue-42 predates the delivery
+    let (tx, rx) = mpsc::channel(16);
+    app.is_loading = true;
+    app.refresh_rx = Some(rx);
+
+    let body = serde_json::to_vec(&issue_update_payload()).unwrap();
+    let update = linear_webhook::parse_payload(&test_config(), &body)
+        .unwrap()
+        .unwrap();
+    tx.try_send(RefreshResult::Live(update)).unwrap();
+    tx.try_send(RefreshResult::Workstream(Box::new(make_workstream(
+        "issue-42",
+    ))))
+    .unwrap();
+    tx.try_send(RefreshResult::Complete).unwrap();
+    assert!(app.poll_refresh());
+
+    assert_eq!(app.state.workstreams.len(), 1);
+    assert_eq!(
+        app.state.workstreams[0].linear_issue.title,
+        "Realtime updates"
+    );
+}
+
+#[test]
+fn test_stale_issue_delivery_is_ignored() {
+    let body = serde_json::to_vec(&issue_update_payload()).unwrap();
+    let update = linear_webhook::parse_payload(&test_config(), &body)
+        .unwrap()
+        .unwrap();
+
+    let mut fresher = make_workstream("issue-42");
+    fresher.linear_issue.updated_at = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+    let mut workstreams = vec![fresher];
+    assert!(!update.apply(&mut workstreams, &me()));
+    assert_eq!(workstreams[0].linear_issue.title, "Old title");
+}
+
+#[test]
+fn test_known_issues_skip_placeholders_and_keep_prs() {
+    let mut linked = make_workstream("issue-42");
+    linked.linked_prs = vec![LinkedPr {
+        pr: GitHubPR {
+            number: 7,
+            title: "Realtime".to_string(),
+            url: "https://github.com/acme/web/pull/7".to_string(),
+            status: GitHubPRStatus::Open,
+            branch: "eng-42-realtime".to_string(),
+            repo: "acme/web".to_string(),
+            ci_status: None,
+            checks: vec![],
+            reviews: vec![],
+            review_threads: vec![],
+            head_sha: None,
+        },
+        vercel_deployment: None,
+    }];
+    let mut placeholder = make_workstream("unlinked-session-1");
+    placeholder.linear_issue.identifier = String::new();
+
+    let links = HashMap::from([(
+        "issue-42".to_string(),
+        (
+            vec!["https://github.com/acme/web/pull/6".to_string()],
+            Some("eng-42-realtime".to_string()),
+        ),
+    )]);
+
+    let issues = integrations::known_linear_issues(&[linked, placeholder], &links);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].issue.id, "issue-42");
+    assert_eq!(
+        issues[0].linked_pr_urls,
+        vec![
+            "https://github.com/acme/web/pull/6",
+            "https://github.com/acme/web/pull/7"
+        ]
+    );
+    assert_eq!(issues[0].branch_name.as_deref(), Some("eng-42-realtime"));
+}
@@ -0,0 +1,204 @@
+//! Tests for issues with several linked PRs
+//!
+//! Covers picking the primary (worst status) PR, aggregating CI state,
+//! webhook upserts and opening other PRs from the links popup.
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    CiStatus, DeploymentSource, GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority,
+    LinearStatus, LinkedPr, VercelDeployment, VercelStatus, Workstream,
+};
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+
+fn make_pr(repo: &str, number: u64, status: GitHubPRStatus, ci: Option<CiStatus>) -> GitHubPR {
+    GitHubPR {
+        number,
+        title: format!("PR {}", number),
+        url: format!("https://github.com/{}/pull/{}", repo, number),
+        status,
+        branch: "eng-1-feature".to_string(),
+        repo: repo.to_string(),
+        ci_status: ci,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
+    }
+}
+
+fn make_linked(pr: GitHubPR) -> LinkedPr {
+    LinkedPr {
+        vercel_deployment: Some(VercelDeployment {
+            id: format!("dpl-{}", pr.number),
+            url: format!("https://preview-{}.vercel.app", pr.number),
+            status: VercelStatus::Ready,
+            created_at: Utc::now(),
+            name: None,
+            project_id: None,
+            target: None,
+            commit_sha: None,
+            commit_message: None,
+            source: DeploymentSource::Vercel,
+        }),
+        pr,
+    }
+}
+
+fn make_workstream() -> Workstream {
+    Workstream {
+        linear_issue: LinearIssue {
+            id: "id-1".to_string(),
+            identifier: "ENG-1".to_string(),
+            title: "Feature".to_string(),
+            description: None,
+            status: LinearStatus::InProgress,
+            priority: LinearPriority::Medium,
+            url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    }
+}
+
+// ============================================================================
+// Aggregation Tests
+// ============================================================================
+
+#[test]
+fn test_primary_pr_is_worst_status() {
+    let mut ws = make_workstream();
+    ws.set_linked_prs(vec![
+        make_linked(make_pr(
+            "acme/web",
+            1,
+            GitHubPRStatus::Approved,
+            Some(CiStatus::Failing),
+        )),
+        make_linked(make_pr(
+            "acme/api",
+            2,
+            GitHubPRStatus::ChangesRequested,
+            Some(CiStatus::Passing),
+        )),
+    ]);
+
+    assert_eq!(ws.pr_count(), 2);
+    assert_eq!(ws.github_pr.as_ref().map(|pr| pr.number), Some(2));
+    assert_eq!(
+        ws.vercel_deployment.as_ref().map(|d| d.id.as_str()),
+        Some("dpl-2")
+    );
+    assert_eq!(ws.linked_prs[1].pr.number, 1);
+    // CI is the worst across PRs, not the primary PR's
+    assert_eq!(ws.worst_ci_status(), Some(CiStatus::Failing));
+}
+
+#[test]
+fn test_legacy_workstream_falls_back_to_primary_pr() {
+    let mut ws = make_workstream();
+    ws.github_pr = Some(make_pr("acme/web", 1, GitHubPRStatus::Open, None));
+
+    assert_eq!(ws.pr_count(), 1);
+    let prs = ws.all_linked_prs();
+    assert_eq!(prs.len(), 1);
+    assert_eq!(prs[0].pr.number, 1);
+    assert_eq!(make_workstream().pr_count(), 0);
+}
+
+#[test]
+fn test_upsert_pr_replaces_by_url() {
+    let mut ws = make_workstream();
+    ws.set_linked_prs(vec![make_linked(make_pr(
+        "acme/web",
+        1,
+        GitHubPRStatus::Open,
+        None,
+    ))]);
+
+    // A second PR is added; the updated first one keeps its preview
+    ws.upsert_pr(make_pr("acme/api", 2, GitHubPRStatus::Draft, None));
+    ws.upsert_pr(make_pr(
+        "acme/web",
+        1,
+        GitHubPRStatus::ChangesRequested,
+        None,
+    ));
+
+    assert_eq!(ws.pr_count(), 2);
+    let primary = ws.github_pr.as_ref().unwrap();
+    assert_eq!(primary.number, 1);
+    assert_eq!(primary.status, GitHubPRStatus::ChangesRequested);
+    assert!(ws.vercel_deployment.is_some());
+}
+
+// ============================================================================
+// Dispatch Tests
+// ============================================================================
+
+#[test]
+fn test_links_popup_opens_other_prs() {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let mut app = App::new(config);
+    app.modal = ModalState::LinkMenu {
+        show_links_popup: true,
+    };
+    let mut input = InputState::new();
+
+    let key = |c| KeyEvent {
+        code: KeyCode::Char(c),
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    };
+    assert_eq!(
+        dispatch(&app, &mut input, key('2')),
+        Message::OpenGithubLink
+    );
+    assert_eq!(dispatch(&app, &mut input, key('5')), Message::OpenPrLink(1));
+    assert_eq!(dispatch(&app, &mut input, key('9')), Message::OpenPrLink(5));
+}
@@ -7,12 +7,12 @@
 
 use chrono::{TimeZone, Utc};
 use panopticon::config::{
-    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, Tokens,
-    UiConfig, VercelConfig,
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
 };
 use panopticon::data::{
-    LinearChildRef, LinearIssue, LinearParentRef, LinearPriority, LinearStatus, SortMode,
-    Workstream,
+    LinearChildRef, LinearIssue, LinearParentRef, LinearPriority, LinearRelationRef, LinearStatus,
+    RelationKind, SortMode, Workstream,
 };
 use panopticon::tui::App;
 
@@ -31,6 +31,8 @@ fn test_config() -> Config {
         cache: CacheConfig::default(),
         notifications: NotificationConfig::default(),
         ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
     }
 }
 
@@ -58,6 +60,10 @@ fn make_workstream_with_hierarchy(
             assignee_id: None,
             assignee_name: None,
             estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            workspace: None,
+            relations: Vec::new(),
             created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             attachments: Vec::new(),
@@ -76,11 +82,13 @@ fn make_workstream_with_hierarchy(
                     url: format!("https://linear.app/test/issue/{}", cident),
                     status: LinearStatus::InProgress,
                     priority: cprio,
+                    estimate: None,
                 })
                 .collect(),
         },
         github_pr: None,
         vercel_deployment: None,
+        linked_prs: vec![],
         agent_sessions: vec![],
         agent_session: None,
         stale: false,
@@ -474,6 +482,91 @@ fn test_no_selection_when_no_parent_and_no_children() {
     assert_eq!(app.selected_child_idx, None);
 }
 
+#[test]
+fn test_relations_follow_children_in_navigation() {
+    let config = test_config();
+    let mut app = App::new(config);
+
+    let mut issue = make_workstream_with_hierarchy(
+        "issue-id",
+        "ISSUE-1",
+        LinearPriority::Medium,
+        None,
+        vec![("child-id", "CHILD-1", LinearPriority::Medium)],
+    );
+    issue.linear_issue.relations = vec![LinearRelationRef {
+        kind: RelationKind::BlockedBy,
+        id: "blocker-id".to_string(),
+        identifier: "BLOCKER-1".to_string(),
+        title: "Blocker".to_string(),
+        url: "https://linear.app/test/issue/BLOCKER-1".to_string(),
+        status: LinearStatus::Todo,
+    }];
+    let blocker = make_simple_workstream("blocker-id", "BLOCKER-1");
+
+    app.state.workstreams = vec![issue, blocker];
+    app.filtered_indices = vec![0, 1];
+    app.rebuild_visual_items();
+    app.visual_selected = 1;
+
+    app.open_link_menu();
+    assert_eq!(app.selected_child_idx, Some(0));
+
+    // Past the last child onto the first relation
+    app.next_child_issue();
+    assert_eq!(app.selected_child_idx, None);
+    assert_eq!(app.selected_relation_idx, Some(0));
+
+    // Can't go beyond the last relation
+    app.next_child_issue();
+    assert_eq!(app.selected_relation_idx, Some(0));
+
+    // Back up to the last child
+    app.prev_child_issue();
+    assert_eq!(app.selected_relation_idx, None);
+    assert_eq!(app.selected_child_idx, Some(0));
+
+    // Open the blocker in the modal, then come back
+    app.next_child_issue();
+    assert!(app.navigate_to_selected_relation());
+    assert_eq!(app.modal_issue_id, Some("blocker-id".to_string()));
+    assert_eq!(app.issue_navigation_stack, vec!["issue-id".to_string()]);
+    assert_eq!(app.selected_relation_idx, None);
+
+    app.navigate_back();
+    assert_eq!(app.modal_issue_id, Some("issue-id".to_string()));
+}
+
+#[test]
+fn test_relation_preselected_without_parent_or_children() {
+    let config = test_config();
+    let mut app = App::new(config);
+
+    let mut issue = make_simple_workstream("issue-id", "ISSUE-1");
+    issue.linear_issue.relations = vec![LinearRelationRef {
+        kind: RelationKind::Related,
+        id: "other-id".to_string(),
+        identifier: "OTHER-1".to_string(),
+        title: "Other".to_string(),
+        url: String::new(),
+        status: LinearStatus::Done,
+    }];
+
+    app.state.workstreams = vec![issue];
+    app.filtered_indices = vec![0];
+    app.rebuild_visual_items();
+    app.visual_selected = 1;
+
+    app.open_link_menu();
+    assert!(!app.parent_selected);
+    assert_eq!(app.selected_child_idx, None);
+    assert_eq!(app.selected_relation_idx, Some(0));
+
+    // Not in workstreams, so there's nowhere to navigate
+    assert!(!app.navigate_to_selected_relation());
+    assert!(!app.issue_exists("other-id"));
+}
+
 #[test]
 fn test_navigation_preserves_stack() {
     let config = test_config();
@@ -731,6 +824,10 @@ fn make_workstream_with_status_children(
             assignee_id: None,
             assignee_name: None,
             estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            workspace: None,
+            relations: Vec::new(),
             created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             attachments: Vec::new(),
@@ -744,11 +841,13 @@ fn make_workstream_with_status_children(
                     url: format!("https://linear.app/test/issue/{}", cident),
                     status: cstatus,
                     priority: LinearPriority::Medium,
+                    estimate: None,
                 })
                 .collect(),
         },
         github_pr: None,
         vercel_deployment: None,
+        linked_prs: vec![],
         agent_sessions: vec![],
         agent_session: None,
         stale: false,
@@ -0,0 +1,272 @@
+//! Tests for PR actions (ready for review, merge, re-request reviews, close)
+//!
+//! Covers the GraphQL mutations, parsing their result, which actions apply to
+//! which PR states, the confirmation flow and updating the PR in place.
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority, LinearStatus, LinkedPr, MergeMethod,
+    PrAction, Workstream,
+};
+use panopticon::integrations::github::{parse_pr_action_result, pr_action_mutation};
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+use serde_json::json;
+
+fn make_pr(number: u64, status: GitHubPRStatus) -> GitHubPR {
+    GitHubPR {
+        number,
+        title: format!("PR {}", number),
+        url: format!("https://github.com/acme/web/pull/{}", number),
+        status,
+        branch: "eng-1-feature".to_string(),
+        repo: "acme/web".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
+    }
+}
+
+fn make_app(prs: Vec<GitHubPR>) -> App {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let mut app = App::new(config);
+
+    let mut ws = Workstream {
+        linear_issue: LinearIssue {
+            id: "id-1".to_string(),
+            identifier: "ENG-1".to_string(),
+            title: "Feature".to_string(),
+            description: None,
+            status: LinearStatus::InReview,
+            priority: LinearPriority::Medium,
+            url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    };
+    ws.set_linked_prs(
+        prs.into_iter()
+            .map(|pr| LinkedPr {
+                pr,
+                vercel_deployment: None,
+            })
+            .collect(),
+    );
+    app.state.workstreams = vec![ws];
+    app.modal_issue_id = Some("id-1".to_string());
+    app.modal = ModalState::LinkMenu {
+        show_links_popup: false,
+    };
+    app
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    }
+}
+
+// ============================================================================
+// GraphQL Tests
+// ============================================================================
+
+#[test]
+fn test_mutations_declare_only_used_variables() {
+    let merge = pr_action_mutation(PrAction::Merge(MergeMethod::Squash));
+    assert!(merge.contains("$method:PullRequestMergeMethod!"));
+    assert!(merge.contains("result: mergePullRequest("));
+    assert!(merge.contains("fragment PrFields"));
+
+    let close = pr_action_mutation(PrAction::Close);
+    assert!(close.starts_with("mutation($id:ID!)"));
+    assert!(!close.contains("$method"));
+
+    let rerequest = pr_action_mutation(PrAction::RerequestReviews);
+    assert!(rerequest.contains("$users:[ID!]!"));
+    assert!(rerequest.contains("union:true"));
+}
+
+#[test]
+fn test_parse_pr_action_result() {
+    let body = json!({
+        "data": { "result": { "pullRequest": {
+            "number": 5,
+            "title": "Add caching",
+            "url": "https://github.com/acme/web/pull/5",
+            "state": "MERGED",
+            "isDraft": false,
+            "merged": true,
+            "headRefName": "eng-1-caching"
+        } } }
+    });
+    let pr = parse_pr_action_result(&body, "acme/web").unwrap();
+    assert_eq!(pr.status, GitHubPRStatus::Merged);
+    assert_eq!(pr.repo, "acme/web");
+
+    assert!(parse_pr_action_result(&json!({ "data": { "result": null } }), "acme/web").is_none());
+}
+
+#[test]
+fn test_action_availability() {
+    let merge = PrAction::Merge(MergeMethod::Rebase);
+    assert!(PrAction::MarkReady.is_available(GitHubPRStatus::Draft));
+    assert!(!PrAction::MarkReady.is_available(GitHubPRStatus::Open));
+    assert!(merge.is_available(GitHubPRStatus::Approved));
+    assert!(!merge.is_available(GitHubPRStatus::Draft));
+    assert!(PrAction::Close.is_available(GitHubPRStatus::Draft));
+    assert!(!PrAction::Close.is_available(GitHubPRStatus::Merged));
+    assert_eq!(merge.label(), "Merge (rebase)");
+    assert_eq!(MergeMethod::Rebase.next(), MergeMethod::Squash);
+}
+
+// ============================================================================
+// Confirmation Flow Tests
+// ============================================================================
+
+#[test]
+fn test_action_needs_confirmation() {
+    let mut app = make_app(vec![make_pr(5, GitHubPRStatus::Approved)]);
+    let mut input = InputState::new();
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('p'))),
+        Message::OpenPrActions
+    );
+    app.open_pr_actions();
+    assert!(app.show_pr_actions());
+
+    let msg = dispatch(&app, &mut input, key(KeyCode::Char('m')));
+    assert_eq!(
+        msg,
+        Message::RequestPrAction(PrAction::Merge(MergeMethod::Squash))
+    );
+    app.request_pr_action(PrAction::Merge(MergeMethod::Squash));
+    assert_eq!(
+        app.pending_confirmation(),
+        Some(PrAction::Merge(MergeMethod::Squash))
+    );
+    assert!(app.pending_pr_actions.is_empty());
+
+    // y confirms; outside a runtime nothing is sent but the PR is marked busy
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('y'))),
+        Message::ConfirmPrAction
+    );
+    app.confirm_pr_action();
+    assert_eq!(app.pending_confirmation(), None);
+    assert!(app
+        .pending_pr_actions
+        .contains("https://github.com/acme/web/pull/5"));
+}
+
+#[test]
+fn test_unavailable_action_is_refused() {
+    let mut app = make_app(vec![make_pr(5, GitHubPRStatus::Draft)]);
+    app.open_pr_actions();
+
+    app.request_pr_action(PrAction::Merge(MergeMethod::Squash));
+    assert_eq!(app.pending_confirmation(), None);
+    assert!(app.error_message.is_some());
+
+    app.request_pr_action(PrAction::MarkReady);
+    assert_eq!(app.pending_confirmation(), Some(PrAction::MarkReady));
+}
+
+#[test]
+fn test_confirmation_follows_pr_when_list_reorders() {
+    let mut app = make_app(vec![
+        make_pr(5, GitHubPRStatus::Approved),
+        make_pr(6, GitHubPRStatus::Open),
+    ]);
+    app.open_pr_actions();
+
+    // Merge is asked for #5, then a refresh moves #6 to the top
+    app.request_pr_action(PrAction::Merge(MergeMethod::Squash));
+    app.apply_pr_update(make_pr(6, GitHubPRStatus::ChangesRequested));
+    assert_eq!(app.selected_action_pr().map(|pr| pr.number), Some(6));
+    assert_eq!(app.pending_confirmation_pr().map(|pr| pr.number), Some(5));
+
+    app.confirm_pr_action();
+    assert_eq!(app.pending_confirmation(), None);
+    assert!(app
+        .pending_pr_actions
+        .contains("https://github.com/acme/web/pull/5"));
+    assert!(!app
+        .pending_pr_actions
+        .contains("https://github.com/acme/web/pull/6"));
+
+    // #6 is merged elsewhere before the confirmation: nothing is sent
+    app.request_pr_action(PrAction::Merge(MergeMethod::Squash));
+    app.apply_pr_update(make_pr(6, GitHubPRStatus::Merged));
+    app.confirm_pr_action();
+    assert_eq!(app.pending_confirmation(), None);
+    assert!(app.error_message.is_some());
+    assert!(!app
+        .pending_pr_actions
+        .contains("https://github.com/acme/web/pull/6"));
+}
+
+#[test]
+fn test_apply_pr_update_in_place() {
+    let mut app = make_app(vec![
+        make_pr(5, GitHubPRStatus::Approved),
+        make_pr(6, GitHubPRStatus::ChangesRequested),
+    ]);
+
+    app.apply_pr_update(make_pr(6, GitHubPRStatus::Merged));
+
+    let ws = &app.state.workstreams[0];
+    assert_eq!(ws.pr_count(), 2);
+    // The merged PR no longer decides the row's status
+    assert_eq!(ws.github_pr.as_ref().map(|pr| pr.number), Some(5));
+    assert_eq!(ws.linked_prs[1].pr.status, GitHubPRStatus::Merged);
+}
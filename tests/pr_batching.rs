@@ -0,0 +1,73 @@
+//! Tests for batched GitHub PR enrichment
+//!
+//! Covers building the aliased GraphQL query and mapping its response back to
+//! the requested PRs, including entries GitHub couldn't return.
+
+use panopticon::data::GitHubPRStatus;
+use panopticon::integrations::github::{parse_pr_batch, pr_batch_query, PrUrl};
+use serde_json::json;
+
+fn urls() -> Vec<PrUrl<'static>> {
+    [("web", 5), ("api", 9), ("docs", 2)]
+        .into_iter()
+        .map(|(repo, number)| PrUrl {
+            host: "github.com",
+            owner: "acme",
+            repo,
+            number,
+        })
+        .collect()
+}
+
+#[test]
+fn test_batch_query_aliases_each_pr() {
+    let (query, variables) = pr_batch_query(&urls());
+
+    assert!(query.starts_with("query($o0:String!, $r0:String!, $n0:Int!, $o1:"));
+    assert!(query.contains("pr2: repository(owner:$o2, name:$r2)"));
+    assert_eq!(query.matches("...PrFields").count(), 3);
+    assert!(query.contains("fragment PrFields"));
+    assert_eq!(variables["r1"], "api");
+    assert_eq!(variables["n1"], 9);
+}
+
+#[test]
+fn test_parse_batch_keeps_request_order() {
+    let body = json!({
+        "data": {
+            "pr0": { "pullRequest": {
+                "number": 5,
+                "title": "Add caching",
+                "url": "https://github.com/acme/web/pull/5",
+                "state": "OPEN",
+                "isDraft": true,
+                "merged": false,
+                "headRefName": "eng-1-caching",
+                "headRefOid": "4f2a9c1e"
+            } },
+            "pr1": null,
+            "pr2": { "pullRequest": {
+                "number": 2,
+                "title": "Docs",
+                "url": "https://github.com/acme/docs/pull/2",
+                "state": "MERGED",
+                "isDraft": false,
+                "merged": true,
+                "headRefName": "eng-2-docs"
+            } }
+        },
+        "errors": [{ "message": "Could not resolve to a Repository", "path": ["pr1"] }]
+    });
+
+    let prs = parse_pr_batch(&body, &urls());
+    assert_eq!(prs.len(), 3);
+    let first = prs[0].as_ref().unwrap();
+    assert_eq!(first.status, GitHubPRStatus::Draft);
+    assert_eq!(first.repo, "acme/web");
+    assert_eq!(first.head_sha.as_deref(), Some("4f2a9c1e"));
+    // An inaccessible PR is left for the per-PR fallback
+    assert!(prs[1].is_none());
+    let last = prs[2].as_ref().unwrap();
+    assert_eq!(last.status, GitHubPRStatus::Merged);
+    assert!(last.head_sha.is_none());
+}
@@ -0,0 +1,291 @@
+//! Tests for discovering open PRs through GitHub search
+//!
+//! Covers the search query, parsing search results, how discovered PRs are
+//! claimed by issues, unlinked sessions or standalone rows, and the fallback
+//! lookup by branch in a session's repository.
+
+use panopticon::config::LinearConfig;
+use panopticon::data::{GitHubPR, GitHubPRStatus, LinearStatus};
+use panopticon::integrations::github::{authored_prs_query, parse_authored_prs, parse_branch_pr};
+use panopticon::integrations::pr_discovery::{
+    create_pr_placeholder_issue, parse_github_remote, standalone_workstream, DiscoveredPrs,
+};
+use panopticon::integrations::LinkedLinearIssue;
+use serde_json::json;
+
+fn make_pr(number: u64, branch: &str, title: &str) -> GitHubPR {
+    GitHubPR {
+        number,
+        title: title.to_string(),
+        url: format!("https://github.com/acme/web/pull/{}", number),
+        status: GitHubPRStatus::Open,
+        branch: branch.to_string(),
+        repo: "acme/web".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
+    }
+}
+
+// ============================================================================
+// Search Tests
+// ============================================================================
+
+#[test]
+fn test_authored_prs_query() {
+    assert_eq!(
+        authored_prs_query("octocat", &[]),
+        "is:pr is:open archived:false author:octocat"
+    );
+    assert_eq!(
+        authored_prs_query("octocat", &["acme".to_string(), "labs".to_string()]),
+        "is:pr is:open archived:false author:octocat org:acme org:labs"
+    );
+}
+
+#[test]
+fn test_parse_authored_prs() {
+    let body = json!({
+        "data": { "search": { "nodes": [
+            {
+                "number": 12,
+                "title": "ENG-7 Fix login",
+                "url": "https://github.com/acme/web/pull/12",
+                "state": "OPEN",
+                "isDraft": true,
+                "merged": false,
+                "headRefName": "fix-login",
+                "repository": { "nameWithOwner": "acme/web" }
+            },
+            {
+                "number": 3,
+                "title": "Approved change",
+                "url": "https://github.com/acme/api/pull/3",
+                "state": "OPEN",
+                "isDraft": false,
+                "merged": false,
+                "headRefName": "change",
+                "reviewDecision": "APPROVED",
+                "repository": { "nameWithOwner": "acme/api" }
+            },
+            // Issues match `type: ISSUE` searches too and come back empty
+            {}
+        ] } }
+    });
+
+    let prs = parse_authored_prs(&body);
+    assert_eq!(prs.len(), 2);
+    assert_eq!(prs[0].number, 12);
+    assert_eq!(prs[0].repo, "acme/web");
+    assert_eq!(prs[0].branch, "fix-login");
+    assert_eq!(prs[0].status, GitHubPRStatus::Draft);
+    assert_eq!(prs[1].repo, "acme/api");
+    assert_eq!(prs[1].status, GitHubPRStatus::Approved);
+
+    assert!(parse_authored_prs(&json!({ "errors": [] })).is_empty());
+}
+
+// ============================================================================
+// Claiming Tests
+// ============================================================================
+
+#[test]
+fn test_linked_prs_are_not_rediscovered() {
+    let mut discovered = DiscoveredPrs::new(
+        vec![make_pr(1, "eng-1-fix", "Fix"), make_pr(2, "other", "Other")],
+        ["https://github.com/acme/web/pull/1/"],
+        &LinearConfig::default().identifier_pattern,
+    );
+
+    assert!(discovered.claim_for_issue("ENG-1", None).is_none());
+    let unclaimed = discovered.take_unclaimed();
+    assert_eq!(unclaimed.len(), 1);
+    assert_eq!(unclaimed[0].number, 2);
+}
+
+#[test]
+fn test_issue_claims_by_branch_name_then_identifier() {
+    let mut discovered = DiscoveredPrs::new(
+        vec![
+            make_pr(1, "feature/eng-1-fix", "Fix things"),
+            make_pr(2, "jane/eng-1-login", "Login"),
+            make_pr(3, "misc", "ENG-2: tidy up"),
+        ],
+        [],
+        &LinearConfig::default().identifier_pattern,
+    );
+
+    // Linear's branch name wins over the identifier in another PR's branch
+    let pr = discovered.claim_for_issue("ENG-1", Some("Jane/ENG-1-login"));
+    assert_eq!(pr.map(|pr| pr.number), Some(2));
+
+    let pr = discovered.claim_for_issue("ENG-1", Some("no-such-branch"));
+    assert_eq!(pr.map(|pr| pr.number), Some(1));
+
+    // Falls back to the identifier in the PR title
+    let pr = discovered.claim_for_issue("ENG-2", None);
+    assert_eq!(pr.map(|pr| pr.number), Some(3));
+
+    // Each PR is claimed once
+    assert!(discovered.claim_for_issue("ENG-1", None).is_none());
+    assert!(discovered.take_unclaimed().is_empty());
+}
+
+#[test]
+fn test_issue_claims_follow_issue_order() {
+    let linked_issue = |identifier: &str, linked_pr_urls: Vec<String>| {
+        let mut issue = create_pr_placeholder_issue(&make_pr(0, "", ""));
+        issue.identifier = identifier.to_string();
+        LinkedLinearIssue {
+            issue,
+            linked_pr_urls,
+            branch_name: Some("eng-1-shared".to_string()),
+        }
+    };
+    let issues = vec![
+        linked_issue("ENG-1", vec![]),
+        linked_issue("ENG-2", vec![]),
+        linked_issue(
+            "ENG-3",
+            vec!["https://github.com/acme/web/pull/9".to_string()],
+        ),
+    ];
+    let mut discovered = DiscoveredPrs::new(
+        vec![make_pr(1, "eng-1-shared", "Shared branch")],
+        [],
+        &LinearConfig::default().identifier_pattern,
+    );
+
+    // Both issues match the PR by branch name; the first issue always wins
+    let claims = discovered.claim_for_issues(&issues);
+    let numbers: Vec<_> = claims
+        .iter()
+        .map(|pr| pr.as_ref().map(|p| p.number))
+        .collect();
+    assert_eq!(numbers, vec![Some(1), None, None]);
+}
+
+#[test]
+fn test_session_branch_claims_remaining_pr() {
+    let mut discovered = DiscoveredPrs::new(
+        vec![
+            make_pr(1, "spike", "Spike"),
+            make_pr(2, "cleanup", "Cleanup"),
+        ],
+        [],
+        &LinearConfig::default().identifier_pattern,
+    );
+
+    assert!(discovered.claim_for_issue("", None).is_none());
+    assert_eq!(
+        discovered.claim_for_branch("spike").map(|pr| pr.number),
+        Some(1)
+    );
+    assert!(discovered.claim_for_branch("spike").is_none());
+
+    let unclaimed = discovered.take_unclaimed();
+    assert_eq!(unclaimed.len(), 1);
+    assert_eq!(unclaimed[0].number, 2);
+    assert!(discovered.take_unclaimed().is_empty());
+}
+
+#[test]
+fn test_pr_found_by_branch_is_not_listed_again() {
+    let mut discovered = DiscoveredPrs::new(
+        vec![make_pr(4, "agent/login-fix", "Fix login")],
+        [],
+        &LinearConfig::default().identifier_pattern,
+    );
+
+    discovered.claim_url("https://github.com/acme/web/pull/4/");
+    assert!(discovered.take_unclaimed().is_empty());
+}
+
+// ============================================================================
+// Branch Lookup Tests
+// ============================================================================
+
+#[test]
+fn test_parse_github_remote() {
+    for url in [
+        "git@github.com:acme/web.git",
+        "https://github.com/acme/web",
+        "https://github.com/acme/web.git\n",
+        "ssh://git@github.com/acme/web.git",
+        "https://token@github.com/acme/web/",
+    ] {
+        assert_eq!(
+            parse_github_remote(url, "github.com").as_deref(),
+            Some("acme/web"),
+            "{}",
+            url
+        );
+    }
+    assert!(parse_github_remote("git@gitlab.com:acme/web.git", "github.com").is_none());
+    assert!(parse_github_remote("https://github.com/acme", "github.com").is_none());
+    assert_eq!(
+        parse_github_remote("git@github.example.com:acme/web.git", "github.example.com").as_deref(),
+        Some("acme/web")
+    );
+}
+
+#[test]
+fn test_parse_branch_pr_prefers_open_same_repo_pr() {
+    let node = |number: u64, state: &str, head: &str| {
+        json!({
+            "number": number,
+            "url": format!("https://github.com/acme/web/pull/{}", number),
+            "state": state,
+            "merged": state == "MERGED",
+            "headRefName": "eng-1-login",
+            "headRepository": { "nameWithOwner": head }
+        })
+    };
+    let body = json!({
+        "data": { "repository": { "pullRequests": { "nodes": [
+            node(9, "OPEN", "someone/web"),
+            node(8, "MERGED", "acme/web"),
+            node(7, "OPEN", "acme/web"),
+        ] } } }
+    });
+
+    let pr = parse_branch_pr(&body, "acme/web").unwrap();
+    assert_eq!(pr.number, 7);
+    assert_eq!(pr.repo, "acme/web");
+
+    // Without an open PR the most recently updated one is used
+    let body = json!({
+        "data": { "repository": { "pullRequests": { "nodes": [
+            node(8, "MERGED", "acme/web"),
+            node(5, "CLOSED", "acme/web"),
+        ] } } }
+    });
+    assert_eq!(
+        parse_branch_pr(&body, "acme/web").map(|pr| pr.number),
+        Some(8)
+    );
+
+    let body = json!({ "data": { "repository": { "pullRequests": { "nodes": [] } } } });
+    assert!(parse_branch_pr(&body, "acme/web").is_none());
+}
+
+// ============================================================================
+// Standalone Row Tests
+// ============================================================================
+
+#[test]
+fn test_standalone_workstream() {
+    let mut pr = make_pr(9, "spike", "Try a thing");
+    pr.status = GitHubPRStatus::ChangesRequested;
+
+    let ws = standalone_workstream(pr);
+    assert_eq!(ws.linear_issue.id, "pr-acme/web#9");
+    assert!(ws.linear_issue.identifier.is_empty());
+    assert!(ws.linear_issue.url.is_empty());
+    assert_eq!(ws.linear_issue.title, "Try a thing");
+    assert_eq!(ws.linear_issue.status, LinearStatus::InReview);
+    assert_eq!(ws.github_pr.map(|pr| pr.number), Some(9));
+    assert!(ws.agent_sessions.is_empty());
+}
@@ -24,12 +24,17 @@ fn make_workstream(id: &str, title: &str) -> Workstream {
             assignee_id: None,
             assignee_name: None,
             estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
             attachments: vec![],
             parent: None,
             children: vec![],
+            relations: vec![],
+            workspace: None,
         },
         github_pr: None,
         vercel_deployment: None,
+        linked_prs: vec![],
         agent_sessions: vec![],
         agent_session: None,
         stale: false,
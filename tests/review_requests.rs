@@ -0,0 +1,195 @@
+//! Tests for the review-requested PR section
+//!
+//! Covers the search query, parsing review requests, sizing, linking them to
+//! Linear issues by branch and where the section sits in the list.
+
+#![allow(clippy::field_reassign_with_default)]
+
+use chrono::{Duration, Utc};
+use panopticon::config::LinearConfig;
+use panopticon::data::{
+    AppState, GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority, LinearStatus, ReviewRequest,
+    SectionType, VisualItem, Workstream,
+};
+use panopticon::integrations::github::{parse_review_requests, review_requests_query};
+use panopticon::integrations::pr_discovery::{attach_linked_issues, link_review_requests};
+use serde_json::json;
+
+fn make_issue(identifier: &str) -> LinearIssue {
+    LinearIssue {
+        id: format!("id-{}", identifier),
+        identifier: identifier.to_string(),
+        title: format!("Issue {}", identifier),
+        description: None,
+        status: LinearStatus::InReview,
+        priority: LinearPriority::High,
+        url: format!("https://linear.app/test/issue/{}", identifier),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        cycle: None,
+        labels: vec![],
+        project: None,
+        team: None,
+        assignee_id: None,
+        assignee_name: None,
+        estimate: None,
+        due_date: None,
+        sla_breaches_at: None,
+        attachments: vec![],
+        parent: None,
+        children: vec![],
+        relations: vec![],
+        workspace: None,
+    }
+}
+
+fn make_request(number: u64, branch: &str, age_hours: i64) -> ReviewRequest {
+    ReviewRequest {
+        pr: GitHubPR {
+            number,
+            title: format!("PR {}", number),
+            url: format!("https://github.com/acme/web/pull/{}", number),
+            status: GitHubPRStatus::ReviewRequested,
+            branch: branch.to_string(),
+            repo: "acme/web".to_string(),
+            ci_status: None,
+            checks: vec![],
+            reviews: vec![],
+            review_threads: vec![],
+            head_sha: None,
+        },
+        author: Some("jane".to_string()),
+        created_at: Utc::now() - Duration::hours(age_hours),
+        additions: 0,
+        deletions: 0,
+        changed_files: 0,
+        linked_identifier: None,
+        linked_issue: None,
+    }
+}
+
+// ============================================================================
+// Search Tests
+// ============================================================================
+
+#[test]
+fn test_review_requests_query() {
+    assert_eq!(
+        review_requests_query("octocat", &["acme".to_string()]),
+        "is:pr is:open archived:false review-requested:octocat org:acme"
+    );
+}
+
+#[test]
+fn test_parse_review_requests() {
+    let body = json!({
+        "data": { "search": { "nodes": [{
+            "number": 42,
+            "title": "Speed up search",
+            "url": "https://github.com/acme/web/pull/42",
+            "state": "OPEN",
+            "isDraft": false,
+            "merged": false,
+            "headRefName": "jane/eng-12-search",
+            "repository": { "nameWithOwner": "acme/web" },
+            "author": { "login": "jane" },
+            "createdAt": "2024-05-01T09:30:00Z",
+            "additions": 120,
+            "deletions": 30,
+            "changedFiles": 4
+        }] } }
+    });
+
+    let requests = parse_review_requests(&body);
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.pr.number, 42);
+    assert_eq!(request.pr.branch, "jane/eng-12-search");
+    assert_eq!(request.author.as_deref(), Some("jane"));
+    assert_eq!(request.created_at.to_rfc3339(), "2024-05-01T09:30:00+00:00");
+    assert_eq!((request.additions, request.deletions), (120, 30));
+    assert_eq!(request.changed_files, 4);
+    assert!(request.linked_identifier.is_none());
+}
+
+#[test]
+fn test_size_label() {
+    let cases = [
+        (0, "XS"),
+        (9, "XS"),
+        (10, "S"),
+        (150, "M"),
+        (700, "L"),
+        (5000, "XL"),
+    ];
+    for (lines, expected) in cases {
+        let mut request = make_request(1, "main", 1);
+        request.additions = lines;
+        assert_eq!(request.size_label(), expected, "{} lines", lines);
+    }
+}
+
+// ============================================================================
+// Linking Tests
+// ============================================================================
+
+#[test]
+fn test_review_requests_link_to_issues_by_branch() {
+    let mut requests = vec![
+        make_request(1, "jane/eng-12-search", 1),
+        make_request(2, "eng-12-followup", 1),
+        make_request(3, "dependabot/npm/lodash", 1),
+    ];
+
+    let identifiers =
+        link_review_requests(&mut requests, &LinearConfig::default().identifier_pattern);
+    assert_eq!(identifiers, vec!["ENG-12".to_string()]);
+    assert_eq!(requests[1].linked_identifier.as_deref(), Some("ENG-12"));
+    assert!(requests[2].linked_identifier.is_none());
+
+    attach_linked_issues(&mut requests, &[make_issue("ENG-12")]);
+    assert_eq!(
+        requests[0].linked_issue.as_ref().map(|i| i.id.as_str()),
+        Some("id-ENG-12")
+    );
+    assert!(requests[1].linked_issue.is_some());
+    assert!(requests[2].linked_issue.is_none());
+}
+
+// ============================================================================
+// Visual Item Tests
+// ============================================================================
+
+#[test]
+fn test_review_section_lists_oldest_first_before_issues() {
+    let mut state = AppState::default();
+    state.workstreams = vec![Workstream {
+        linear_issue: make_issue("ENG-1"),
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    }];
+    state.review_requests = vec![make_request(1, "a", 2), make_request(2, "b", 30)];
+
+    let items = state.build_visual_items(&[0], false);
+    assert!(matches!(
+        items[0],
+        VisualItem::SectionHeader(SectionType::ReviewRequests)
+    ));
+    assert!(matches!(items[1], VisualItem::ReviewRequest(1)));
+    assert!(matches!(items[2], VisualItem::ReviewRequest(0)));
+    assert!(matches!(
+        items[3],
+        VisualItem::SectionHeader(SectionType::Issues)
+    ));
+
+    state.collapsed_sections.insert(SectionType::ReviewRequests);
+    let items = state.build_visual_items(&[0], false);
+    assert!(matches!(
+        items[1],
+        VisualItem::SectionHeader(SectionType::Issues)
+    ));
+}
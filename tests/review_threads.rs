@@ -0,0 +1,179 @@
+//! Tests for PR reviews and review threads
+//!
+//! Covers parsing reviews and threads out of a GraphQL PR node, the
+//! unresolved thread counter, the prompt handed to agents and its shortcut.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{GitHubPR, GitHubPRStatus, ReviewState, ReviewThread};
+use panopticon::integrations::github::parse_authored_prs;
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+use serde_json::json;
+
+fn make_thread(path: &str, line: Option<u32>, body: &str, resolved: bool) -> ReviewThread {
+    ReviewThread {
+        path: path.to_string(),
+        line,
+        author: Some("alice".to_string()),
+        body: body.to_string(),
+        url: None,
+        comment_count: 1,
+        resolved,
+    }
+}
+
+fn make_pr(review_threads: Vec<ReviewThread>) -> GitHubPR {
+    GitHubPR {
+        number: 5,
+        title: "Add caching".to_string(),
+        url: "https://github.com/acme/web/pull/5".to_string(),
+        status: GitHubPRStatus::ChangesRequested,
+        branch: "eng-1-caching".to_string(),
+        repo: "acme/web".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads,
+        head_sha: None,
+    }
+}
+
+// ============================================================================
+// Parsing Tests
+// ============================================================================
+
+#[test]
+fn test_parse_reviews_and_threads() {
+    let body = json!({
+        "data": { "search": { "nodes": [{
+            "number": 5,
+            "url": "https://github.com/acme/web/pull/5",
+            "state": "OPEN",
+            "reviewDecision": "CHANGES_REQUESTED",
+            "repository": { "nameWithOwner": "acme/web" },
+            "latestReviews": { "nodes": [
+                { "author": { "login": "alice" }, "state": "CHANGES_REQUESTED", "body": "Needs tests" },
+                { "author": { "login": "bob" }, "state": "APPROVED", "body": "" },
+                { "author": { "login": "carol" }, "state": "DISMISSED", "body": "" }
+            ] },
+            "reviewThreads": { "nodes": [
+                {
+                    "isResolved": false,
+                    "path": "src/cache.rs",
+                    "line": 42,
+                    "comments": {
+                        "totalCount": 3,
+                        "nodes": [{
+                            "author": { "login": "alice" },
+                            "body": "This never expires",
+                            "url": "https://github.com/acme/web/pull/5#discussion_r1"
+                        }]
+                    }
+                },
+                {
+                    "isResolved": true,
+                    "path": "src/lib.rs",
+                    "line": null,
+                    "comments": { "totalCount": 1, "nodes": [{ "body": "Typo" }] }
+                }
+            ] }
+        }] } }
+    });
+
+    let pr = &parse_authored_prs(&body)[0];
+    let reviews: Vec<(Option<&str>, ReviewState)> = pr
+        .reviews
+        .iter()
+        .map(|r| (r.author.as_deref(), r.state))
+        .collect();
+    assert_eq!(
+        reviews,
+        vec![
+            (Some("alice"), ReviewState::ChangesRequested),
+            (Some("bob"), ReviewState::Approved),
+        ]
+    );
+
+    assert_eq!(pr.review_threads.len(), 2);
+    let thread = &pr.review_threads[0];
+    assert_eq!(thread.location(), "src/cache.rs:42");
+    assert_eq!(thread.author.as_deref(), Some("alice"));
+    assert_eq!(thread.comment_count, 3);
+    assert!(thread.url.is_some());
+    assert_eq!(pr.review_threads[1].location(), "src/lib.rs");
+    assert_eq!(pr.unresolved_thread_count(), 1);
+}
+
+// ============================================================================
+// Prompt Tests
+// ============================================================================
+
+#[test]
+fn test_unresolved_threads_prompt() {
+    let pr = make_pr(vec![
+        make_thread("src/cache.rs", Some(42), "This never expires", false),
+        make_thread("src/lib.rs", None, "Typo", true),
+        make_thread(
+            "README.md",
+            Some(3),
+            "Document the TTL\nand the default",
+            false,
+        ),
+    ]);
+
+    let prompt = pr.unresolved_threads_prompt().unwrap();
+    assert_eq!(
+        prompt,
+        "Address the unresolved review comments on PR #5 (acme/web):\n\
+         \n1. src/cache.rs:42 (@alice)\n   This never expires\n\
+         \n2. README.md:3 (@alice)\n   Document the TTL\n   and the default\n"
+    );
+
+    let resolved = make_pr(vec![make_thread("src/lib.rs", None, "Typo", true)]);
+    assert_eq!(resolved.unresolved_threads_prompt(), None);
+}
+
+// ============================================================================
+// Dispatch Tests
+// ============================================================================
+
+#[test]
+fn test_send_review_comments_key() {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let mut app = App::new(config);
+    app.modal = ModalState::LinkMenu {
+        show_links_popup: false,
+    };
+    let key = KeyEvent {
+        code: KeyCode::Char('a'),
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    };
+
+    let msg = dispatch(&app, &mut InputState::new(), key);
+    assert_eq!(msg, Message::SendReviewCommentsToAgent);
+}
@@ -0,0 +1,288 @@
+//! Tests for the team view's shared session snapshots
+//!
+//! Covers sanitizing local sessions into a snapshot, merging teammates'
+//! snapshots into workstreams, the shared-directory round trip and the signed
+//! HTTP listener.
+
+use chrono::{Duration, TimeZone, Utc};
+use panopticon::data::{
+    AgentActivity, AgentSession, AgentStatus, AgentType, LinearIssue, LinearPriority, LinearStatus,
+    SessionOwner, Workstream,
+};
+use panopticon::integrations::team_share::{self, TeamSnapshot, TeamUpdate};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+const SECRET: &str = "team-secret";
+
+fn make_session(id: &str, branch: &str) -> AgentSession {
+    AgentSession {
+        id: id.to_string(),
+        agent_type: AgentType::ClaudeCode,
+        status: AgentStatus::Running,
+        working_directory: Some("/home/alice/Projects/api".to_string()),
+        git_branch: Some(branch.to_string()),
+        last_output: Some("secret output".to_string()),
+        started_at: Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap(),
+        last_activity: Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap(),
+        window_id: Some("%3".to_string()),
+        owner: None,
+        activity: AgentActivity {
+            current_tool: Some("Edit".to_string()),
+            current_target: Some("/home/alice/Projects/api/src/main.rs".to_string()),
+            last_prompt: Some("rotate the prod credentials".to_string()),
+            ..Default::default()
+        },
+    }
+}
+
+fn make_workstream(id: &str, identifier: &str, sessions: Vec<AgentSession>) -> Workstream {
+    Workstream {
+        linear_issue: LinearIssue {
+            id: id.to_string(),
+            identifier: identifier.to_string(),
+            title: format!("Issue {}", identifier),
+            description: None,
+            status: LinearStatus::InProgress,
+            priority: LinearPriority::Medium,
+            url: format!("https://linear.app/acme/issue/{}", identifier),
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_session: sessions.first().cloned(),
+        agent_sessions: sessions,
+        stale: false,
+    }
+}
+
+fn alice_snapshot() -> TeamSnapshot {
+    let workstreams = vec![
+        make_workstream(
+            "issue-1",
+            "ENG-1",
+            vec![make_session("s1", "alice/eng-1-login")],
+        ),
+        make_workstream(
+            "issue-9",
+            "ENG-9",
+            vec![make_session("s2", "alice/eng-9-billing")],
+        ),
+    ];
+    TeamSnapshot::from_workstreams(
+        "alice",
+        Some("user-alice".to_string()),
+        &workstreams,
+        Utc::now(),
+    )
+}
+
+// ============================================================================
+// Snapshot Tests
+// ============================================================================
+
+#[test]
+fn test_snapshot_is_sanitized() {
+    let snapshot = alice_snapshot();
+    assert_eq!(snapshot.sessions.len(), 2);
+
+    let shared = &snapshot.sessions[0];
+    assert_eq!(shared.issue_identifier.as_deref(), Some("ENG-1"));
+    assert_eq!(shared.branch.as_deref(), Some("alice/eng-1-login"));
+    assert_eq!(shared.current_tool.as_deref(), Some("Edit"));
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    assert!(!json.contains("/home/alice"), "paths must not be shared");
+    assert!(!json.contains("credentials"), "prompts must not be shared");
+    assert!(!json.contains("secret output"), "output must not be shared");
+}
+
+#[test]
+fn test_snapshot_skips_teammates_sessions() {
+    let mut teammate = make_session("bob:s1", "bob/eng-2");
+    teammate.owner = Some(SessionOwner {
+        name: "bob".to_string(),
+        linear_user_id: None,
+    });
+    let workstreams = vec![make_workstream(
+        "issue-1",
+        "ENG-1",
+        vec![make_session("s1", "eng-1"), teammate],
+    )];
+
+    let snapshot = TeamSnapshot::from_workstreams("alice", None, &workstreams, Utc::now());
+    assert_eq!(snapshot.sessions.len(), 1);
+    assert_eq!(snapshot.sessions[0].id, "s1");
+}
+
+#[test]
+fn test_stale_snapshot() {
+    let mut snapshot = alice_snapshot();
+    let now = Utc::now();
+    assert!(!snapshot.is_stale(now, 120));
+    snapshot.published_at = now - Duration::seconds(300);
+    assert!(snapshot.is_stale(now, 120));
+}
+
+// ============================================================================
+// Merge Tests
+// ============================================================================
+
+#[test]
+fn test_merge_attaches_to_issue_or_placeholder() {
+    let local = make_session("mine", "eng-1-pairing");
+    let mut workstreams = vec![make_workstream("issue-1", "ENG-1", vec![local])];
+    let snapshot = alice_snapshot();
+
+    team_share::merge(&mut workstreams, [&snapshot]);
+
+    // ENG-1 is in our list: Alice's session joins ours
+    assert_eq!(workstreams[0].agent_sessions.len(), 2);
+    let shared = &workstreams[0].agent_sessions[1];
+    assert_eq!(shared.id, "alice:s1");
+    assert_eq!(shared.owner.as_ref().unwrap().name, "alice");
+    assert_eq!(workstreams[0].agent_session.as_ref().unwrap().id, "mine");
+
+    // ENG-9 isn't: it gets a placeholder row owned by Alice
+    assert_eq!(workstreams.len(), 2);
+    let placeholder = &workstreams[1];
+    assert_eq!(placeholder.linear_issue.identifier, "ENG-9");
+    assert_eq!(
+        placeholder.linear_issue.url,
+        "https://linear.app/acme/issue/ENG-9"
+    );
+    assert_eq!(
+        placeholder.linear_issue.assignee_id.as_deref(),
+        Some("user-alice")
+    );
+    assert_eq!(placeholder.agent_session.as_ref().unwrap().id, "alice:s2");
+}
+
+#[test]
+fn test_merge_is_idempotent_and_strips_departed_teammates() {
+    let mut workstreams = vec![make_workstream(
+        "issue-1",
+        "ENG-1",
+        vec![make_session("mine", "eng-1")],
+    )];
+    let snapshot = alice_snapshot();
+
+    team_share::merge(&mut workstreams, [&snapshot]);
+    team_share::merge(&mut workstreams, [&snapshot]);
+    assert_eq!(workstreams.len(), 2);
+    assert_eq!(workstreams[0].agent_sessions.len(), 2);
+
+    team_share::merge(&mut workstreams, []);
+    assert_eq!(workstreams.len(), 1);
+    assert_eq!(workstreams[0].agent_sessions.len(), 1);
+    assert_eq!(workstreams[0].agent_session.as_ref().unwrap().id, "mine");
+}
+
+// ============================================================================
+// Shared Directory Tests
+// ============================================================================
+
+#[test]
+fn test_shared_directory_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let alice = alice_snapshot();
+    let own = TeamSnapshot::from_workstreams("lead", None, &[], Utc::now());
+
+    team_share::write_snapshot(dir.path(), &alice).unwrap();
+    team_share::write_snapshot(dir.path(), &own).unwrap();
+    std::fs::write(dir.path().join("garbage.json"), "not json").unwrap();
+
+    let snapshots = team_share::read_snapshots(dir.path(), "lead").unwrap();
+    assert_eq!(snapshots, vec![alice]);
+}
+
+#[test]
+fn test_snapshot_file_name_is_sanitized() {
+    assert_eq!(team_share::snapshot_file_name("alice"), "alice.json");
+    assert_eq!(
+        team_share::snapshot_file_name("../bob smith"),
+        "_bob_smith.json"
+    );
+}
+
+// ============================================================================
+// HTTP Tests
+// ============================================================================
+
+async fn start_listener() -> (String, mpsc::Receiver<TeamUpdate>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = format!("http://{}/snapshots", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(team_share::serve(listener, SECRET.to_string(), tx));
+    (addr, rx)
+}
+
+#[tokio::test]
+async fn test_http_publish_reaches_listener() {
+    let (addr, mut rx) = start_listener().await;
+    let snapshot = alice_snapshot();
+
+    team_share::publish_http(&addr, &snapshot, Some(SECRET))
+        .await
+        .unwrap();
+
+    match rx.recv().await.unwrap() {
+        TeamUpdate::Snapshot(received) => assert_eq!(received, snapshot),
+        other => panic!("expected snapshot, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_http_listener_rejects_bad_signature() {
+    let (addr, mut rx) = start_listener().await;
+
+    let err = team_share::publish_http(&addr, &alice_snapshot(), Some("wrong"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("401"));
+
+    // Unsigned snapshots are rejected too
+    let response = reqwest::Client::new()
+        .post(&addr)
+        .body(serde_json::to_vec(&alice_snapshot()).unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status().as_u16(), 401);
+    assert!(rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_http_sharing_requires_secret() {
+    let (tx, _rx) = mpsc::channel(16);
+    let err = team_share::spawn_listener("127.0.0.1:0", None, tx.clone())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("team.secret"));
+    assert!(
+        team_share::spawn_listener("127.0.0.1:0", Some(String::new()), tx)
+            .await
+            .is_err()
+    );
+
+    let err = team_share::publish_http("http://127.0.0.1:9/snapshots", &alice_snapshot(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("team.secret"));
+}
@@ -185,6 +185,7 @@ pub fn parse_issue(node: &Value) -> Option<ParsedIssue> {
                         url: c["url"].as_str().unwrap_or("").to_string(),
                         status: child_status,
                         priority: child_priority,
+                        estimate: c["estimate"].as_f64().map(|e| e as f32),
                     })
                 })
                 .collect()
@@ -315,6 +316,7 @@ pub struct LinearChildRef {
     pub url: String,
     pub status: LinearStatus,
     pub priority: LinearPriority,
+    pub estimate: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +20,7 @@ mod session_merger {
             started_at: Utc::now(),
             last_activity: Utc::now(),
             window_id: None,
+            owner: None,
             activity: Default::default(),
         }
     }
@@ -0,0 +1,323 @@
+//! Tests for the Vercel build log viewer
+//!
+//! Covers parsing build events (and only the newer ones on later polls),
+//! finding the first error, the excerpt handed to agents, and opening and
+//! scrolling the modal.
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    BuildLog, BuildLogCursor, DeploymentSource, LinearIssue, LinearPriority, LinearStatus,
+    VercelDeployment, VercelStatus, VisualItem, Workstream,
+};
+use panopticon::integrations::vercel::{parse_build_events, parse_build_events_after};
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message};
+use serde_json::json;
+
+fn deployment(status: VercelStatus) -> VercelDeployment {
+    VercelDeployment {
+        id: "dpl_1".to_string(),
+        url: "https://web-abc.vercel.app".to_string(),
+        status,
+        created_at: Utc::now(),
+        name: None,
+        project_id: None,
+        target: None,
+        commit_sha: None,
+        commit_message: None,
+        source: DeploymentSource::Vercel,
+    }
+}
+
+fn make_app(deployment: Option<VercelDeployment>) -> App {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let mut app = App::new(config);
+
+    let mut ws = Workstream {
+        linear_issue: LinearIssue {
+            id: "id-1".to_string(),
+            identifier: "ENG-1".to_string(),
+            title: "Feature".to_string(),
+            description: None,
+            status: LinearStatus::InReview,
+            priority: LinearPriority::Medium,
+            url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    };
+    ws.vercel_deployment = deployment;
+    app.state.workstreams = vec![ws];
+    app.apply_filters();
+    app.rebuild_visual_items();
+    app.visual_selected = app
+        .visual_items
+        .iter()
+        .position(|item| matches!(item, VisualItem::Workstream(_)))
+        .unwrap();
+    app
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    }
+}
+
+fn lines(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|t| t.to_string()).collect()
+}
+
+// ============================================================================
+// Parsing Tests
+// ============================================================================
+
+#[test]
+fn test_parse_build_events() {
+    let body = json!([
+        { "type": "command", "created": 1, "payload": { "text": "Running \"npm run build\"" } },
+        { "type": "delimiter", "created": 2, "payload": { "text": "Build" } },
+        { "type": "stdout", "created": 3, "text": "Creating an optimized production build..." },
+        { "type": "stderr", "created": 4, "payload": { "text": "Failed to compile." } },
+        { "type": "exit", "created": 5, "payload": {} }
+    ]);
+    assert_eq!(
+        parse_build_events(&body),
+        lines(&[
+            "Running \"npm run build\"",
+            "Creating an optimized production build...",
+            "Failed to compile.",
+        ])
+    );
+    assert!(parse_build_events(&json!({ "error": "not found" })).is_empty());
+}
+
+#[test]
+fn test_parse_build_events_after_cursor() {
+    let body = json!([
+        { "type": "stdout", "created": 10, "payload": { "id": "e1", "text": "one" } },
+        { "type": "stdout", "created": 10, "payload": { "id": "e2", "text": "two" } },
+        { "type": "stdout", "created": 12, "payload": { "id": "e3", "text": "three" } }
+    ]);
+
+    let (all, cursor) = parse_build_events_after(&body, None);
+    assert_eq!(all, lines(&["one", "two", "three"]));
+    assert_eq!(
+        cursor,
+        Some(BuildLogCursor {
+            created: 12,
+            id: Some("e3".to_string()),
+        })
+    );
+
+    // `since=` repeats events at the cursor's timestamp
+    let after = BuildLogCursor {
+        created: 10,
+        id: Some("e1".to_string()),
+    };
+    let (newer, cursor) = parse_build_events_after(&body, Some(&after));
+    assert_eq!(newer, lines(&["two", "three"]));
+    assert_eq!(cursor.map(|c| c.created), Some(12));
+
+    // Without an ID only later timestamps count
+    let after = BuildLogCursor {
+        created: 10,
+        id: None,
+    };
+    assert_eq!(
+        parse_build_events_after(&body, Some(&after)).0,
+        lines(&["three"])
+    );
+
+    // Nothing new keeps the cursor
+    let after = BuildLogCursor {
+        created: 12,
+        id: Some("e3".to_string()),
+    };
+    let (newer, cursor) = parse_build_events_after(&body, Some(&after));
+    assert!(newer.is_empty());
+    assert_eq!(cursor, Some(after));
+}
+
+#[test]
+fn test_build_log_finds_first_error() {
+    let log = BuildLog::new(
+        "dpl_1",
+        VercelStatus::Error,
+        lines(&[
+            "Installing dependencies...",
+            "\u{1b}[31mType error\u{1b}[0m: Property 'id' does not exist\n  at page.tsx:12",
+            "Error: Command \"npm run build\" exited with 1",
+        ]),
+    );
+    assert_eq!(log.lines.len(), 4);
+    assert_eq!(log.lines[1], "Type error: Property 'id' does not exist");
+    assert_eq!(log.first_error, Some(1));
+    assert!(!log.is_live());
+
+    let prompt = log.agent_prompt("https://web-abc.vercel.app");
+    assert!(prompt.contains("https://web-abc.vercel.app failed to build"));
+    assert!(prompt.contains("Installing dependencies...\nType error"));
+}
+
+#[test]
+fn test_build_log_append() {
+    let mut log = BuildLog::new("dpl_1", VercelStatus::Building, lines(&["a", "b"]));
+    let mut newer = BuildLog::new(
+        "dpl_1",
+        VercelStatus::Error,
+        lines(&["c", "Error: Cannot find module 'react'"]),
+    );
+    newer.cursor = Some(BuildLogCursor {
+        created: 5,
+        id: None,
+    });
+    log.append(newer);
+
+    assert_eq!(log.lines.len(), 4);
+    assert_eq!(log.first_error, Some(3));
+    assert_eq!(log.status, VercelStatus::Error);
+    assert_eq!(log.cursor.map(|c| c.created), Some(5));
+}
+
+#[test]
+fn test_build_log_excerpt_without_errors() {
+    let raw: Vec<String> = (0..100).map(|i| format!("step {}", i)).collect();
+    let log = BuildLog::new("dpl_1", VercelStatus::Building, raw);
+    assert_eq!(log.first_error, None);
+    assert!(log.is_live());
+    let excerpt = log.failure_excerpt();
+    assert_eq!(excerpt.len(), 60);
+    assert_eq!(excerpt[0], "step 40");
+}
+
+// ============================================================================
+// Modal Tests
+// ============================================================================
+
+#[test]
+fn test_open_build_log_for_selected_issue() {
+    let mut app = make_app(Some(deployment(VercelStatus::Error)));
+    let mut input = InputState::new();
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('b'))),
+        Message::OpenBuildLog
+    );
+    // Outside a runtime nothing is fetched, but the modal shows as loading
+    app.open_build_log();
+    assert!(app.show_build_log());
+    assert!(app.build_log.is_none());
+    assert_eq!(
+        app.build_log_target.as_ref().map(|(_, d)| d.id.as_str()),
+        Some("dpl_1")
+    );
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('e'))),
+        Message::JumpToBuildError
+    );
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('a'))),
+        Message::SendBuildLogToAgent
+    );
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Esc)),
+        Message::CloseBuildLog
+    );
+}
+
+#[test]
+fn test_open_build_log_without_deployment() {
+    let mut app = make_app(None);
+    app.open_build_log();
+    assert!(!app.show_build_log());
+    assert!(app.error_message.is_some());
+}
+
+#[test]
+fn test_open_build_log_only_for_failed_or_running_builds() {
+    let mut app = make_app(Some(deployment(VercelStatus::Ready)));
+    app.open_build_log();
+    assert!(!app.show_build_log());
+    assert!(app.error_message.is_some());
+
+    let mut app = make_app(Some(deployment(VercelStatus::Building)));
+    app.open_build_log();
+    assert!(app.show_build_log());
+}
+
+#[test]
+fn test_open_build_log_for_github_sourced_deployment() {
+    let mut dep = deployment(VercelStatus::Error);
+    dep.source = DeploymentSource::GitHub;
+    let mut app = make_app(Some(dep));
+    app.open_build_log();
+    assert!(!app.show_build_log());
+    assert_eq!(
+        app.error_message.as_deref(),
+        Some("Build logs need a Vercel token")
+    );
+}
+
+#[test]
+fn test_jump_to_build_error() {
+    let mut app = make_app(Some(deployment(VercelStatus::Error)));
+    app.open_build_log();
+    let mut raw: Vec<String> = (0..100).map(|i| format!("step {}", i)).collect();
+    raw[30] = "Error: Cannot find module 'react'".to_string();
+    app.build_log = Some(Ok(BuildLog::new("dpl_1", VercelStatus::Error, raw)));
+
+    app.jump_to_build_error();
+    // The error line ends up 10 lines above the bottom of the view
+    assert_eq!(app.build_log_scroll, 60);
+    app.scroll_build_log(i32::MAX);
+    assert_eq!(app.build_log_scroll, 0);
+}
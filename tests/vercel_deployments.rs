@@ -0,0 +1,332 @@
+//! Tests for the Vercel deployment history and deployment actions
+//!
+//! Covers parsing deployments, which actions apply to which deployments, the
+//! confirmation flow and updating deployments in place.
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{
+    DeploymentAction, DeploymentSource, GitHubPR, GitHubPRStatus, LinearIssue, LinearPriority,
+    LinearStatus, LinkedPr, VercelDeployment, VercelStatus, Workstream,
+};
+use panopticon::integrations::vercel::{
+    branch_deployments_url, parse_deployment, parse_deployments,
+};
+use panopticon::tui::input::InputState;
+use panopticon::tui::keybindings::dispatch;
+use panopticon::tui::{App, Message, ModalState};
+use serde_json::json;
+
+fn deployment(id: &str, status: VercelStatus) -> VercelDeployment {
+    VercelDeployment {
+        id: id.to_string(),
+        url: format!("https://web-{}.vercel.app", id),
+        status,
+        created_at: Utc::now(),
+        name: Some("web".to_string()),
+        project_id: Some("prj_1".to_string()),
+        target: None,
+        commit_sha: Some("0123456789abcdef".to_string()),
+        commit_message: Some("Fix login".to_string()),
+        source: DeploymentSource::Vercel,
+    }
+}
+
+fn make_pr(number: u64) -> GitHubPR {
+    GitHubPR {
+        number,
+        title: format!("PR {}", number),
+        url: format!("https://github.com/acme/web/pull/{}", number),
+        status: GitHubPRStatus::Open,
+        branch: "eng-1-feature".to_string(),
+        repo: "acme/web".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: None,
+    }
+}
+
+fn make_app(prs: Vec<GitHubPR>) -> App {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig::default(),
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let mut app = App::new(config);
+
+    let mut ws = Workstream {
+        linear_issue: LinearIssue {
+            id: "id-1".to_string(),
+            identifier: "ENG-1".to_string(),
+            title: "Feature".to_string(),
+            description: None,
+            status: LinearStatus::InReview,
+            priority: LinearPriority::Medium,
+            url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            cycle: None,
+            labels: vec![],
+            project: None,
+            team: None,
+            assignee_id: None,
+            assignee_name: None,
+            estimate: None,
+            due_date: None,
+            sla_breaches_at: None,
+            attachments: vec![],
+            parent: None,
+            children: vec![],
+            relations: vec![],
+            workspace: None,
+        },
+        github_pr: None,
+        vercel_deployment: None,
+        linked_prs: vec![],
+        agent_sessions: vec![],
+        agent_session: None,
+        stale: false,
+    };
+    ws.set_linked_prs(
+        prs.into_iter()
+            .map(|pr| LinkedPr {
+                pr,
+                vercel_deployment: Some(deployment("dpl_2", VercelStatus::Ready)),
+            })
+            .collect(),
+    );
+    app.state.workstreams = vec![ws];
+    app.modal_issue_id = Some("id-1".to_string());
+    app.modal = ModalState::LinkMenu {
+        show_links_popup: false,
+    };
+    app
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::empty(),
+        kind: KeyEventKind::Press,
+        state: KeyEventState::empty(),
+    }
+}
+
+/// App with the deployments popup open and its history loaded
+fn app_with_history() -> App {
+    let mut app = make_app(vec![make_pr(5)]);
+    app.open_deployments();
+    app.deployment_history = Some(Ok(vec![
+        deployment("dpl_2", VercelStatus::Ready),
+        deployment("dpl_1", VercelStatus::Error),
+    ]));
+    app
+}
+
+// ============================================================================
+// API Tests
+// ============================================================================
+
+#[test]
+fn test_parse_deployment_list() {
+    let body = json!({ "deployments": [{
+        "uid": "dpl_1",
+        "name": "web",
+        "projectId": "prj_1",
+        "url": "web-abc.vercel.app",
+        "state": "READY",
+        "target": "production",
+        "createdAt": 1_714_567_890_000_i64,
+        "meta": {
+            "githubCommitSha": "0123456789abcdef",
+            "githubCommitMessage": "Fix login\n\nLonger description"
+        }
+    }] });
+    let deployments = parse_deployments(&body);
+    assert_eq!(deployments.len(), 1);
+    let d = &deployments[0];
+    assert_eq!(d.id, "dpl_1");
+    assert_eq!(d.url, "https://web-abc.vercel.app");
+    assert_eq!(d.status, VercelStatus::Ready);
+    assert!(d.is_production());
+    assert_eq!(d.short_sha(), Some("0123456"));
+    assert_eq!(d.commit_message.as_deref(), Some("Fix login"));
+    assert_eq!(d.project_id.as_deref(), Some("prj_1"));
+}
+
+#[test]
+fn test_parse_created_deployment() {
+    // Creating and canceling answer with the single-deployment shape
+    let d = parse_deployment(&json!({
+        "id": "dpl_3",
+        "name": "web",
+        "url": "web-def.vercel.app",
+        "readyState": "QUEUED",
+        "createdAt": 1_714_567_890_000_i64
+    }));
+    assert_eq!(d.id, "dpl_3");
+    assert_eq!(d.status, VercelStatus::Queued);
+    assert!(!d.is_production());
+    assert_eq!(d.short_sha(), None);
+}
+
+#[test]
+fn test_history_url_limit() {
+    let config = make_app(vec![]).config.as_ref().clone();
+    let url = branch_deployments_url(&config, "acme/web", "main", 10);
+    assert!(url.starts_with("https://api.vercel.com/v6/deployments?limit=10&"));
+}
+
+#[test]
+fn test_action_availability() {
+    let ready = deployment("dpl_1", VercelStatus::Ready);
+    let building = deployment("dpl_2", VercelStatus::Building);
+    let production = VercelDeployment {
+        target: Some("production".to_string()),
+        ..ready.clone()
+    };
+    assert!(DeploymentAction::Redeploy.is_available(&ready));
+    assert!(!DeploymentAction::Redeploy.is_available(&building));
+    assert!(DeploymentAction::Cancel.is_available(&building));
+    assert!(!DeploymentAction::Cancel.is_available(&ready));
+    assert!(DeploymentAction::Promote.is_available(&ready));
+    assert!(!DeploymentAction::Promote.is_available(&production));
+    assert!(!DeploymentAction::Promote.is_available(&building));
+}
+
+// ============================================================================
+// Popup Tests
+// ============================================================================
+
+#[test]
+fn test_open_deployments_from_issue_details() {
+    let mut app = make_app(vec![make_pr(5)]);
+    let mut input = InputState::new();
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('v'))),
+        Message::OpenDeployments
+    );
+
+    // Outside a runtime nothing is fetched, but the popup shows as loading
+    app.open_deployments();
+    assert!(app.show_deployments());
+    assert!(app.deployment_history.is_none());
+    assert_eq!(app.deployment_pr.as_ref().map(|pr| pr.number), Some(5));
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Esc)),
+        Message::CancelDeploymentAction
+    );
+}
+
+#[test]
+fn test_open_deployments_without_pr() {
+    let mut app = make_app(vec![]);
+    app.open_deployments();
+    assert!(!app.show_deployments());
+    assert!(app.error_message.is_some());
+}
+
+#[test]
+fn test_action_needs_confirmation() {
+    let mut app = app_with_history();
+    let mut input = InputState::new();
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('p'))),
+        Message::RequestDeploymentAction(DeploymentAction::Promote)
+    );
+    app.request_deployment_action(DeploymentAction::Promote);
+    assert_eq!(
+        app.pending_deployment_confirmation(),
+        Some(DeploymentAction::Promote)
+    );
+
+    assert_eq!(
+        dispatch(&app, &mut input, key(KeyCode::Char('y'))),
+        Message::ConfirmDeploymentAction
+    );
+    app.confirm_deployment_action();
+    assert_eq!(app.pending_deployment_confirmation(), None);
+    assert!(app.pending_deployment_actions.contains("dpl_2"));
+}
+
+#[test]
+fn test_unavailable_action_is_refused() {
+    let mut app = app_with_history();
+    app.move_deployment_selection(1);
+    app.request_deployment_action(DeploymentAction::Promote);
+    assert_eq!(app.pending_deployment_confirmation(), None);
+    assert!(app.error_message.is_some());
+    assert!(matches!(
+        app.modal,
+        ModalState::Deployments { confirm: None }
+    ));
+}
+
+#[test]
+fn test_redeploy_becomes_latest_deployment() {
+    let mut app = app_with_history();
+    let pr_url = make_pr(5).url;
+    let new = deployment("dpl_3", VercelStatus::Queued);
+    app.apply_deployment_update(&pr_url, DeploymentAction::Redeploy, new);
+
+    let history = app.deployment_history.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].id, "dpl_3");
+    let ws = &app.state.workstreams[0];
+    assert_eq!(
+        ws.vercel_deployment.as_ref().map(|d| d.id.as_str()),
+        Some("dpl_3")
+    );
+    assert_eq!(
+        ws.linked_prs[0].vercel_deployment.as_ref().unwrap().status,
+        VercelStatus::Queued
+    );
+}
+
+#[test]
+fn test_action_updates_deployment_in_place() {
+    let mut app = app_with_history();
+    let pr_url = make_pr(5).url;
+
+    // An older deployment changes in the history only
+    let canceled = deployment("dpl_1", VercelStatus::Canceled);
+    app.apply_deployment_update(&pr_url, DeploymentAction::Cancel, canceled);
+    let history = app.deployment_history.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(history[1].status, VercelStatus::Canceled);
+    let ws = &app.state.workstreams[0];
+    assert_eq!(
+        ws.vercel_deployment.as_ref().map(|d| d.id.as_str()),
+        Some("dpl_2")
+    );
+
+    // The latest one changes on the issue too
+    let promoted = VercelDeployment {
+        target: Some("production".to_string()),
+        ..deployment("dpl_2", VercelStatus::Ready)
+    };
+    app.apply_deployment_update(&pr_url, DeploymentAction::Promote, promoted);
+    let ws = &app.state.workstreams[0];
+    assert!(ws.vercel_deployment.as_ref().unwrap().is_production());
+}
@@ -0,0 +1,145 @@
+//! Tests for deriving deployments from GitHub when Vercel can't be asked
+//!
+//! Covers picking Vercel's GitHub deployment, mapping deployment and commit
+//! statuses to deployments, marking them as GitHub-sourced, and skipping the
+//! lookup without a GitHub token.
+
+use panopticon::config::{
+    CacheConfig, Config, GithubConfig, LinearConfig, NotificationConfig, PollingConfig, TeamConfig,
+    Tokens, UiConfig, VercelConfig, WebhookConfig,
+};
+use panopticon::data::{DeploymentSource, GitHubPR, GitHubPRStatus, VercelStatus};
+use panopticon::integrations::vercel::{
+    deployment_from_commit_statuses, deployment_from_github, fetch_deployment_from_github_status,
+    pick_vercel_deployment,
+};
+use serde_json::json;
+
+#[test]
+fn test_pick_vercel_deployment_by_creator() {
+    let deployments = json!([
+        { "id": 1, "creator": { "login": "netlify[bot]" } },
+        { "id": 2, "creator": { "login": "vercel[bot]" } },
+        { "id": 3, "creator": { "login": "Vercel" } }
+    ]);
+    let picked = pick_vercel_deployment(&deployments).unwrap();
+    assert_eq!(picked["id"], 2);
+
+    let others = json!([{ "id": 1, "creator": { "login": "octocat" } }]);
+    assert!(pick_vercel_deployment(&others).is_none());
+    assert!(pick_vercel_deployment(&json!({})).is_none());
+}
+
+#[test]
+fn test_deployment_from_github_status() {
+    let deployment = json!({
+        "id": 42,
+        "environment": "Preview – web",
+        "created_at": "2026-03-01T12:00:00Z",
+        "creator": { "login": "vercel[bot]" }
+    });
+    let status = json!({
+        "state": "success",
+        "environment_url": "https://web-abc.vercel.app",
+        "target_url": "https://vercel.com/acme/web/abc"
+    });
+
+    let dep = deployment_from_github(&deployment, &status, "4f2a9c1e");
+    assert_eq!(dep.id, "42");
+    assert_eq!(dep.url, "https://web-abc.vercel.app");
+    assert_eq!(dep.status, VercelStatus::Ready);
+    assert_eq!(dep.commit_sha.as_deref(), Some("4f2a9c1e"));
+    assert_eq!(dep.source, DeploymentSource::GitHub);
+    assert!(!dep.is_production());
+}
+
+#[test]
+fn test_deployment_from_github_production_and_states() {
+    let deployment = json!({ "id": 7, "production_environment": true });
+
+    let building = deployment_from_github(
+        &deployment,
+        &json!({ "state": "in_progress", "target_url": "https://vercel.com/acme/web/x" }),
+        "abc",
+    );
+    assert!(building.is_production());
+    assert_eq!(building.status, VercelStatus::Building);
+    // Without an environment URL the inspector link is used
+    assert_eq!(building.url, "https://vercel.com/acme/web/x");
+
+    let failed = deployment_from_github(&deployment, &json!({ "state": "failure" }), "abc");
+    assert_eq!(failed.status, VercelStatus::Error);
+
+    // No status reported yet
+    let queued = deployment_from_github(&deployment, &serde_json::Value::Null, "abc");
+    assert_eq!(queued.status, VercelStatus::Queued);
+}
+
+#[test]
+fn test_deployment_from_commit_statuses() {
+    let statuses = json!([
+        { "id": 1, "context": "ci/test", "state": "failure" },
+        {
+            "id": 2,
+            "context": "Vercel – web",
+            "state": "pending",
+            "target_url": "https://vercel.com/acme/web/abc"
+        },
+        { "id": 3, "context": "Vercel", "state": "success" }
+    ]);
+
+    // Statuses are newest first, so the first Vercel one wins
+    let dep = deployment_from_commit_statuses(&statuses, "4f2a9c1e").unwrap();
+    assert_eq!(dep.id, "2");
+    assert_eq!(dep.status, VercelStatus::Building);
+    assert_eq!(dep.url, "https://vercel.com/acme/web/abc");
+    assert_eq!(dep.source, DeploymentSource::GitHub);
+
+    let unrelated = json!([{ "id": 1, "context": "ci/test", "state": "success" }]);
+    assert!(deployment_from_commit_statuses(&unrelated, "abc").is_none());
+}
+
+#[tokio::test]
+async fn test_fallback_needs_github_token() {
+    let config = Config {
+        tokens: Tokens {
+            linear: String::new(),
+            github: String::new(),
+            vercel: None,
+        },
+        linear: LinearConfig::default(),
+        github: GithubConfig {
+            // Nothing listens here: a request would fail rather than return None
+            api_url: "http://127.0.0.1:9".to_string(),
+            ..GithubConfig::default()
+        },
+        vercel: VercelConfig::default(),
+        polling: PollingConfig::default(),
+        cache: CacheConfig {
+            enabled: false,
+            ..CacheConfig::default()
+        },
+        notifications: NotificationConfig::default(),
+        ui: UiConfig::default(),
+        webhook: WebhookConfig::default(),
+        team: TeamConfig::default(),
+    };
+    let pr = GitHubPR {
+        number: 7,
+        title: "Login".to_string(),
+        url: "https://github.com/acme/web/pull/7".to_string(),
+        status: GitHubPRStatus::Open,
+        branch: "eng-1-login".to_string(),
+        repo: "acme/web".to_string(),
+        ci_status: None,
+        checks: vec![],
+        reviews: vec![],
+        review_threads: vec![],
+        head_sha: Some("abc".to_string()),
+    };
+
+    let deployment = fetch_deployment_from_github_status(&config, &pr)
+        .await
+        .unwrap();
+    assert!(deployment.is_none());
+}
@@ -1,6 +1,23 @@
 //! Tests for Vercel URL encoding
 //!
-//! Verifies that branch names with special characters are properly URL encoded.
+//! Verifies that branch names with special characters are properly URL encoded,
+//! and that deployment queries are scoped to the team, projects and repository.
+
+use panopticon::config::{Config, VercelConfig};
+use panopticon::integrations::vercel::deployments_url;
+
+fn config_with(vercel: VercelConfig) -> Config {
+    let mut config: Config = toml::from_str(
+        r#"
+        [tokens]
+        linear = "lin"
+        github = "gh"
+        "#,
+    )
+    .unwrap();
+    config.vercel = vercel;
+    config
+}
 
 #[test]
 fn test_branch_with_slash_encodes_correctly() {
@@ -52,3 +69,38 @@ fn test_url_construction_with_encoded_branch() {
         "https://api.vercel.com/v6/deployments?limit=1&meta-githubCommitRef=fix%2Fissue-123"
     );
 }
+
+#[test]
+fn test_deployments_url_matches_repository() {
+    let url = deployments_url(&config_with(VercelConfig::default()), "acme/web", "fix/a");
+    assert_eq!(
+        url,
+        "https://api.vercel.com/v6/deployments?limit=1&meta-githubCommitRef=fix%2Fa&meta-githubOrg=acme&meta-githubRepo=web"
+    );
+}
+
+#[test]
+fn test_deployments_url_scopes_team_and_project() {
+    let config = config_with(VercelConfig {
+        team_id: Some("team_abc".to_string()),
+        project_ids: vec!["prj_1".to_string()],
+        ..VercelConfig::default()
+    });
+    let url = deployments_url(&config, "acme/web", "main");
+    assert!(
+        url.ends_with("&meta-githubOrg=acme&meta-githubRepo=web&teamId=team_abc&projectId=prj_1")
+    );
+}
+
+#[test]
+fn test_deployments_url_team_slug_and_several_projects() {
+    let config = config_with(VercelConfig {
+        team_id: Some("acme".to_string()),
+        project_ids: vec!["prj_1".to_string(), "prj_2".to_string()],
+        ..VercelConfig::default()
+    });
+    let url = deployments_url(&config, "acme/web", "main");
+    assert!(url.contains("&slug=acme"));
+    assert!(!url.contains("teamId"));
+    assert!(url.ends_with("&projectIds=prj_1&projectIds=prj_2"));
+}